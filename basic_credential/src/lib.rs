@@ -9,9 +9,10 @@ use std::fmt::Debug;
 use openmls_traits::{
     signatures::{Signer, SignerError},
     storage::{self, StorageProvider, CURRENT_VERSION},
-    types::{CryptoError, SignatureScheme},
+    types::{CompositeValue, CryptoError, SignatureScheme},
 };
 
+use ml_dsa::{KeyGen, MlDsa65, MlDsa87, MlDsaParams};
 use p256::ecdsa::{signature::Signer as P256Signer, Signature, SigningKey};
 
 use rand::rngs::OsRng;
@@ -44,21 +45,7 @@ impl Debug for SignatureKeyPair {
 
 impl Signer for SignatureKeyPair {
     fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, SignerError> {
-        match self.signature_scheme {
-            SignatureScheme::ECDSA_SECP256R1_SHA256 => {
-                let k = SigningKey::from_bytes(self.private.as_slice().into())
-                    .map_err(|_| SignerError::SigningError)?;
-                let signature: Signature = k.sign(payload);
-                Ok(signature.to_der().to_bytes().into())
-            }
-            SignatureScheme::ED25519 => {
-                let k = ed25519_dalek::SigningKey::try_from(self.private.as_slice())
-                    .map_err(|_| SignerError::SigningError)?;
-                let signature = k.sign(payload);
-                Ok(signature.to_bytes().into())
-            }
-            _ => Err(SignerError::SigningError),
-        }
+        sign(self.signature_scheme, &self.private, payload)
     }
 
     fn signature_scheme(&self) -> SignatureScheme {
@@ -66,6 +53,73 @@ impl Signer for SignatureKeyPair {
     }
 }
 
+/// Sign the `payload` with the `private` key of the `signature_scheme`.
+fn sign(
+    signature_scheme: SignatureScheme,
+    private: &[u8],
+    payload: &[u8],
+) -> Result<Vec<u8>, SignerError> {
+    match signature_scheme {
+        SignatureScheme::ECDSA_SECP256R1_SHA256 => {
+            let k =
+                SigningKey::from_bytes(private.into()).map_err(|_| SignerError::SigningError)?;
+            let signature: Signature = k.sign(payload);
+            Ok(signature.to_der().to_bytes().into())
+        }
+        SignatureScheme::ECDSA_SECP384R1_SHA384 => {
+            let k = p384::ecdsa::SigningKey::from_slice(private)
+                .map_err(|_| SignerError::SigningError)?;
+            let signature: p384::ecdsa::Signature = k.sign(payload);
+            Ok(signature.to_der().to_bytes().into())
+        }
+        SignatureScheme::ECDSA_SECP521R1_SHA512 => {
+            let k = p521::ecdsa::SigningKey::from_slice(private)
+                .map_err(|_| SignerError::SigningError)?;
+            let signature: p521::ecdsa::Signature = k.sign(payload);
+            Ok(signature.to_der().to_bytes().into())
+        }
+        SignatureScheme::ED25519 => {
+            let k = ed25519_dalek::SigningKey::try_from(private)
+                .map_err(|_| SignerError::SigningError)?;
+            let signature = k.sign(payload);
+            Ok(signature.to_bytes().into())
+        }
+        SignatureScheme::ML_DSA_65 => ml_dsa_sign::<MlDsa65>(private, payload),
+        SignatureScheme::ML_DSA_87 => ml_dsa_sign::<MlDsa87>(private, payload),
+        SignatureScheme::ML_DSA_65_ED25519 => {
+            let (post_quantum, classical) = signature_scheme
+                .composite_components()
+                .ok_or(SignerError::SigningError)?;
+            let private = CompositeValue::private_key(signature_scheme, private)
+                .map_err(SignerError::CryptoError)?;
+            let signature = CompositeValue {
+                post_quantum: &sign(post_quantum, private.post_quantum, payload)?,
+                classical: &sign(classical, private.classical, payload)?,
+            };
+            Ok(signature.to_vec())
+        }
+        _ => Err(SignerError::SigningError),
+    }
+}
+
+/// Sign the `payload` with the encoded ML-DSA signing key `private`.
+fn ml_dsa_sign<P: MlDsaParams>(private: &[u8], payload: &[u8]) -> Result<Vec<u8>, SignerError> {
+    let encoded =
+        ml_dsa::EncodedSigningKey::<P>::try_from(private).map_err(|_| SignerError::SigningError)?;
+    let signature = ml_dsa::SigningKey::<P>::decode(&encoded).sign(payload);
+    Ok(signature.encode().to_vec())
+}
+
+/// Generate a fresh ML-DSA key pair and return the encoded signing and
+/// verifying keys.
+fn ml_dsa_key_gen<P: MlDsaParams>() -> (Vec<u8>, Vec<u8>) {
+    let key_pair = P::key_gen(&mut OsRng);
+    (
+        key_pair.signing_key().encode().to_vec(),
+        key_pair.verifying_key().encode().to_vec(),
+    )
+}
+
 /// Compute the ID for a [`Signature`] in the key store.
 fn id(public_key: &[u8], signature_scheme: SignatureScheme) -> Vec<u8> {
     const LABEL: &[u8; 22] = b"RustCryptoSignatureKey";
@@ -76,22 +130,60 @@ fn id(public_key: &[u8], signature_scheme: SignatureScheme) -> Vec<u8> {
     id
 }
 
+/// Generate a fresh key pair of the `signature_scheme` and return the private
+/// and the public key.
+fn key_gen(signature_scheme: SignatureScheme) -> Result<(Vec<u8>, Vec<u8>), CryptoError> {
+    let key_pair = match signature_scheme {
+        SignatureScheme::ECDSA_SECP256R1_SHA256 => {
+            let k = SigningKey::random(&mut OsRng);
+            let pk = k.verifying_key().to_encoded_point(false).as_bytes().into();
+            (k.to_bytes().as_slice().into(), pk)
+        }
+        SignatureScheme::ECDSA_SECP384R1_SHA384 => {
+            let k = p384::ecdsa::SigningKey::random(&mut OsRng);
+            let pk = k.verifying_key().to_encoded_point(false).as_bytes().into();
+            (k.to_bytes().as_slice().into(), pk)
+        }
+        SignatureScheme::ECDSA_SECP521R1_SHA512 => {
+            let k = p521::ecdsa::SigningKey::random(&mut OsRng);
+            let pk = p521::ecdsa::VerifyingKey::from(&k)
+                .to_encoded_point(false)
+                .as_bytes()
+                .into();
+            (k.to_bytes().as_slice().into(), pk)
+        }
+        SignatureScheme::ED25519 => {
+            let sk = ed25519_dalek::SigningKey::generate(&mut OsRng);
+            let pk = sk.verifying_key().to_bytes().into();
+            (sk.to_bytes().into(), pk)
+        }
+        SignatureScheme::ML_DSA_65 => ml_dsa_key_gen::<MlDsa65>(),
+        SignatureScheme::ML_DSA_87 => ml_dsa_key_gen::<MlDsa87>(),
+        SignatureScheme::ML_DSA_65_ED25519 => {
+            let (post_quantum, classical) = signature_scheme
+                .composite_components()
+                .ok_or(CryptoError::UnsupportedSignatureScheme)?;
+            let (post_quantum_private, post_quantum_public) = key_gen(post_quantum)?;
+            let (classical_private, classical_public) = key_gen(classical)?;
+            let private = CompositeValue {
+                post_quantum: &post_quantum_private,
+                classical: &classical_private,
+            };
+            let public = CompositeValue {
+                post_quantum: &post_quantum_public,
+                classical: &classical_public,
+            };
+            (private.to_vec(), public.to_vec())
+        }
+        _ => return Err(CryptoError::UnsupportedSignatureScheme),
+    };
+    Ok(key_pair)
+}
+
 impl SignatureKeyPair {
     /// Generates a fresh signature keypair using the [`SignatureScheme`].
     pub fn new(signature_scheme: SignatureScheme) -> Result<Self, CryptoError> {
-        let (private, public) = match signature_scheme {
-            SignatureScheme::ECDSA_SECP256R1_SHA256 => {
-                let k = SigningKey::random(&mut OsRng);
-                let pk = k.verifying_key().to_encoded_point(false).as_bytes().into();
-                (k.to_bytes().as_slice().into(), pk)
-            }
-            SignatureScheme::ED25519 => {
-                let sk = ed25519_dalek::SigningKey::generate(&mut OsRng);
-                let pk = sk.verifying_key().to_bytes().into();
-                (sk.to_bytes().into(), pk)
-            }
-            _ => return Err(CryptoError::UnsupportedSignatureScheme),
-        };
+        let (private, public) = key_gen(signature_scheme)?;
 
         Ok(Self {
             private,
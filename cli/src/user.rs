@@ -397,7 +397,8 @@ impl User {
         let processed_message_credential: Credential = processed_message.credential().clone();
 
         let message_out = match processed_message.into_content() {
-            ProcessedMessageContent::ApplicationMessage(application_message) => {
+            ProcessedMessageContent::ApplicationMessage(application_message)
+            | ProcessedMessageContent::TargetedMessage(application_message) => {
                 let processed_message_credential =
                     BasicCredential::try_from(processed_message_credential.clone()).unwrap();
                 let sender_name = match self.contacts.get(processed_message_credential.identity()) {
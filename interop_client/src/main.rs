@@ -15,12 +15,15 @@ use mls_client::{
 use mls_interop_proto::mls_client;
 use openmls::{
     credentials::{BasicCredential, Credential, CredentialType, CredentialWithKey},
+    extensions::{Extensions, ExternalSender, SenderExtensionIndex},
     framing::{MlsMessageBodyIn, MlsMessageIn, MlsMessageOut, ProcessedMessageContent},
     group::{
-        GroupEpoch, GroupId, MlsGroup, MlsGroupCreateConfig, MlsGroupJoinConfig, StagedWelcome,
-        WireFormatPolicy, PURE_CIPHERTEXT_WIRE_FORMAT_POLICY, PURE_PLAINTEXT_WIRE_FORMAT_POLICY,
+        GroupEpoch, GroupId, MlsGroup, MlsGroupCreateConfig, MlsGroupJoinConfig, ProposalStore,
+        PublicGroup, StagedWelcome, WireFormatPolicy, PURE_CIPHERTEXT_WIRE_FORMAT_POLICY,
+        PURE_PLAINTEXT_WIRE_FORMAT_POLICY,
     },
     key_packages::{KeyPackage, KeyPackageBundle},
+    messages::{external_proposals::ExternalProposal, proposals::ReInitProposal},
     prelude::{Capabilities, ExtensionType, SenderRatchetConfiguration},
     schedule::{psk::ResumptionPskUsage, ExternalPsk, PreSharedKeyId, Psk},
     treesync::{LeafNodeParameters, RatchetTreeIn},
@@ -62,12 +65,21 @@ type PendingState = (
     OpenMlsRustCrypto,
 );
 
+/// This struct contains the state of an external signer, i.e. a party outside
+/// of any group that is listed in the `ExternalSendersExtension` of groups it
+/// sends proposals to.
+pub struct ExternalSigner {
+    signature_keys: SignatureKeyPair,
+    external_sender: ExternalSender,
+}
+
 /// This is the main state struct of the interop client. It keeps track of the
-/// individual MLS clients, as well as pending key packages that it was told to
-/// create. It also contains a transaction id map, that maps the `u32`
-/// transaction ids to key package hashes.
+/// individual MLS clients and external signers, as well as pending key packages
+/// that it was told to create. It also contains a transaction id map, that maps
+/// the `u32` transaction ids to key package hashes.
 pub struct MlsClientImpl {
     groups: Mutex<Vec<InteropGroup>>,
+    external_signers: Mutex<Vec<ExternalSigner>>,
     pending_state: Mutex<HashMap<Vec<u8>, PendingState>>,
     transaction_id_map: Mutex<HashMap<u32, Vec<u8>>>, // Indirection, linking to pending key packages
 }
@@ -77,6 +89,7 @@ impl MlsClientImpl {
     fn new() -> Self {
         MlsClientImpl {
             groups: Mutex::new(Vec::new()),
+            external_signers: Mutex::new(Vec::new()),
             pending_state: Mutex::new(HashMap::new()),
             transaction_id_map: Mutex::new(HashMap::new()),
         }
@@ -150,6 +163,24 @@ fn ratchet_tree_from_config(bytes: Vec<u8>) -> Option<RatchetTreeIn> {
     }
 }
 
+// A helper function translating the extensions in the protobuf to OpenMLS' Extensions
+fn extensions_from_proto(extensions: &[mls_client::Extension]) -> Result<Extensions, Status> {
+    let extensions = extensions
+        .iter()
+        .map(|extension| {
+            // An extension is encoded as its type followed by the opaque data.
+            let mut bytes = (extension.extension_type as u16).to_be_bytes().to_vec();
+            tls_codec::VLBytes::new(extension.extension_data.clone())
+                .tls_serialize(&mut bytes)
+                .map_err(|_| Status::invalid_argument("Invalid extension"))?;
+            openmls::extensions::Extension::tls_deserialize_exact(bytes)
+                .map_err(|_| Status::invalid_argument("Invalid extension"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Extensions::from_vec(extensions).map_err(into_status)
+}
+
 fn bytes_to_string<B>(bytes: B) -> String
 where
     B: AsRef<[u8]>,
@@ -663,6 +694,7 @@ impl MlsClient for MlsClientImpl {
             }
             ProcessedMessageContent::ProposalMessage(_) => unreachable!(),
             ProcessedMessageContent::ExternalJoinProposalMessage(_) => unreachable!(),
+            ProcessedMessageContent::TargetedMessage(_) => unreachable!(),
             ProcessedMessageContent::StagedCommitMessage(_) => unreachable!(),
         };
 
@@ -964,6 +996,7 @@ impl MlsClient for MlsClientImpl {
                         })?;
                 }
                 ProcessedMessageContent::ExternalJoinProposalMessage(_) => unreachable!(),
+                ProcessedMessageContent::TargetedMessage(_) => unreachable!(),
                 ProcessedMessageContent::StagedCommitMessage(_) => unreachable!(),
             }
         }
@@ -1148,6 +1181,7 @@ impl MlsClient for MlsClientImpl {
                         })?;
                 }
                 ProcessedMessageContent::ExternalJoinProposalMessage(_) => unreachable!(),
+                ProcessedMessageContent::TargetedMessage(_) => unreachable!(),
                 ProcessedMessageContent::StagedCommitMessage(_) => unreachable!(),
             }
         }
@@ -1175,6 +1209,7 @@ impl MlsClient for MlsClientImpl {
             ProcessedMessageContent::ApplicationMessage(_) => unreachable!(),
             ProcessedMessageContent::ProposalMessage(_) => unreachable!(),
             ProcessedMessageContent::ExternalJoinProposalMessage(_) => unreachable!(),
+            ProcessedMessageContent::TargetedMessage(_) => unreachable!(),
             ProcessedMessageContent::StagedCommitMessage(staged_commit) => {
                 debug!(commit=?staged_commit, "Merging staged commit.");
                 group
@@ -1425,25 +1460,262 @@ impl MlsClient for MlsClientImpl {
         todo!()
     }
 
+    #[instrument(skip_all)]
     async fn create_external_signer(
         &self,
-        _: Request<CreateExternalSignerRequest>,
+        request: Request<CreateExternalSignerRequest>,
     ) -> Result<Response<CreateExternalSignerResponse>, Status> {
-        todo!()
+        let request = request.get_ref();
+        info!(?request, "Request");
+
+        let ciphersuite = *to_ciphersuite(request.cipher_suite)?;
+        let credential = BasicCredential::new(request.identity.clone());
+        let signature_keys = SignatureKeyPair::new(ciphersuite.signature_algorithm()).unwrap();
+        let external_sender =
+            ExternalSender::new(signature_keys.public().into(), credential.into());
+
+        let serialized_external_sender = external_sender
+            .tls_serialize_detached()
+            .map_err(|_| Status::aborted("failed to serialize external sender"))?;
+
+        let mut external_signers = self.external_signers.lock().unwrap();
+        let signer_id = external_signers.len() as u32;
+        external_signers.push(ExternalSigner {
+            signature_keys,
+            external_sender,
+        });
+
+        let response = CreateExternalSignerResponse {
+            signer_id,
+            external_sender: serialized_external_sender,
+        };
+
+        info!(?response, "Response");
+        Ok(Response::new(response))
     }
 
+    #[instrument(skip_all)]
     async fn add_external_signer(
         &self,
-        _: Request<AddExternalSignerRequest>,
+        request: Request<AddExternalSignerRequest>,
     ) -> Result<Response<ProposalResponse>, Status> {
-        todo!()
+        let request = request.get_ref();
+        info!(?request, "Request");
+
+        let external_sender = ExternalSender::tls_deserialize_exact(&request.external_sender)
+            .map_err(|_| Status::invalid_argument("Invalid external sender"))?;
+
+        let mut groups = self.groups.lock().unwrap();
+        let interop_group = groups
+            .get_mut(request.state_id as usize)
+            .ok_or_else(|| Status::new(Code::InvalidArgument, "unknown state_id"))?;
+        trace!("   in epoch {:?}", interop_group.group.epoch());
+
+        // Append the new external sender to the existing ones.
+        let mut extensions = interop_group.group.extensions().clone();
+        let mut external_senders = extensions.external_senders().cloned().unwrap_or_default();
+        external_senders.push(external_sender);
+        extensions.add_or_replace(openmls::extensions::Extension::ExternalSenders(
+            external_senders,
+        ));
+
+        let (proposal, _proposal_ref) = interop_group
+            .group
+            .propose_group_context_extensions(
+                &interop_group.crypto_provider,
+                extensions,
+                &interop_group.signature_keys,
+            )
+            .map_err(into_status)?;
+
+        // Store the proposal for potential future use.
+        interop_group.messages_out.push(proposal.clone().into());
+
+        let proposal = proposal.to_bytes().unwrap();
+
+        let response = ProposalResponse { proposal };
+
+        info!(?response, "Response");
+        Ok(Response::new(response))
     }
 
+    #[instrument(skip_all)]
     async fn external_signer_proposal(
         &self,
-        _: Request<ExternalSignerProposalRequest>,
+        request: Request<ExternalSignerProposalRequest>,
     ) -> Result<Response<ProposalResponse>, Status> {
-        todo!()
+        let request = request.get_ref();
+        info!(?request, "Request");
+
+        let external_signers = self.external_signers.lock().unwrap();
+        let external_signer = external_signers
+            .get(request.signer_id as usize)
+            .ok_or_else(|| Status::new(Code::InvalidArgument, "unknown signer_id"))?;
+
+        debug!("Deserializing `MlsMessageIn` (to obtain group info).");
+        let verifiable_group_info = {
+            let msg = MlsMessageIn::tls_deserialize_exact(&request.group_info)
+                .map_err(|_| Status::invalid_argument("Invalid group info"))?;
+
+            match msg.extract() {
+                MlsMessageBodyIn::GroupInfo(verifiable_group_info) => verifiable_group_info,
+                _ => return Err(Status::invalid_argument("Message was not a group info")),
+            }
+        };
+        let ratchet_tree = ratchet_tree_from_config(request.ratchet_tree.clone())
+            .or_else(|| {
+                verifiable_group_info
+                    .extensions()
+                    .ratchet_tree()
+                    .map(|extension| extension.ratchet_tree().clone())
+            })
+            .ok_or_else(|| Status::invalid_argument("Missing ratchet tree"))?;
+
+        // Track the group as a public group to learn about its members and
+        // external senders.
+        let provider = OpenMlsRustCrypto::default();
+        let (public_group, _group_info) = PublicGroup::from_external(
+            provider.crypto(),
+            provider.storage(),
+            ratchet_tree,
+            verifiable_group_info,
+            ProposalStore::new(),
+        )
+        .map_err(into_status)?;
+        let group_id = public_group.group_id().clone();
+        let epoch = public_group.group_context().epoch();
+        let ciphersuite = public_group.ciphersuite();
+
+        let sender_index = public_group
+            .group_context()
+            .extensions()
+            .external_senders()
+            .and_then(|external_senders| {
+                external_senders
+                    .iter()
+                    .position(|sender| sender == &external_signer.external_sender)
+            })
+            .map(|index| SenderExtensionIndex::new(index as u32))
+            .ok_or_else(|| Status::failed_precondition("Not an external sender of the group"))?;
+
+        let description = request
+            .description
+            .as_ref()
+            .ok_or_else(|| Status::invalid_argument("Missing proposal description"))?;
+        let proposal_type = String::from_utf8_lossy(&description.proposal_type).to_string();
+        trace!(r#type = proposal_type, "Creating external proposal.");
+
+        let signer = &external_signer.signature_keys;
+        let proposal = match proposal_type.as_ref() {
+            "add" => {
+                let key_package = MlsMessageIn::tls_deserialize_exact(&description.key_package)
+                    .map_err(|_| Status::invalid_argument("Invalid key package"))?
+                    .into_keypackage()
+                    .ok_or(Status::invalid_argument("Message was not a key package"))?;
+
+                ExternalProposal::new_add::<OpenMlsRustCrypto>(
+                    key_package,
+                    group_id,
+                    epoch,
+                    signer,
+                    sender_index,
+                )
+                .map_err(into_status)?
+            }
+            "remove" => {
+                let removed_credential: Credential =
+                    BasicCredential::new(description.removed_id.clone()).into();
+                let removed = public_group
+                    .members()
+                    .find(|member| member.credential == removed_credential)
+                    .map(|member| member.index)
+                    .ok_or_else(|| Status::invalid_argument("Unknown member"))?;
+
+                ExternalProposal::new_remove::<OpenMlsRustCrypto>(
+                    removed,
+                    group_id,
+                    epoch,
+                    signer,
+                    sender_index,
+                )
+                .map_err(into_status)?
+            }
+            "externalPSK" => {
+                let psk_id = PreSharedKeyId::new(
+                    ciphersuite,
+                    provider.rand(),
+                    Psk::External(ExternalPsk::new(description.psk_id.clone())),
+                )
+                .map_err(|_| Status::internal("unable to create PreSharedKeyId"))?;
+
+                ExternalProposal::new_psk::<OpenMlsRustCrypto>(
+                    psk_id,
+                    group_id,
+                    epoch,
+                    signer,
+                    sender_index,
+                )
+                .map_err(into_status)?
+            }
+            "resumptionPSK" => {
+                let psk_nonce = provider
+                    .rand()
+                    .random_vec(ciphersuite.hash_length())
+                    .map_err(|_| Status::internal("unable to create PSK nonce"))?;
+                let psk_id = PreSharedKeyId::resumption(
+                    ResumptionPskUsage::Application,
+                    group_id.clone(),
+                    GroupEpoch::from(description.epoch_id),
+                    psk_nonce,
+                );
+
+                ExternalProposal::new_psk::<OpenMlsRustCrypto>(
+                    psk_id,
+                    group_id,
+                    epoch,
+                    signer,
+                    sender_index,
+                )
+                .map_err(into_status)?
+            }
+            "groupContextExtensions" => {
+                let extensions = extensions_from_proto(&description.extensions)?;
+
+                ExternalProposal::new_group_context_extensions::<OpenMlsRustCrypto>(
+                    extensions,
+                    group_id,
+                    epoch,
+                    signer,
+                    sender_index,
+                )
+                .map_err(into_status)?
+            }
+            "reinit" => {
+                let reinit = ReInitProposal::new(
+                    GroupId::from_slice(&description.group_id),
+                    ProtocolVersion::Mls10,
+                    *to_ciphersuite(description.cipher_suite)?,
+                    extensions_from_proto(&description.extensions)?,
+                );
+
+                ExternalProposal::new_reinit::<OpenMlsRustCrypto>(
+                    reinit,
+                    group_id,
+                    epoch,
+                    signer,
+                    sender_index,
+                )
+                .map_err(into_status)?
+            }
+            _ => return Err(Status::invalid_argument("Invalid proposal type")),
+        };
+
+        let proposal = proposal.to_bytes().unwrap();
+
+        let response = ProposalResponse { proposal };
+
+        info!(?response, "Response");
+        Ok(Response::new(response))
     }
 
     async fn free(&self, _request: Request<FreeRequest>) -> Result<Response<FreeResponse>, Status> {
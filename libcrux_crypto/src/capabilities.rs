@@ -0,0 +1,180 @@
+//! Reports on the capabilities of the [`CryptoProvider`].
+//!
+//! The [`CryptoProvider`] supports the same ciphersuites on every machine.
+//! Some primitives are implemented with CPU features like AES-NI or AVX2 when
+//! they are detected at runtime, and fall back to portable implementations
+//! otherwise. A [`CapabilityReport`] lists the supported ciphersuites and the
+//! implementation that is used for each primitive on the current machine.
+
+use openmls_traits::types::Ciphersuite;
+
+use crate::{crypto::SUPPORTED_CIPHERSUITES, CryptoProvider};
+
+/// A cryptographic primitive used by the [`CryptoProvider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Primitive {
+    /// AES-GCM with 128 and 256 bit keys.
+    AesGcm,
+    /// ChaCha20-Poly1305.
+    ChaCha20Poly1305,
+    /// SHA-256, SHA-384 and SHA-512, and HKDF on top of them.
+    Sha2,
+    /// X25519, on its own and as part of X-Wing.
+    X25519,
+    /// ECDH and ECDSA on the NIST P-256 curve.
+    P256,
+    /// Ed25519 signatures.
+    Ed25519,
+    /// ML-KEM encapsulation.
+    MlKem,
+    /// ML-DSA signatures.
+    MlDsa,
+}
+
+/// The implementation of a [`Primitive`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Implementation {
+    /// An implementation that uses CPU features which are available on this
+    /// machine.
+    HardwareAccelerated,
+    /// A portable implementation.
+    Software,
+}
+
+/// A [`Primitive`], its [`Implementation`] and an explanation why that
+/// implementation is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimitiveReport {
+    primitive: Primitive,
+    implementation: Implementation,
+    description: &'static str,
+}
+
+impl PrimitiveReport {
+    fn new(
+        primitive: Primitive,
+        accelerated: bool,
+        hardware: &'static str,
+        software: &'static str,
+    ) -> Self {
+        let (implementation, description) = if accelerated {
+            (Implementation::HardwareAccelerated, hardware)
+        } else {
+            (Implementation::Software, software)
+        };
+        Self {
+            primitive,
+            implementation,
+            description,
+        }
+    }
+
+    fn portable(primitive: Primitive, description: &'static str) -> Self {
+        Self {
+            primitive,
+            implementation: Implementation::Software,
+            description,
+        }
+    }
+
+    /// Returns the primitive.
+    pub fn primitive(&self) -> Primitive {
+        self.primitive
+    }
+
+    /// Returns the implementation that is used for the primitive.
+    pub fn implementation(&self) -> Implementation {
+        self.implementation
+    }
+
+    /// Returns a human-readable explanation of the implementation.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+}
+
+/// The capabilities of the [`CryptoProvider`] on the current machine. See
+/// [`CryptoProvider::capability_report()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapabilityReport {
+    ciphersuites: Vec<Ciphersuite>,
+    primitives: Vec<PrimitiveReport>,
+}
+
+impl CapabilityReport {
+    /// Returns the supported ciphersuites. They are the same on every
+    /// machine.
+    pub fn ciphersuites(&self) -> &[Ciphersuite] {
+        &self.ciphersuites
+    }
+
+    /// Returns the reports for all primitives.
+    pub fn primitives(&self) -> &[PrimitiveReport] {
+        &self.primitives
+    }
+
+    /// Returns the implementation that is used for the `primitive`.
+    pub fn implementation(&self, primitive: Primitive) -> Implementation {
+        self.primitives
+            .iter()
+            .find(|report| report.primitive == primitive)
+            .map(PrimitiveReport::implementation)
+            .unwrap_or(Implementation::Software)
+    }
+
+    /// Returns `true` if the `primitive` is hardware-accelerated.
+    pub fn is_hardware_accelerated(&self, primitive: Primitive) -> bool {
+        self.implementation(primitive) == Implementation::HardwareAccelerated
+    }
+}
+
+impl CryptoProvider {
+    /// Returns a report of the supported ciphersuites and of the primitives
+    /// that are hardware-accelerated on this machine.
+    ///
+    /// The CPU features are detected at runtime, so the report may differ
+    /// between machines. The supported ciphersuites don't.
+    pub fn capability_report(&self) -> CapabilityReport {
+        let primitives = vec![
+            PrimitiveReport::new(
+                Primitive::AesGcm,
+                self.aes_support(),
+                "libcrux AES-GCM using AES-NI and PCLMULQDQ",
+                "portable AES-GCM from the RustCrypto aes-gcm crate",
+            ),
+            PrimitiveReport::new(
+                Primitive::ChaCha20Poly1305,
+                libcrux_platform::simd256_support() || libcrux_platform::simd128_support(),
+                "libcrux ChaCha20-Poly1305 using AVX2 or 128 bit SIMD instructions",
+                "portable libcrux ChaCha20-Poly1305",
+            ),
+            PrimitiveReport::portable(Primitive::Sha2, "portable libcrux SHA-2"),
+            PrimitiveReport::new(
+                Primitive::X25519,
+                libcrux_platform::x25519_support() && cfg!(target_arch = "x86_64"),
+                "libcrux X25519 using BMI2 and ADX",
+                "portable libcrux X25519",
+            ),
+            PrimitiveReport::portable(Primitive::P256, "portable libcrux P-256"),
+            PrimitiveReport::portable(Primitive::Ed25519, "portable libcrux Ed25519"),
+            PrimitiveReport::new(
+                Primitive::MlKem,
+                libcrux_platform::simd256_support()
+                    || (libcrux_platform::simd128_support() && cfg!(target_arch = "aarch64")),
+                "libcrux ML-KEM using AVX2 or NEON",
+                "portable libcrux ML-KEM",
+            ),
+            PrimitiveReport::new(
+                Primitive::MlDsa,
+                libcrux_platform::simd256_support(),
+                "libcrux ML-DSA using AVX2",
+                "portable libcrux ML-DSA",
+            ),
+        ];
+
+        CapabilityReport {
+            ciphersuites: SUPPORTED_CIPHERSUITES.to_vec(),
+            primitives,
+        }
+    }
+}
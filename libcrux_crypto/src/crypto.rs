@@ -4,14 +4,45 @@ use libcrux::drbg::{Drbg, RngCore};
 use libcrux::hpke::{self, HPKEConfig};
 use openmls_traits::crypto::OpenMlsCrypto;
 use openmls_traits::types::{
-    AeadType, Ciphersuite, CryptoError, ExporterSecret, HashType, HpkeAeadType, HpkeCiphertext,
-    HpkeConfig, HpkeKdfType, HpkeKemType, HpkeKeyPair, KemOutput, SignatureScheme,
+    AeadType, Ciphersuite, CompositeValue, CryptoError, ExporterSecret, HashType, HpkeAeadType,
+    HpkeCiphertext, HpkeConfig, HpkeKdfType, HpkeKemType, HpkeKeyPair, KemOutput, SignatureScheme,
 };
 
 use rand::CryptoRng;
 use tls_codec::SecretVLBytes;
 
+use crate::{mldsa, mlkem, software_aead};
+
+/// The ciphersuites supported by the [`CryptoProvider`].
+///
+/// The list is the same on every machine. Primitives without hardware support
+/// fall back to portable implementations, see
+/// [`CryptoProvider::capability_report()`].
+pub(crate) const SUPPORTED_CIPHERSUITES: [Ciphersuite; 7] = [
+    Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519,
+    Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519,
+    Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256,
+    Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519,
+    Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65,
+    Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87,
+    Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519,
+];
+
 /// The libcrux-backed cryptography provider for OpenMLS
+///
+/// The provider supports the same ciphersuites on every x86_64 and aarch64
+/// machine:
+///
+/// * `MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519`
+/// * `MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519`
+/// * `MLS_128_DHKEMP256_AES128GCM_SHA256_P256`
+/// * `MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519`
+/// * `MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65`
+/// * `MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87`
+/// * `MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519`
+///
+/// AES-GCM uses AES-NI if it is available and a portable implementation
+/// otherwise.
 pub struct CryptoProvider {
     drbg: Mutex<Drbg>,
 }
@@ -30,23 +61,39 @@ impl Default for CryptoProvider {
 
 impl CryptoProvider {
     #[inline(always)]
-    fn aes_support(&self) -> bool {
+    pub(crate) fn aes_support(&self) -> bool {
         libcrux::aes_ni_support() && cfg!(target_arch = "x86_64")
     }
+
+    /// Returns `true` if the `alg` has to use the portable AES-GCM, because
+    /// libcrux can't use AES-NI on this machine.
+    fn software_aes_gcm(&self, alg: AeadType) -> bool {
+        software_aead::is_aes_gcm(alg) && !self.aes_support()
+    }
+
+    fn random_array<const N: usize>(&self) -> Result<[u8; N], CryptoError> {
+        let mut out = [0u8; N];
+        self.drbg
+            .lock()
+            .map_err(|_| CryptoError::CryptoLibraryError)?
+            .try_fill_bytes(&mut out)
+            .map_err(|_| CryptoError::InsufficientRandomness)?;
+        Ok(out)
+    }
 }
 
 impl OpenMlsCrypto for CryptoProvider {
     fn supports(&self, ciphersuite: Ciphersuite) -> Result<(), CryptoError> {
-        match (ciphersuite.aead_algorithm(), self.aes_support()) {
-            (AeadType::Aes128Gcm, true)
-            | (AeadType::Aes256Gcm, true)
-            | (AeadType::ChaCha20Poly1305, true)
-            | (AeadType::ChaCha20Poly1305, false) => Ok(()),
-            _ => Err(CryptoError::UnsupportedCiphersuite),
+        match ciphersuite.aead_algorithm() {
+            AeadType::Aes128Gcm | AeadType::Aes256Gcm | AeadType::ChaCha20Poly1305 => Ok(()),
         }?;
 
         match ciphersuite.signature_algorithm() {
-            SignatureScheme::ECDSA_SECP256R1_SHA256 | SignatureScheme::ED25519 => Ok(()),
+            SignatureScheme::ECDSA_SECP256R1_SHA256
+            | SignatureScheme::ED25519
+            | SignatureScheme::ML_DSA_65
+            | SignatureScheme::ML_DSA_87
+            | SignatureScheme::ML_DSA_65_ED25519 => Ok(()),
             _ => Err(CryptoError::UnsupportedCiphersuite),
         }?;
 
@@ -55,28 +102,17 @@ impl OpenMlsCrypto for CryptoProvider {
         }?;
 
         match ciphersuite.hpke_aead_algorithm() {
-            HpkeAeadType::ChaCha20Poly1305 => Ok(()),
-            HpkeAeadType::AesGcm128 | HpkeAeadType::AesGcm256 if self.aes_support() => Ok(()),
-            _ => Err(CryptoError::UnsupportedCiphersuite),
+            HpkeAeadType::ChaCha20Poly1305 | HpkeAeadType::AesGcm128 | HpkeAeadType::AesGcm256 => {
+                Ok(())
+            }
+            HpkeAeadType::Export => Err(CryptoError::UnsupportedCiphersuite),
         }?;
 
         Ok(())
     }
 
     fn supported_ciphersuites(&self) -> Vec<Ciphersuite> {
-        if self.aes_support() {
-            vec![
-                Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519,
-                Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519,
-                Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256,
-                Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519,
-            ]
-        } else {
-            vec![
-                Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519,
-                Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519,
-            ]
-        }
+        SUPPORTED_CIPHERSUITES.to_vec()
     }
 
     fn hkdf_extract(
@@ -125,6 +161,10 @@ impl OpenMlsCrypto for CryptoProvider {
         nonce: &[u8],
         aad: &[u8],
     ) -> Result<Vec<u8>, CryptoError> {
+        if self.software_aes_gcm(alg) {
+            return software_aead::encrypt(alg, key, data, nonce, aad);
+        }
+
         // only fails on wrong length
         let iv = libcrux::aead::Iv::new(nonce).map_err(|err| match err {
             libcrux::aead::InvalidArgumentError::InvalidIv => CryptoError::InvalidLength,
@@ -152,6 +192,10 @@ impl OpenMlsCrypto for CryptoProvider {
         nonce: &[u8],
         aad: &[u8],
     ) -> Result<Vec<u8>, CryptoError> {
+        if self.software_aes_gcm(alg) {
+            return software_aead::decrypt(alg, key, ct_tag, nonce, aad);
+        }
+
         if ct_tag.len() < 16 || nonce.len() != 12 {
             return Err(CryptoError::InvalidLength);
         }
@@ -176,6 +220,25 @@ impl OpenMlsCrypto for CryptoProvider {
     }
 
     fn signature_key_gen(&self, alg: SignatureScheme) -> Result<(Vec<u8>, Vec<u8>), CryptoError> {
+        if let Some((post_quantum, classical)) = alg.composite_components() {
+            let (post_quantum_private, post_quantum_public) =
+                self.signature_key_gen(post_quantum)?;
+            let (classical_private, classical_public) = self.signature_key_gen(classical)?;
+            let private = CompositeValue {
+                post_quantum: &post_quantum_private,
+                classical: &classical_private,
+            };
+            let public = CompositeValue {
+                post_quantum: &post_quantum_public,
+                classical: &classical_public,
+            };
+            return Ok((private.to_vec(), public.to_vec()));
+        }
+
+        if mldsa::is_ml_dsa(alg) {
+            return mldsa::key_gen(alg, self.random_array()?);
+        }
+
         let alg = sig_alg(alg)?;
         let mut rng = self
             .drbg
@@ -193,11 +256,35 @@ impl OpenMlsCrypto for CryptoProvider {
         pk: &[u8],
         signature: &[u8],
     ) -> Result<(), CryptoError> {
+        if let Some((post_quantum, classical)) = alg.composite_components() {
+            let pk = CompositeValue::public_key(alg, pk)?;
+            let signature = CompositeValue::signature(alg, signature)?;
+            self.verify_signature(post_quantum, data, pk.post_quantum, signature.post_quantum)?;
+            return self.verify_signature(classical, data, pk.classical, signature.classical);
+        }
+
+        if mldsa::is_ml_dsa(alg) {
+            return mldsa::verify(alg, data, pk, signature);
+        }
+
         let signature = sig(alg, signature)?;
         libcrux::signature::verify(data, &signature, pk).map_err(|_| CryptoError::InvalidSignature)
     }
 
     fn sign(&self, alg: SignatureScheme, data: &[u8], key: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        if let Some((post_quantum, classical)) = alg.composite_components() {
+            let key = CompositeValue::private_key(alg, key)?;
+            let signature = CompositeValue {
+                post_quantum: &self.sign(post_quantum, data, key.post_quantum)?,
+                classical: &self.sign(classical, data, key.classical)?,
+            };
+            return Ok(signature.to_vec());
+        }
+
+        if mldsa::is_ml_dsa(alg) {
+            return mldsa::sign(alg, data, key, self.random_array()?);
+        }
+
         let alg = sig_alg(alg)?;
         let drbg = self
             .drbg
@@ -217,7 +304,12 @@ impl OpenMlsCrypto for CryptoProvider {
         aad: &[u8],
         ptxt: &[u8],
     ) -> Result<HpkeCiphertext, CryptoError> {
-        let config = hpke_config(config);
+        if mlkem::is_ml_kem(config.0) {
+            return mlkem::seal(self, config, pk_r, info, aad, ptxt, self.random_array()?);
+        }
+
+        let aead = aead_type(config.2)?;
+        let config = hpke_config(config)?;
         let randomness = {
             let mut rng = self
                 .drbg
@@ -230,6 +322,20 @@ impl OpenMlsCrypto for CryptoProvider {
         let pk_r = libcrux::hpke::kem::DeserializePublicKey(config.1, pk_r)
             .map_err(|_| CryptoError::CryptoLibraryError)?;
 
+        // Without AES-NI, only the key schedule is done by libcrux and the
+        // encryption by the portable AES-GCM.
+        if self.software_aes_gcm(aead) {
+            let (kem_output, (key, base_nonce, _, _)) =
+                libcrux::hpke::SetupBaseS(config, &pk_r, info, randomness)
+                    .map_err(|_| CryptoError::SenderSetupError)?;
+            let ciphertext = software_aead::encrypt(aead, &key, ptxt, &base_nonce, aad)?;
+
+            return Ok(HpkeCiphertext {
+                kem_output: kem_output.into(),
+                ciphertext: ciphertext.into(),
+            });
+        }
+
         let libcrux::hpke::HPKECiphertext(kem_output, ciphertext) =
             libcrux::hpke::HpkeSeal(config, &pk_r, info, aad, ptxt, None, None, None, randomness)
                 .map_err(|e| match e {
@@ -258,7 +364,27 @@ impl OpenMlsCrypto for CryptoProvider {
         info: &[u8],
         aad: &[u8],
     ) -> Result<Vec<u8>, CryptoError> {
-        let config = hpke_config(config);
+        if mlkem::is_ml_kem(config.0) {
+            return mlkem::open(self, config, input, sk_r, info, aad);
+        }
+
+        let aead = aead_type(config.2)?;
+        let config = hpke_config(config)?;
+
+        if self.software_aes_gcm(aead) {
+            let (key, base_nonce, _, _) =
+                libcrux::hpke::SetupBaseR(config, input.kem_output.as_slice(), sk_r, info)
+                    .map_err(|_| CryptoError::HpkeDecryptionError)?;
+            return software_aead::decrypt(
+                aead,
+                &key,
+                input.ciphertext.as_slice(),
+                &base_nonce,
+                aad,
+            )
+            .map_err(|_| CryptoError::HpkeDecryptionError);
+        }
+
         let ctxt = libcrux::hpke::HPKECiphertext(
             input.kem_output.as_ref().to_vec(),
             input.ciphertext.as_ref().to_vec(),
@@ -284,7 +410,18 @@ impl OpenMlsCrypto for CryptoProvider {
         exporter_context: &[u8],
         exporter_length: usize,
     ) -> Result<(KemOutput, ExporterSecret), CryptoError> {
-        let config = hpke_config(config);
+        if mlkem::is_ml_kem(config.0) {
+            return mlkem::setup_sender_and_export(
+                config,
+                pk_r,
+                info,
+                exporter_context,
+                exporter_length,
+                self.random_array()?,
+            );
+        }
+
+        let config = hpke_config(config)?;
         let randomness = self
             .drbg
             .lock()
@@ -312,7 +449,18 @@ impl OpenMlsCrypto for CryptoProvider {
         exporter_context: &[u8],
         exporter_length: usize,
     ) -> Result<ExporterSecret, CryptoError> {
-        let config = hpke_config(config);
+        if mlkem::is_ml_kem(config.0) {
+            return mlkem::setup_receiver_and_export(
+                config,
+                enc,
+                sk_r,
+                info,
+                exporter_context,
+                exporter_length,
+            );
+        }
+
+        let config = hpke_config(config)?;
 
         let ctx = libcrux::hpke::SetupBaseR(config, enc, sk_r, info)
             .map_err(|_| CryptoError::ReceiverSetupError)?;
@@ -327,7 +475,11 @@ impl OpenMlsCrypto for CryptoProvider {
         config: HpkeConfig,
         ikm: &[u8],
     ) -> Result<HpkeKeyPair, CryptoError> {
-        let config = hpke_config(config);
+        if mlkem::is_ml_kem(config.0) {
+            return mlkem::derive_key_pair(config, ikm);
+        }
+
+        let config = hpke_config(config)?;
         let HPKEConfig(_, alg, _, _) = config;
         let (sk, pk) = hpke::kem::DeriveKeyPair(alg, ikm).map_err(|e| match e {
             hpke::errors::HpkeError::InvalidParameters => CryptoError::InvalidLength,
@@ -410,13 +562,23 @@ fn sig_alg(alg: SignatureScheme) -> Result<libcrux::signature::Algorithm, Crypto
     }
 }
 
-fn hpke_config(config: HpkeConfig) -> libcrux::hpke::HPKEConfig {
-    libcrux::hpke::HPKEConfig(
+fn hpke_config(config: HpkeConfig) -> Result<libcrux::hpke::HPKEConfig, CryptoError> {
+    Ok(libcrux::hpke::HPKEConfig(
         libcrux::hpke::Mode::mode_base,
-        hpke_kem(config.0),
+        hpke_kem(config.0)?,
         hpke_kdf(config.1),
         hpke_aead(config.2),
-    )
+    ))
+}
+
+/// Returns the AEAD of the `aead` HPKE AEAD.
+pub(crate) fn aead_type(aead: HpkeAeadType) -> Result<AeadType, CryptoError> {
+    match aead {
+        HpkeAeadType::AesGcm128 => Ok(AeadType::Aes128Gcm),
+        HpkeAeadType::AesGcm256 => Ok(AeadType::Aes256Gcm),
+        HpkeAeadType::ChaCha20Poly1305 => Ok(AeadType::ChaCha20Poly1305),
+        HpkeAeadType::Export => Err(CryptoError::UnsupportedAeadAlgorithm),
+    }
 }
 
 fn hpke_kdf(kdf: HpkeKdfType) -> libcrux::hpke::kdf::KDF {
@@ -427,14 +589,16 @@ fn hpke_kdf(kdf: HpkeKdfType) -> libcrux::hpke::kdf::KDF {
     }
 }
 
-fn hpke_kem(kem: HpkeKemType) -> libcrux::hpke::kem::KEM {
+fn hpke_kem(kem: HpkeKemType) -> Result<libcrux::hpke::kem::KEM, CryptoError> {
     match kem {
-        HpkeKemType::DhKemP256 => libcrux::hpke::kem::KEM::DHKEM_P256_HKDF_SHA256,
-        HpkeKemType::DhKemP384 => libcrux::hpke::kem::KEM::DHKEM_P384_HKDF_SHA384,
-        HpkeKemType::DhKemP521 => libcrux::hpke::kem::KEM::DHKEM_P521_HKDF_SHA512,
-        HpkeKemType::DhKem25519 => libcrux::hpke::kem::KEM::DHKEM_X25519_HKDF_SHA256,
-        HpkeKemType::DhKem448 => libcrux::hpke::kem::KEM::DHKEM_X448_HKDF_SHA512,
-        HpkeKemType::XWingKemDraft2 => libcrux::hpke::kem::KEM::XWingDraft02,
+        HpkeKemType::DhKemP256 => Ok(libcrux::hpke::kem::KEM::DHKEM_P256_HKDF_SHA256),
+        HpkeKemType::DhKemP384 => Ok(libcrux::hpke::kem::KEM::DHKEM_P384_HKDF_SHA384),
+        HpkeKemType::DhKemP521 => Ok(libcrux::hpke::kem::KEM::DHKEM_P521_HKDF_SHA512),
+        HpkeKemType::DhKem25519 => Ok(libcrux::hpke::kem::KEM::DHKEM_X25519_HKDF_SHA256),
+        HpkeKemType::DhKem448 => Ok(libcrux::hpke::kem::KEM::DHKEM_X448_HKDF_SHA512),
+        HpkeKemType::XWingKemDraft2 => Ok(libcrux::hpke::kem::KEM::XWingDraft02),
+        // ML-KEM is handled in the `mlkem` module.
+        HpkeKemType::MlKem768 | HpkeKemType::MlKem1024 => Err(CryptoError::UnsupportedCiphersuite),
     }
 }
 
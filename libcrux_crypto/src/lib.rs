@@ -1,8 +1,13 @@
 use openmls_traits::OpenMlsProvider;
 
+mod capabilities;
 mod crypto;
+mod mldsa;
+mod mlkem;
 mod rand;
+mod software_aead;
 
+pub use capabilities::{CapabilityReport, Implementation, Primitive, PrimitiveReport};
 pub use crypto::CryptoProvider;
 pub use rand::RandError;
 pub use rand::RandProvider;
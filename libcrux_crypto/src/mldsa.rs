@@ -0,0 +1,106 @@
+//! ML-DSA signatures.
+//!
+//! Signatures are created in the pure (non-prehash) mode of FIPS 204 with an
+//! empty context string.
+
+use libcrux_ml_dsa::{ml_dsa_65, ml_dsa_87};
+use openmls_traits::types::{CryptoError, SignatureScheme};
+
+/// The length of the randomness used for the key generation and signing.
+pub(crate) const RANDOMNESS_LENGTH: usize = 32;
+
+/// The context string for all signatures.
+const CONTEXT: &[u8] = b"";
+
+/// Returns `true` if the `alg` is handled by this module.
+pub(crate) fn is_ml_dsa(alg: SignatureScheme) -> bool {
+    matches!(alg, SignatureScheme::ML_DSA_65 | SignatureScheme::ML_DSA_87)
+}
+
+/// Generates a key pair and returns the signing and the verification key.
+pub(crate) fn key_gen(
+    alg: SignatureScheme,
+    randomness: [u8; RANDOMNESS_LENGTH],
+) -> Result<(Vec<u8>, Vec<u8>), CryptoError> {
+    match alg {
+        SignatureScheme::ML_DSA_65 => {
+            let key_pair = ml_dsa_65::generate_key_pair(randomness);
+            Ok((
+                key_pair.signing_key.as_slice().to_vec(),
+                key_pair.verification_key.as_slice().to_vec(),
+            ))
+        }
+        SignatureScheme::ML_DSA_87 => {
+            let key_pair = ml_dsa_87::generate_key_pair(randomness);
+            Ok((
+                key_pair.signing_key.as_slice().to_vec(),
+                key_pair.verification_key.as_slice().to_vec(),
+            ))
+        }
+        _ => Err(CryptoError::UnsupportedSignatureScheme),
+    }
+}
+
+/// Signs the `data` with the signing `key`.
+pub(crate) fn sign(
+    alg: SignatureScheme,
+    data: &[u8],
+    key: &[u8],
+    randomness: [u8; RANDOMNESS_LENGTH],
+) -> Result<Vec<u8>, CryptoError> {
+    match alg {
+        SignatureScheme::ML_DSA_65 => {
+            let key = ml_dsa_65::MLDSA65SigningKey::new(
+                key.try_into().map_err(|_| CryptoError::InvalidLength)?,
+            );
+            ml_dsa_65::sign(&key, data, CONTEXT, randomness)
+                .map(|signature| signature.as_slice().to_vec())
+                .map_err(|_| CryptoError::SigningError)
+        }
+        SignatureScheme::ML_DSA_87 => {
+            let key = ml_dsa_87::MLDSA87SigningKey::new(
+                key.try_into().map_err(|_| CryptoError::InvalidLength)?,
+            );
+            ml_dsa_87::sign(&key, data, CONTEXT, randomness)
+                .map(|signature| signature.as_slice().to_vec())
+                .map_err(|_| CryptoError::SigningError)
+        }
+        _ => Err(CryptoError::UnsupportedSignatureScheme),
+    }
+}
+
+/// Verifies the `signature` on the `data` with the verification key `pk`.
+pub(crate) fn verify(
+    alg: SignatureScheme,
+    data: &[u8],
+    pk: &[u8],
+    signature: &[u8],
+) -> Result<(), CryptoError> {
+    match alg {
+        SignatureScheme::ML_DSA_65 => {
+            let pk = ml_dsa_65::MLDSA65VerificationKey::new(
+                pk.try_into().map_err(|_| CryptoError::InvalidPublicKey)?,
+            );
+            let signature = ml_dsa_65::MLDSA65Signature::new(
+                signature
+                    .try_into()
+                    .map_err(|_| CryptoError::InvalidSignature)?,
+            );
+            ml_dsa_65::verify(&pk, data, CONTEXT, &signature)
+                .map_err(|_| CryptoError::InvalidSignature)
+        }
+        SignatureScheme::ML_DSA_87 => {
+            let pk = ml_dsa_87::MLDSA87VerificationKey::new(
+                pk.try_into().map_err(|_| CryptoError::InvalidPublicKey)?,
+            );
+            let signature = ml_dsa_87::MLDSA87Signature::new(
+                signature
+                    .try_into()
+                    .map_err(|_| CryptoError::InvalidSignature)?,
+            );
+            ml_dsa_87::verify(&pk, data, CONTEXT, &signature)
+                .map_err(|_| CryptoError::InvalidSignature)
+        }
+        _ => Err(CryptoError::UnsupportedSignatureScheme),
+    }
+}
@@ -0,0 +1,350 @@
+//! HPKE with ML-KEM.
+//!
+//! The HPKE implementation in libcrux doesn't support ML-KEM as a KEM. This
+//! module implements the HPKE base mode from RFC 9180 on top of libcrux's
+//! ML-KEM, HKDF and AEAD, for the single-shot encryption and the secret export
+//! that MLS needs.
+//!
+//! ML-KEM private keys are represented by the 64 byte seed they are generated
+//! from. Key pairs are derived from the input keying material with the HPKE
+//! KDF, in the same way DHKEM derives its key pairs.
+
+use libcrux_ml_kem::{mlkem1024, mlkem768, MlKemSharedSecret};
+use openmls_traits::{
+    crypto::OpenMlsCrypto,
+    types::{
+        CryptoError, ExporterSecret, HpkeCiphertext, HpkeConfig, HpkeKdfType, HpkeKemType,
+        HpkeKeyPair, KemOutput,
+    },
+};
+
+use crate::crypto::aead_type;
+
+/// The length of the seed ML-KEM key pairs are generated from.
+const KEY_GENERATION_SEED_LENGTH: usize = 64;
+
+/// The length of the randomness used for the encapsulation.
+pub(crate) const ENCAPSULATION_RANDOMNESS_LENGTH: usize = 32;
+
+/// The length of the nonce of all supported AEADs.
+const NONCE_LENGTH: usize = 12;
+
+/// Returns `true` if the `kem` is handled by this module.
+pub(crate) fn is_ml_kem(kem: HpkeKemType) -> bool {
+    matches!(kem, HpkeKemType::MlKem768 | HpkeKemType::MlKem1024)
+}
+
+/// Encrypts the `ptxt` to the public key `pk_r`.
+pub(crate) fn seal(
+    crypto: &impl OpenMlsCrypto,
+    config: HpkeConfig,
+    pk_r: &[u8],
+    info: &[u8],
+    aad: &[u8],
+    ptxt: &[u8],
+    randomness: [u8; ENCAPSULATION_RANDOMNESS_LENGTH],
+) -> Result<HpkeCiphertext, CryptoError> {
+    let (kem_output, shared_secret) = encapsulate(config.0, pk_r, randomness)?;
+    let context = KeySchedule::new(&config, &shared_secret, info)?;
+    let ciphertext = crypto.aead_encrypt(
+        aead_type(config.2)?,
+        &context.key,
+        ptxt,
+        &context.base_nonce,
+        aad,
+    )?;
+
+    Ok(HpkeCiphertext {
+        kem_output: kem_output.into(),
+        ciphertext: ciphertext.into(),
+    })
+}
+
+/// Decrypts the `input` with the private key `sk_r`.
+pub(crate) fn open(
+    crypto: &impl OpenMlsCrypto,
+    config: HpkeConfig,
+    input: &HpkeCiphertext,
+    sk_r: &[u8],
+    info: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>, CryptoError> {
+    let shared_secret = decapsulate(config.0, input.kem_output.as_slice(), sk_r)?;
+    let context = KeySchedule::new(&config, &shared_secret, info)?;
+
+    crypto
+        .aead_decrypt(
+            aead_type(config.2)?,
+            &context.key,
+            input.ciphertext.as_slice(),
+            &context.base_nonce,
+            aad,
+        )
+        .map_err(|_| CryptoError::HpkeDecryptionError)
+}
+
+/// Sets up a sender context for the public key `pk_r` and exports a secret
+/// from it.
+pub(crate) fn setup_sender_and_export(
+    config: HpkeConfig,
+    pk_r: &[u8],
+    info: &[u8],
+    exporter_context: &[u8],
+    exporter_length: usize,
+    randomness: [u8; ENCAPSULATION_RANDOMNESS_LENGTH],
+) -> Result<(KemOutput, ExporterSecret), CryptoError> {
+    let (kem_output, shared_secret) =
+        encapsulate(config.0, pk_r, randomness).map_err(|_| CryptoError::SenderSetupError)?;
+    let context = KeySchedule::new(&config, &shared_secret, info)
+        .map_err(|_| CryptoError::SenderSetupError)?;
+    let exported = context.export(&config, exporter_context, exporter_length)?;
+
+    Ok((kem_output, exported.into()))
+}
+
+/// Sets up a receiver context for the encapsulated key `enc` and exports a
+/// secret from it.
+pub(crate) fn setup_receiver_and_export(
+    config: HpkeConfig,
+    enc: &[u8],
+    sk_r: &[u8],
+    info: &[u8],
+    exporter_context: &[u8],
+    exporter_length: usize,
+) -> Result<ExporterSecret, CryptoError> {
+    let shared_secret =
+        decapsulate(config.0, enc, sk_r).map_err(|_| CryptoError::ReceiverSetupError)?;
+    let context = KeySchedule::new(&config, &shared_secret, info)
+        .map_err(|_| CryptoError::ReceiverSetupError)?;
+
+    context
+        .export(&config, exporter_context, exporter_length)
+        .map(ExporterSecret::from)
+}
+
+/// Derives an ML-KEM key pair from the `ikm`.
+pub(crate) fn derive_key_pair(config: HpkeConfig, ikm: &[u8]) -> Result<HpkeKeyPair, CryptoError> {
+    let suite_id = kem_suite_id(config.0);
+    let dkp_prk = labeled_extract(config.1, &suite_id, &[], b"dkp_prk", ikm);
+    let seed = labeled_expand(
+        config.1,
+        &suite_id,
+        &dkp_prk,
+        b"sk",
+        &[],
+        KEY_GENERATION_SEED_LENGTH,
+    )?;
+    let public = public_key(config.0, &seed)?;
+
+    Ok(HpkeKeyPair {
+        private: seed.into(),
+        public,
+    })
+}
+
+/// The secrets of the HPKE base mode key schedule.
+struct KeySchedule {
+    key: Vec<u8>,
+    base_nonce: Vec<u8>,
+    exporter_secret: Vec<u8>,
+}
+
+impl KeySchedule {
+    fn new(
+        config: &HpkeConfig,
+        shared_secret: &MlKemSharedSecret,
+        info: &[u8],
+    ) -> Result<Self, CryptoError> {
+        // The base mode doesn't use a PSK.
+        const MODE_BASE: u8 = 0x00;
+
+        let suite_id = hpke_suite_id(config);
+        let psk_id_hash = labeled_extract(config.1, &suite_id, &[], b"psk_id_hash", &[]);
+        let info_hash = labeled_extract(config.1, &suite_id, &[], b"info_hash", info);
+        let mut key_schedule_context = vec![MODE_BASE];
+        key_schedule_context.extend_from_slice(&psk_id_hash);
+        key_schedule_context.extend_from_slice(&info_hash);
+
+        let secret = labeled_extract(config.1, &suite_id, shared_secret, b"secret", &[]);
+        let key_length = aead_type(config.2).map(|aead| aead.key_size()).unwrap_or(0);
+        let nonce_length = if key_length == 0 { 0 } else { NONCE_LENGTH };
+
+        Ok(Self {
+            key: labeled_expand(
+                config.1,
+                &suite_id,
+                &secret,
+                b"key",
+                &key_schedule_context,
+                key_length,
+            )?,
+            base_nonce: labeled_expand(
+                config.1,
+                &suite_id,
+                &secret,
+                b"base_nonce",
+                &key_schedule_context,
+                nonce_length,
+            )?,
+            exporter_secret: labeled_expand(
+                config.1,
+                &suite_id,
+                &secret,
+                b"exp",
+                &key_schedule_context,
+                kdf_length(config.1),
+            )?,
+        })
+    }
+
+    fn export(
+        &self,
+        config: &HpkeConfig,
+        exporter_context: &[u8],
+        exporter_length: usize,
+    ) -> Result<Vec<u8>, CryptoError> {
+        labeled_expand(
+            config.1,
+            &hpke_suite_id(config),
+            &self.exporter_secret,
+            b"sec",
+            exporter_context,
+            exporter_length,
+        )
+        .map_err(|_| CryptoError::ExporterError)
+    }
+}
+
+fn encapsulate(
+    kem: HpkeKemType,
+    pk_r: &[u8],
+    randomness: [u8; ENCAPSULATION_RANDOMNESS_LENGTH],
+) -> Result<(KemOutput, MlKemSharedSecret), CryptoError> {
+    match kem {
+        HpkeKemType::MlKem768 => {
+            let pk_r = mlkem768::MlKem768PublicKey::try_from(pk_r)
+                .map_err(|_| CryptoError::InvalidPublicKey)?;
+            if !mlkem768::validate_public_key(&pk_r) {
+                return Err(CryptoError::InvalidPublicKey);
+            }
+            let (ciphertext, shared_secret) = mlkem768::encapsulate(&pk_r, randomness);
+            Ok((ciphertext.as_slice().to_vec(), shared_secret))
+        }
+        HpkeKemType::MlKem1024 => {
+            let pk_r = mlkem1024::MlKem1024PublicKey::try_from(pk_r)
+                .map_err(|_| CryptoError::InvalidPublicKey)?;
+            if !mlkem1024::validate_public_key(&pk_r) {
+                return Err(CryptoError::InvalidPublicKey);
+            }
+            let (ciphertext, shared_secret) = mlkem1024::encapsulate(&pk_r, randomness);
+            Ok((ciphertext.as_slice().to_vec(), shared_secret))
+        }
+        _ => Err(CryptoError::UnsupportedCiphersuite),
+    }
+}
+
+fn decapsulate(
+    kem: HpkeKemType,
+    enc: &[u8],
+    sk_r: &[u8],
+) -> Result<MlKemSharedSecret, CryptoError> {
+    let seed: [u8; KEY_GENERATION_SEED_LENGTH] =
+        sk_r.try_into().map_err(|_| CryptoError::InvalidLength)?;
+    match kem {
+        HpkeKemType::MlKem768 => {
+            let ciphertext = mlkem768::MlKem768Ciphertext::try_from(enc)
+                .map_err(|_| CryptoError::HpkeDecryptionError)?;
+            let key_pair = mlkem768::generate_key_pair(seed);
+            Ok(mlkem768::decapsulate(key_pair.private_key(), &ciphertext))
+        }
+        HpkeKemType::MlKem1024 => {
+            let ciphertext = mlkem1024::MlKem1024Ciphertext::try_from(enc)
+                .map_err(|_| CryptoError::HpkeDecryptionError)?;
+            let key_pair = mlkem1024::generate_key_pair(seed);
+            Ok(mlkem1024::decapsulate(key_pair.private_key(), &ciphertext))
+        }
+        _ => Err(CryptoError::UnsupportedCiphersuite),
+    }
+}
+
+fn public_key(kem: HpkeKemType, seed: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    let seed: [u8; KEY_GENERATION_SEED_LENGTH] =
+        seed.try_into().map_err(|_| CryptoError::InvalidLength)?;
+    match kem {
+        HpkeKemType::MlKem768 => Ok(mlkem768::generate_key_pair(seed)
+            .public_key()
+            .as_slice()
+            .to_vec()),
+        HpkeKemType::MlKem1024 => Ok(mlkem1024::generate_key_pair(seed)
+            .public_key()
+            .as_slice()
+            .to_vec()),
+        _ => Err(CryptoError::UnsupportedCiphersuite),
+    }
+}
+
+fn kem_suite_id(kem: HpkeKemType) -> Vec<u8> {
+    let mut suite_id = b"KEM".to_vec();
+    suite_id.extend_from_slice(&(kem as u16).to_be_bytes());
+    suite_id
+}
+
+fn hpke_suite_id(config: &HpkeConfig) -> Vec<u8> {
+    let mut suite_id = b"HPKE".to_vec();
+    suite_id.extend_from_slice(&(config.0 as u16).to_be_bytes());
+    suite_id.extend_from_slice(&(config.1 as u16).to_be_bytes());
+    suite_id.extend_from_slice(&(config.2 as u16).to_be_bytes());
+    suite_id
+}
+
+fn labeled_extract(
+    kdf: HpkeKdfType,
+    suite_id: &[u8],
+    salt: &[u8],
+    label: &[u8],
+    ikm: &[u8],
+) -> Vec<u8> {
+    let mut labeled_ikm = b"HPKE-v1".to_vec();
+    labeled_ikm.extend_from_slice(suite_id);
+    labeled_ikm.extend_from_slice(label);
+    labeled_ikm.extend_from_slice(ikm);
+
+    libcrux::hkdf::extract(hkdf_algorithm(kdf), salt, &labeled_ikm)
+}
+
+fn labeled_expand(
+    kdf: HpkeKdfType,
+    suite_id: &[u8],
+    prk: &[u8],
+    label: &[u8],
+    info: &[u8],
+    length: usize,
+) -> Result<Vec<u8>, CryptoError> {
+    let length_bytes = u16::try_from(length)
+        .map_err(|_| CryptoError::HkdfOutputLengthInvalid)?
+        .to_be_bytes();
+    let mut labeled_info = length_bytes.to_vec();
+    labeled_info.extend_from_slice(b"HPKE-v1");
+    labeled_info.extend_from_slice(suite_id);
+    labeled_info.extend_from_slice(label);
+    labeled_info.extend_from_slice(info);
+
+    libcrux::hkdf::expand(hkdf_algorithm(kdf), prk, &labeled_info, length)
+        .map_err(|_| CryptoError::HkdfOutputLengthInvalid)
+}
+
+fn hkdf_algorithm(kdf: HpkeKdfType) -> libcrux::hkdf::Algorithm {
+    match kdf {
+        HpkeKdfType::HkdfSha256 => libcrux::hkdf::Algorithm::Sha256,
+        HpkeKdfType::HkdfSha384 => libcrux::hkdf::Algorithm::Sha384,
+        HpkeKdfType::HkdfSha512 => libcrux::hkdf::Algorithm::Sha512,
+    }
+}
+
+fn kdf_length(kdf: HpkeKdfType) -> usize {
+    match kdf {
+        HpkeKdfType::HkdfSha256 => 32,
+        HpkeKdfType::HkdfSha384 => 48,
+        HpkeKdfType::HkdfSha512 => 64,
+    }
+}
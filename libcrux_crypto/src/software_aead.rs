@@ -0,0 +1,70 @@
+//! Portable AES-GCM.
+//!
+//! libcrux only implements AES-GCM with the AES-NI instructions on x86_64.
+//! On machines without them, AES-GCM is provided by the portable
+//! implementation in the RustCrypto `aes-gcm` crate, so that the same
+//! ciphersuites are supported on every machine.
+
+use aes_gcm::{
+    aead::{Aead, Payload},
+    Aes128Gcm, Aes256Gcm, KeyInit,
+};
+use openmls_traits::types::{AeadType, CryptoError};
+
+/// The length of the nonce of AES-GCM.
+const NONCE_LENGTH: usize = 12;
+
+/// Returns `true` if the `alg` is handled by this module.
+pub(crate) fn is_aes_gcm(alg: AeadType) -> bool {
+    matches!(alg, AeadType::Aes128Gcm | AeadType::Aes256Gcm)
+}
+
+/// Encrypts the `data` and returns the ciphertext with the tag appended.
+pub(crate) fn encrypt(
+    alg: AeadType,
+    key: &[u8],
+    data: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>, CryptoError> {
+    if nonce.len() != NONCE_LENGTH {
+        return Err(CryptoError::InvalidLength);
+    }
+    let payload = Payload { msg: data, aad };
+
+    match alg {
+        AeadType::Aes128Gcm => Aes128Gcm::new_from_slice(key)
+            .map_err(|_| CryptoError::InvalidLength)?
+            .encrypt(nonce.into(), payload),
+        AeadType::Aes256Gcm => Aes256Gcm::new_from_slice(key)
+            .map_err(|_| CryptoError::InvalidLength)?
+            .encrypt(nonce.into(), payload),
+        AeadType::ChaCha20Poly1305 => return Err(CryptoError::UnsupportedAeadAlgorithm),
+    }
+    .map_err(|_| CryptoError::CryptoLibraryError)
+}
+
+/// Decrypts the ciphertext with the tag appended in `ct_tag`.
+pub(crate) fn decrypt(
+    alg: AeadType,
+    key: &[u8],
+    ct_tag: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>, CryptoError> {
+    if nonce.len() != NONCE_LENGTH {
+        return Err(CryptoError::InvalidLength);
+    }
+    let payload = Payload { msg: ct_tag, aad };
+
+    match alg {
+        AeadType::Aes128Gcm => Aes128Gcm::new_from_slice(key)
+            .map_err(|_| CryptoError::InvalidLength)?
+            .decrypt(nonce.into(), payload),
+        AeadType::Aes256Gcm => Aes256Gcm::new_from_slice(key)
+            .map_err(|_| CryptoError::InvalidLength)?
+            .decrypt(nonce.into(), payload),
+        AeadType::ChaCha20Poly1305 => return Err(CryptoError::UnsupportedAeadAlgorithm),
+    }
+    .map_err(|_| CryptoError::AeadDecryptionError)
+}
@@ -225,13 +225,17 @@ impl Group {
             openmls::framing::MlsMessageBodyIn::PrivateMessage(msg) => {
                 self.mls_group.process_message(provider.as_ref(), msg)?
             }
+            openmls::framing::MlsMessageBodyIn::TargetedMessage(msg) => {
+                self.mls_group.process_message(provider.as_ref(), msg)?
+            }
             openmls::framing::MlsMessageBodyIn::Welcome(_) => todo!(),
             openmls::framing::MlsMessageBodyIn::GroupInfo(_) => todo!(),
             openmls::framing::MlsMessageBodyIn::KeyPackage(_) => todo!(),
         };
 
         match msg.into_content() {
-            openmls::framing::ProcessedMessageContent::ApplicationMessage(app_msg) => {
+            openmls::framing::ProcessedMessageContent::ApplicationMessage(app_msg)
+            | openmls::framing::ProcessedMessageContent::TargetedMessage(app_msg) => {
                 Ok(app_msg.into_bytes())
             }
             openmls::framing::ProcessedMessageContent::ProposalMessage(_)
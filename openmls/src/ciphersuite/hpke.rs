@@ -36,6 +36,7 @@
 
 use openmls_traits::{
     crypto::OpenMlsCrypto,
+    key_handles::{KeyHandle, KeyHandleProvider},
     types::{Ciphersuite, CryptoError, HpkeCiphertext},
 };
 use thiserror::Error;
@@ -156,3 +157,33 @@ pub(crate) fn decrypt_with_label(
 
     plaintext
 }
+
+/// Decrypt with HPKE and label, using a private key that is held by the
+/// `key_handles` provider.
+pub(crate) fn decrypt_with_label_and_handle(
+    key_handles: &dyn KeyHandleProvider,
+    handle: &KeyHandle,
+    label: &str,
+    context: &[u8],
+    ciphertext: &HpkeCiphertext,
+    ciphersuite: Ciphersuite,
+) -> Result<Vec<u8>, Error> {
+    let context: EncryptContext = (label, context).into();
+    let context = context.tls_serialize_detached()?;
+
+    log_crypto!(
+        debug,
+        "HPKE Decrypt with label `{label}` and `ciphersuite` {ciphersuite:?}:"
+    );
+    log_crypto!(debug, "* context:     {context:x?}");
+    log_crypto!(debug, "* key handle:  {handle:x?}");
+    log_crypto!(debug, "* ciphertext:  {ciphertext:x?}");
+
+    let plaintext = key_handles
+        .hpke_open(handle, ciphersuite.hpke_config(), ciphertext, &context, &[])
+        .map_err(|e| e.into());
+
+    log_crypto!(debug, "* plaintext:   {plaintext:x?}");
+
+    plaintext
+}
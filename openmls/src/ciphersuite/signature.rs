@@ -2,6 +2,7 @@
 //!
 //! This module contains structs for creating signature keys, issuing signatures and verifying them.
 
+use openmls_traits::types::CompositeValue;
 use tls_codec::Serialize;
 
 use super::{LABEL_PREFIX, *};
@@ -154,7 +155,14 @@ impl<T> SignedStruct<T> for Signature {
 
 impl OpenMlsSignaturePublicKey {
     /// Create a new signature public key from raw key bytes.
+    ///
+    /// Returns a [`CryptoError::InvalidPublicKey`] if the `value` is not a
+    /// valid encoding of a composite public key of a composite
+    /// `signature_scheme`.
     pub fn new(value: VLBytes, signature_scheme: SignatureScheme) -> Result<Self, CryptoError> {
+        if signature_scheme.composite_components().is_some() {
+            CompositeValue::public_key(signature_scheme, value.as_slice())?;
+        }
         Ok(Self {
             value,
             signature_scheme,
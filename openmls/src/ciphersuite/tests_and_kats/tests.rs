@@ -76,3 +76,130 @@ fn test_hpke_seal_open() {
         CryptoError::HpkeDecryptionError
     );
 }
+
+// The RustCrypto provider doesn't implement X-Wing and must report it as
+// unsupported instead of panicking.
+#[test]
+fn rust_crypto_rejects_xwing() {
+    let provider = openmls_rust_crypto::OpenMlsRustCrypto::default();
+    let ciphersuite = Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519;
+
+    assert_eq!(
+        provider.crypto().supports(ciphersuite),
+        Err(CryptoError::UnsupportedCiphersuite)
+    );
+    assert!(!provider
+        .crypto()
+        .supported_ciphersuites()
+        .contains(&ciphersuite));
+    assert_eq!(
+        provider
+            .crypto()
+            .derive_hpke_keypair(ciphersuite.hpke_config(), &[0u8; 32])
+            .expect_err("X-Wing key pairs can't be derived"),
+        CryptoError::UnsupportedCiphersuite
+    );
+    assert_eq!(
+        provider
+            .crypto()
+            .hpke_seal(ciphersuite.hpke_config(), &[0u8; 32], &[], &[], &[1, 2, 3])
+            .expect_err("X-Wing can't be used for sealing"),
+        CryptoError::UnsupportedCiphersuite
+    );
+}
+
+// The libcrux provider supports the same ciphersuites on every machine and
+// its AES-GCM, with or without AES-NI, interoperates with the RustCrypto one.
+#[test]
+#[cfg(all(
+    feature = "libcrux-provider",
+    not(any(
+        target_arch = "wasm32",
+        all(target_arch = "x86", target_os = "windows")
+    ))
+))]
+fn libcrux_capability_report() {
+    use openmls_libcrux_crypto::{CryptoProvider, Primitive};
+
+    let libcrux = CryptoProvider::default();
+    let rust_crypto = openmls_rust_crypto::OpenMlsRustCrypto::default();
+    let report = libcrux.capability_report();
+
+    assert_eq!(report.ciphersuites(), libcrux.supported_ciphersuites());
+    assert!(report
+        .ciphersuites()
+        .contains(&Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256));
+    for ciphersuite in report.ciphersuites() {
+        assert_eq!(libcrux.supports(*ciphersuite), Ok(()));
+    }
+    assert_eq!(report.primitives().len(), 8);
+    assert!(!report.is_hardware_accelerated(Primitive::P256));
+
+    let key = [7u8; 16];
+    let nonce = [3u8; 12];
+    let ciphertext = libcrux
+        .aead_encrypt(AeadType::Aes128Gcm, &key, b"message", &nonce, b"aad")
+        .expect("error encrypting with AES-GCM");
+    assert_eq!(
+        ciphertext,
+        rust_crypto
+            .crypto()
+            .aead_encrypt(AeadType::Aes128Gcm, &key, b"message", &nonce, b"aad")
+            .expect("error encrypting with AES-GCM")
+    );
+    assert_eq!(
+        libcrux
+            .aead_decrypt(AeadType::Aes128Gcm, &key, &ciphertext, &nonce, b"aad")
+            .expect("error decrypting with AES-GCM"),
+        b"message"
+    );
+}
+
+// Composite signatures are only valid if both component signatures are valid.
+#[test]
+fn composite_signature() {
+    use openmls_basic_credential::SignatureKeyPair;
+    use openmls_traits::{signatures::Signer, types::CompositeValue};
+    use tls_codec::Serialize;
+
+    let provider = openmls_rust_crypto::OpenMlsRustCrypto::default();
+    let signature_scheme = SignatureScheme::ML_DSA_65_ED25519;
+    let signer = SignatureKeyPair::new(signature_scheme).unwrap();
+    let public_key =
+        OpenMlsSignaturePublicKey::new(signer.to_public_vec().into(), signature_scheme).unwrap();
+
+    let sign_content = SignContent::from(("composite", &[1, 2, 3][..]));
+    let signature = signer
+        .sign(&sign_content.tls_serialize_detached().unwrap())
+        .unwrap();
+    public_key
+        .verify_with_label(provider.crypto(), &signature.clone().into(), &sign_content)
+        .expect("Invalid composite signature.");
+
+    let composite = CompositeValue::signature(signature_scheme, &signature).unwrap();
+    let mut broken_post_quantum = composite.post_quantum.to_vec();
+    broken_post_quantum[0] ^= 0xFF;
+    let mut broken_classical = composite.classical.to_vec();
+    broken_classical[0] ^= 0xFF;
+    for broken in [
+        CompositeValue {
+            post_quantum: &broken_post_quantum,
+            classical: composite.classical,
+        },
+        CompositeValue {
+            post_quantum: composite.post_quantum,
+            classical: &broken_classical,
+        },
+    ] {
+        assert_eq!(
+            public_key.verify_with_label(provider.crypto(), &broken.to_vec().into(), &sign_content),
+            Err(CryptoError::InvalidSignature)
+        );
+    }
+
+    // A public key that can't be split into its components is rejected.
+    assert_eq!(
+        OpenMlsSignaturePublicKey::new(vec![0u8; 32].into(), signature_scheme),
+        Err(CryptoError::InvalidPublicKey)
+    );
+}
@@ -17,6 +17,18 @@ pub enum CredentialError {
     /// Verifying the signature with this credential failed.
     #[error("Invalid signature.")]
     InvalidSignature,
+    /// The credential type is defined in the MLS protocol spec.
+    #[error("The credential type is defined in the MLS protocol spec.")]
+    ReservedCredentialType,
+    /// Another credential kind with the same credential type is already registered.
+    #[error("Another credential kind with the same credential type is already registered.")]
+    AlreadyRegistered,
+    /// The credential is of another credential type.
+    #[error("The credential is of another credential type.")]
+    WrongCredentialType,
+    /// The credential content is malformed.
+    #[error("The credential content is malformed.")]
+    InvalidCredential,
 }
 
 /// An error that occurs in methods of a [`super::Credential`].
@@ -0,0 +1,145 @@
+//! # Credential kinds
+//!
+//! Applications can define their own credential types, e.g. a JWT-based or a
+//! Verifiable Credential identity, by implementing [`CredentialKind`]. A
+//! credential kind defines how the credential content is encoded and which
+//! identity it represents.
+//!
+//! Credential kinds are registered process-wide with
+//! [`register_credential_kind()`]. Registered credential types are included
+//! in the default [`Capabilities`](crate::prelude::Capabilities)
+//! and their identity is used by [`Credential::identity()`], e.g. to find the
+//! member in
+//! [`MlsGroup::propose_remove_member_by_credential()`](crate::group::MlsGroup::propose_remove_member_by_credential).
+
+use std::{
+    any::TypeId,
+    collections::BTreeMap,
+    sync::{PoisonError, RwLock},
+};
+
+#[cfg(doc)]
+use super::BasicCredential;
+use super::{errors::CredentialError, Credential, CredentialType};
+
+/// An application-defined credential type.
+///
+/// The credential type must be a code point that is not one of the credential
+/// types that are defined in the MLS protocol spec.
+pub trait CredentialKind: Sized + 'static {
+    /// The credential type of this credential kind.
+    const CREDENTIAL_TYPE: u16;
+
+    /// Decodes the credential content. Returns
+    /// [`CredentialError::InvalidCredential`] if the content is malformed.
+    fn decode(serialized_content: &[u8]) -> Result<Self, CredentialError>;
+
+    /// Encodes the credential content.
+    fn encode(&self) -> Result<Vec<u8>, CredentialError>;
+
+    /// Returns the identity this credential represents.
+    ///
+    /// Two credentials of the same kind with the same identity are considered
+    /// to represent the same client, even if their content differs.
+    fn identity(&self) -> Vec<u8>;
+
+    /// Returns the [`CredentialType`] of this credential kind.
+    fn credential_type() -> CredentialType {
+        CredentialType::from(Self::CREDENTIAL_TYPE)
+    }
+}
+
+/// Extracts the identity from the serialized credential content.
+type IdentityFn = fn(&[u8]) -> Result<Vec<u8>, CredentialError>;
+
+fn identity_of<T: CredentialKind>(serialized_content: &[u8]) -> Result<Vec<u8>, CredentialError> {
+    T::decode(serialized_content).map(|credential| credential.identity())
+}
+
+/// The registered credential kinds by credential type.
+static CREDENTIAL_KINDS: RwLock<BTreeMap<u16, (TypeId, IdentityFn)>> = RwLock::new(BTreeMap::new());
+
+/// Registers the credential kind `T` for this process.
+///
+/// Returns an error if the credential type of `T` is defined in the MLS
+/// protocol spec or if another credential kind with the same credential type
+/// is already registered. Registering the same kind twice is a no-op.
+pub fn register_credential_kind<T: CredentialKind>() -> Result<(), CredentialError> {
+    if !matches!(T::credential_type(), CredentialType::Other(_)) {
+        return Err(CredentialError::ReservedCredentialType);
+    }
+
+    let mut kinds = CREDENTIAL_KINDS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    match kinds.get(&T::CREDENTIAL_TYPE) {
+        Some((type_id, _)) if *type_id != TypeId::of::<T>() => {
+            Err(CredentialError::AlreadyRegistered)
+        }
+        Some(_) => Ok(()),
+        None => {
+            kinds.insert(T::CREDENTIAL_TYPE, (TypeId::of::<T>(), identity_of::<T>));
+            Ok(())
+        }
+    }
+}
+
+/// Returns the credential types of all registered credential kinds.
+pub fn registered_credential_types() -> Vec<CredentialType> {
+    CREDENTIAL_KINDS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .keys()
+        .copied()
+        .map(CredentialType::from)
+        .collect()
+}
+
+impl Credential {
+    /// Creates a [`Credential`] from a credential of the kind `T`.
+    pub fn from_kind<T: CredentialKind>(credential: &T) -> Result<Self, CredentialError> {
+        Ok(Self::new(T::credential_type(), credential.encode()?))
+    }
+
+    /// Decodes this credential as a credential of the kind `T`.
+    ///
+    /// Returns [`CredentialError::WrongCredentialType`] if the credential is
+    /// of another credential type.
+    pub fn as_kind<T: CredentialKind>(&self) -> Result<T, CredentialError> {
+        if self.credential_type() != T::credential_type() {
+            return Err(CredentialError::WrongCredentialType);
+        }
+        T::decode(self.serialized_content())
+    }
+
+    /// Returns the identity of this credential.
+    ///
+    /// This is the identity of a [`BasicCredential`] or the identity of a
+    /// registered [`CredentialKind`]. Returns `None` for other credential
+    /// types or if the credential can't be decoded.
+    pub fn identity(&self) -> Option<Vec<u8>> {
+        match self.credential_type() {
+            CredentialType::Basic => Some(self.serialized_content().to_vec()),
+            CredentialType::X509 => None,
+            CredentialType::Other(credential_type) => {
+                let (_, identity_fn) = *CREDENTIAL_KINDS
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .get(&credential_type)?;
+                identity_fn(self.serialized_content()).ok()
+            }
+        }
+    }
+
+    /// Returns `true` if this credential and `other` represent the same
+    /// client. Credentials without a known identity must be equal.
+    pub(crate) fn same_identity(&self, other: &Credential) -> bool {
+        if self.credential_type() != other.credential_type() {
+            return false;
+        }
+        match (self.identity(), other.identity()) {
+            (Some(identity), Some(other_identity)) => identity == other_identity,
+            _ => self == other,
+        }
+    }
+}
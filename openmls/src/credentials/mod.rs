@@ -20,7 +20,8 @@
 //! query the Authentication Service to ensure that the new credential is valid.
 //!
 //! There are multiple [`CredentialType`]s, although OpenMLS currently only
-//! supports the [`BasicCredential`].
+//! supports the [`BasicCredential`]. Applications can add their own credential
+//! types with a [`CredentialKind`].
 
 use std::io::{Read, Write};
 
@@ -36,9 +37,15 @@ mod tests;
 use crate::ciphersuite::SignaturePublicKey;
 use errors::*;
 
+// Private
+mod kind;
+
 // Public
 pub mod errors;
 
+// Public re-exports
+pub use kind::{register_credential_kind, registered_credential_types, CredentialKind};
+
 /// CredentialType.
 ///
 /// This enum contains variants for the different Credential Types.
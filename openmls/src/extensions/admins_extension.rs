@@ -0,0 +1,76 @@
+use tls_codec::{TlsDeserialize, TlsDeserializeBytes, TlsSerialize, TlsSize};
+
+use super::{Deserialize, Serialize};
+use crate::credentials::Credential;
+
+/// # Admins
+///
+/// The admins extension is a group context extension that lists the
+/// credentials of the administrators of a group. If the extension is present,
+/// only members whose leaf carries one of these credentials may send or
+/// commit Add, Remove and GroupContextExtensions proposals. Members may still
+/// remove themselves.
+///
+/// Admins are identified by their credential rather than by their leaf index,
+/// because leaf indices are reused when members leave and join the group.
+///
+/// Proposals sent by external senders listed in the
+/// [`ExternalSendersExtension`](super::ExternalSendersExtension) and by new
+/// members joining through an external commit are not restricted.
+///
+/// ```c
+/// struct {
+///     Credential admins<V>;
+/// } AdminsExtension;
+/// ```
+#[derive(
+    PartialEq,
+    Eq,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    TlsSerialize,
+    TlsDeserialize,
+    TlsDeserializeBytes,
+    TlsSize,
+)]
+pub struct AdminsExtension {
+    admins: Vec<Credential>,
+}
+
+impl AdminsExtension {
+    /// Create a new admins extension from a list of admin credentials.
+    pub fn new(admins: Vec<Credential>) -> Self {
+        Self { admins }
+    }
+
+    /// Returns the credentials of the admins.
+    pub fn admins(&self) -> &[Credential] {
+        self.admins.as_slice()
+    }
+
+    /// Returns `true` if the `credential` belongs to an admin.
+    pub fn is_admin(&self, credential: &Credential) -> bool {
+        self.admins.contains(credential)
+    }
+
+    /// Adds the `credential` to the admins. Returns `false` if it already was
+    /// an admin credential.
+    pub fn add_admin(&mut self, credential: Credential) -> bool {
+        if self.is_admin(&credential) {
+            return false;
+        }
+        self.admins.push(credential);
+        true
+    }
+
+    /// Removes the `credential` from the admins. Returns `false` if it was not
+    /// an admin credential.
+    pub fn remove_admin(&mut self, credential: &Credential) -> bool {
+        let length = self.admins.len();
+        self.admins.retain(|admin| admin != credential);
+        self.admins.len() != length
+    }
+}
@@ -3,9 +3,9 @@ use std::io::{Read, Write};
 use tls_codec::{Deserialize, DeserializeBytes, Serialize, Size, VLBytes};
 
 use crate::extensions::{
-    ApplicationIdExtension, Extension, ExtensionType, ExternalPubExtension,
+    AdminsExtension, ApplicationIdExtension, Extension, ExtensionType, ExternalPubExtension,
     ExternalSendersExtension, RatchetTreeExtension, RequiredCapabilitiesExtension,
-    UnknownExtension,
+    RequiredMediaTypesExtension, SupportedMediaTypesExtension, UnknownExtension,
 };
 
 use super::last_resort::LastResortExtension;
@@ -37,6 +37,9 @@ impl Size for Extension {
             Extension::ExternalPub(e) => e.tls_serialized_len(),
             Extension::ExternalSenders(e) => e.tls_serialized_len(),
             Extension::LastResort(e) => e.tls_serialized_len(),
+            Extension::Admins(e) => e.tls_serialized_len(),
+            Extension::SupportedMediaTypes(e) => e.tls_serialized_len(),
+            Extension::RequiredMediaTypes(e) => e.tls_serialized_len(),
             Extension::Unknown(_, e) => e.0.len(),
         };
 
@@ -69,6 +72,9 @@ impl Serialize for Extension {
             Extension::ExternalPub(e) => e.tls_serialize(&mut extension_data),
             Extension::ExternalSenders(e) => e.tls_serialize(&mut extension_data),
             Extension::LastResort(e) => e.tls_serialize(&mut extension_data),
+            Extension::Admins(e) => e.tls_serialize(&mut extension_data),
+            Extension::SupportedMediaTypes(e) => e.tls_serialize(&mut extension_data),
+            Extension::RequiredMediaTypes(e) => e.tls_serialize(&mut extension_data),
             Extension::Unknown(_, e) => extension_data
                 .write_all(e.0.as_slice())
                 .map(|_| e.0.len())
@@ -118,6 +124,15 @@ impl Deserialize for Extension {
             ExtensionType::LastResort => {
                 Extension::LastResort(LastResortExtension::tls_deserialize(&mut extension_data)?)
             }
+            ExtensionType::Admins => {
+                Extension::Admins(AdminsExtension::tls_deserialize(&mut extension_data)?)
+            }
+            ExtensionType::SupportedMediaTypes => Extension::SupportedMediaTypes(
+                SupportedMediaTypesExtension::tls_deserialize(&mut extension_data)?,
+            ),
+            ExtensionType::RequiredMediaTypes => Extension::RequiredMediaTypes(
+                RequiredMediaTypesExtension::tls_deserialize(&mut extension_data)?,
+            ),
             ExtensionType::Unknown(unknown) => {
                 Extension::Unknown(unknown, UnknownExtension(extension_data.to_vec()))
             }
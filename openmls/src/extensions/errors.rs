@@ -103,3 +103,29 @@ pub enum InvalidExtensionError {
     )]
     IllegalInLeafNodes,
 }
+
+/// Typed extension error
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum TypedExtensionError {
+    /// An extension with the same extension type is already registered.
+    #[error("An extension with the same extension type is already registered.")]
+    AlreadyRegistered,
+    /// The extension type is reserved for an extension that is built into OpenMLS.
+    #[error("The extension type is reserved for an extension that is built into OpenMLS.")]
+    ReservedExtensionType,
+    /// The extension does not have the extension type of the typed extension.
+    #[error("The extension does not have the extension type of the typed extension.")]
+    WrongExtensionType,
+    /// The extension data could not be decoded as the typed extension.
+    #[error("The extension data could not be decoded as the typed extension.")]
+    Decoding,
+    /// The typed extension could not be encoded.
+    #[error("The typed extension could not be encoded.")]
+    Encoding,
+    /// The extension is not allowed in this context.
+    #[error("The extension is not allowed in this context.")]
+    IllegalContext,
+    /// The extension was rejected by its validation callback.
+    #[error("The extension was rejected by its validation callback.")]
+    Invalid,
+}
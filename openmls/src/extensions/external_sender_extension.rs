@@ -38,11 +38,11 @@ impl ExternalSender {
         }
     }
 
-    pub(crate) fn credential(&self) -> &Credential {
+    pub fn credential(&self) -> &Credential {
         &self.credential
     }
 
-    pub(crate) fn signature_key(&self) -> &SignaturePublicKey {
+    pub fn signature_key(&self) -> &SignaturePublicKey {
         &self.signature_key
     }
 }
@@ -0,0 +1,156 @@
+use tls_codec::{TlsDeserialize, TlsDeserializeBytes, TlsSerialize, TlsSize, VLBytes};
+
+use super::{Deserialize, Extensions, Serialize};
+
+/// # Media type
+///
+/// A media type as defined in [RFC 6838], e.g. `text/plain` or
+/// `text/markdown;variant=GFM`. Media types are compared byte-wise.
+///
+/// ```c
+/// struct {
+///     opaque media_type<V>;
+/// } MediaType;
+/// ```
+///
+/// [RFC 6838]: https://www.rfc-editor.org/rfc/rfc6838
+#[derive(
+    PartialEq,
+    Eq,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    TlsSerialize,
+    TlsDeserialize,
+    TlsDeserializeBytes,
+    TlsSize,
+)]
+pub struct MediaType {
+    media_type: VLBytes,
+}
+
+impl MediaType {
+    /// Create a new media type.
+    pub fn new(media_type: &str) -> Self {
+        Self {
+            media_type: media_type.as_bytes().into(),
+        }
+    }
+
+    /// Returns the media type as bytes.
+    pub fn as_slice(&self) -> &[u8] {
+        self.media_type.as_slice()
+    }
+
+    /// Returns the media type as string or `None` if it is not valid UTF-8.
+    pub fn as_str(&self) -> Option<&str> {
+        std::str::from_utf8(self.as_slice()).ok()
+    }
+}
+
+impl From<&str> for MediaType {
+    fn from(media_type: &str) -> Self {
+        Self::new(media_type)
+    }
+}
+
+/// # Supported media types
+///
+/// The supported media types extension is a leaf node extension that lists the
+/// media types a client can process in application messages, as defined in
+/// the content advertisement section of draft-ietf-mls-extensions. This uses
+/// a code point from the private use range.
+///
+/// ```c
+/// struct {
+///     MediaType media_types<V>;
+/// } MediaTypeList;
+///
+/// MediaTypeList supported_media_types;
+/// ```
+#[derive(
+    PartialEq,
+    Eq,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    TlsSerialize,
+    TlsDeserialize,
+    TlsDeserializeBytes,
+    TlsSize,
+)]
+pub struct SupportedMediaTypesExtension {
+    media_types: Vec<MediaType>,
+}
+
+impl SupportedMediaTypesExtension {
+    /// Create a new supported media types extension.
+    pub fn new(media_types: Vec<MediaType>) -> Self {
+        Self { media_types }
+    }
+
+    /// Returns the supported media types.
+    pub fn media_types(&self) -> &[MediaType] {
+        self.media_types.as_slice()
+    }
+
+    /// Returns `true` if the `media_type` is supported.
+    pub fn supports(&self, media_type: &MediaType) -> bool {
+        self.media_types.contains(media_type)
+    }
+}
+
+/// # Required media types
+///
+/// The required media types extension is a group context extension that lists
+/// the media types every member of the group must support, as defined in the
+/// content advertisement section of draft-ietf-mls-extensions. Members
+/// advertise the media types they support with a
+/// [`SupportedMediaTypesExtension`] in their leaf node. This uses a code point
+/// from the private use range.
+///
+/// ```c
+/// MediaTypeList required_media_types;
+/// ```
+#[derive(
+    PartialEq,
+    Eq,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    TlsSerialize,
+    TlsDeserialize,
+    TlsDeserializeBytes,
+    TlsSize,
+)]
+pub struct RequiredMediaTypesExtension {
+    media_types: Vec<MediaType>,
+}
+
+impl RequiredMediaTypesExtension {
+    /// Create a new required media types extension.
+    pub fn new(media_types: Vec<MediaType>) -> Self {
+        Self { media_types }
+    }
+
+    /// Returns the required media types.
+    pub fn media_types(&self) -> &[MediaType] {
+        self.media_types.as_slice()
+    }
+
+    /// Returns `true` if the [`SupportedMediaTypesExtension`] in the
+    /// `leaf_node_extensions` lists all required media types. A leaf node
+    /// without the extension supports no media types.
+    pub fn is_supported_by(&self, leaf_node_extensions: &Extensions) -> bool {
+        self.media_types.iter().all(|media_type| {
+            leaf_node_extensions
+                .supported_media_types()
+                .is_some_and(|supported| supported.supports(media_type))
+        })
+    }
+}
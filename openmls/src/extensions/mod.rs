@@ -18,6 +18,9 @@
 //! - [`RatchetTreeExtension`] (GroupInfo extension)
 //! - [`RequiredCapabilitiesExtension`] (GroupContext extension)
 //! - [`ExternalPubExtension`] (GroupInfo extension)
+//!
+//! Applications can define their own extensions with [`TypedExtension`] and
+//! have them validated by an [`ExtensionRegistry`].
 
 use std::{
     fmt::Debug,
@@ -27,31 +30,39 @@ use std::{
 use serde::{Deserialize, Serialize};
 
 // Private
+mod admins_extension;
 mod application_id_extension;
 mod codec;
 mod external_pub_extension;
 mod external_sender_extension;
 mod last_resort;
+mod media_types_extension;
 mod ratchet_tree_extension;
 mod required_capabilities;
+mod typed_extension;
 use errors::*;
 
 // Public
 pub mod errors;
 
 // Public re-exports
+pub use admins_extension::AdminsExtension;
 pub use application_id_extension::ApplicationIdExtension;
 pub use external_pub_extension::ExternalPubExtension;
 pub use external_sender_extension::{
     ExternalSender, ExternalSendersExtension, SenderExtensionIndex,
 };
 pub use last_resort::LastResortExtension;
+pub use media_types_extension::{
+    MediaType, RequiredMediaTypesExtension, SupportedMediaTypesExtension,
+};
 pub use ratchet_tree_extension::RatchetTreeExtension;
 pub use required_capabilities::RequiredCapabilitiesExtension;
 use tls_codec::{
     Deserialize as TlsDeserializeTrait, DeserializeBytes, Error, Serialize as TlsSerializeTrait,
     Size, TlsSize,
 };
+pub use typed_extension::{ExtensionContext, ExtensionRegistry, TypedExtension};
 
 #[cfg(test)]
 mod tests;
@@ -97,6 +108,18 @@ pub enum ExtensionType {
     /// scenario.
     LastResort,
 
+    /// Group context extension that lists the credentials of the group's
+    /// admins. This uses a code point from the private use range.
+    Admins,
+
+    /// Leaf node extension that lists the media types the client supports.
+    /// This uses a code point from the private use range.
+    SupportedMediaTypes,
+
+    /// Group context extension that lists the media types all members must
+    /// support. This uses a code point from the private use range.
+    RequiredMediaTypes,
+
     /// A currently unknown extension type.
     Unknown(u16),
 }
@@ -148,6 +171,9 @@ impl From<u16> for ExtensionType {
             4 => ExtensionType::ExternalPub,
             5 => ExtensionType::ExternalSenders,
             10 => ExtensionType::LastResort,
+            0xff80 => ExtensionType::Admins,
+            0xff81 => ExtensionType::SupportedMediaTypes,
+            0xff82 => ExtensionType::RequiredMediaTypes,
             unknown => ExtensionType::Unknown(unknown),
         }
     }
@@ -162,6 +188,9 @@ impl From<ExtensionType> for u16 {
             ExtensionType::ExternalPub => 4,
             ExtensionType::ExternalSenders => 5,
             ExtensionType::LastResort => 10,
+            ExtensionType::Admins => 0xff80,
+            ExtensionType::SupportedMediaTypes => 0xff81,
+            ExtensionType::RequiredMediaTypes => 0xff82,
             ExtensionType::Unknown(unknown) => unknown,
         }
     }
@@ -201,6 +230,15 @@ pub enum Extension {
     /// A [`LastResortExtension`]
     LastResort(LastResortExtension),
 
+    /// An [`AdminsExtension`]
+    Admins(AdminsExtension),
+
+    /// A [`SupportedMediaTypesExtension`]
+    SupportedMediaTypes(SupportedMediaTypesExtension),
+
+    /// A [`RequiredMediaTypesExtension`]
+    RequiredMediaTypes(RequiredMediaTypesExtension),
+
     /// A currently unknown extension.
     Unknown(u16, UnknownExtension),
 }
@@ -391,6 +429,33 @@ impl Extensions {
                 _ => None,
             })
     }
+
+    /// Get a reference to the [`AdminsExtension`] if there is any.
+    pub fn admins(&self) -> Option<&AdminsExtension> {
+        self.find_by_type(ExtensionType::Admins)
+            .and_then(|e| match e {
+                Extension::Admins(e) => Some(e),
+                _ => None,
+            })
+    }
+
+    /// Get a reference to the [`SupportedMediaTypesExtension`] if there is any.
+    pub fn supported_media_types(&self) -> Option<&SupportedMediaTypesExtension> {
+        self.find_by_type(ExtensionType::SupportedMediaTypes)
+            .and_then(|e| match e {
+                Extension::SupportedMediaTypes(e) => Some(e),
+                _ => None,
+            })
+    }
+
+    /// Get a reference to the [`RequiredMediaTypesExtension`] if there is any.
+    pub fn required_media_types(&self) -> Option<&RequiredMediaTypesExtension> {
+        self.find_by_type(ExtensionType::RequiredMediaTypes)
+            .and_then(|e| match e {
+                Extension::RequiredMediaTypes(e) => Some(e),
+                _ => None,
+            })
+    }
 }
 
 impl Extension {
@@ -458,6 +523,46 @@ impl Extension {
         }
     }
 
+    /// Get a reference to this extension as [`AdminsExtension`].
+    /// Returns an [`ExtensionError::InvalidExtensionType`] error if called on
+    /// an [`Extension`] that's not an [`AdminsExtension`].
+    pub fn as_admins_extension(&self) -> Result<&AdminsExtension, ExtensionError> {
+        match self {
+            Self::Admins(e) => Ok(e),
+            _ => Err(ExtensionError::InvalidExtensionType(
+                "This is not an AdminsExtension".into(),
+            )),
+        }
+    }
+
+    /// Get a reference to this extension as [`SupportedMediaTypesExtension`].
+    /// Returns an [`ExtensionError::InvalidExtensionType`] error if called on
+    /// an [`Extension`] that's not a [`SupportedMediaTypesExtension`].
+    pub fn as_supported_media_types_extension(
+        &self,
+    ) -> Result<&SupportedMediaTypesExtension, ExtensionError> {
+        match self {
+            Self::SupportedMediaTypes(e) => Ok(e),
+            _ => Err(ExtensionError::InvalidExtensionType(
+                "This is not a SupportedMediaTypesExtension".into(),
+            )),
+        }
+    }
+
+    /// Get a reference to this extension as [`RequiredMediaTypesExtension`].
+    /// Returns an [`ExtensionError::InvalidExtensionType`] error if called on
+    /// an [`Extension`] that's not a [`RequiredMediaTypesExtension`].
+    pub fn as_required_media_types_extension(
+        &self,
+    ) -> Result<&RequiredMediaTypesExtension, ExtensionError> {
+        match self {
+            Self::RequiredMediaTypes(e) => Ok(e),
+            _ => Err(ExtensionError::InvalidExtensionType(
+                "This is not a RequiredMediaTypesExtension".into(),
+            )),
+        }
+    }
+
     /// Returns the [`ExtensionType`]
     #[inline]
     pub const fn extension_type(&self) -> ExtensionType {
@@ -468,6 +573,9 @@ impl Extension {
             Extension::ExternalPub(_) => ExtensionType::ExternalPub,
             Extension::ExternalSenders(_) => ExtensionType::ExternalSenders,
             Extension::LastResort(_) => ExtensionType::LastResort,
+            Extension::Admins(_) => ExtensionType::Admins,
+            Extension::SupportedMediaTypes(_) => ExtensionType::SupportedMediaTypes,
+            Extension::RequiredMediaTypes(_) => ExtensionType::RequiredMediaTypes,
             Extension::Unknown(kind, _) => ExtensionType::Unknown(*kind),
         }
     }
@@ -0,0 +1,228 @@
+//! # Typed extensions
+//!
+//! Extensions that are not built into OpenMLS are represented as
+//! [`Extension::Unknown`] with the raw extension data. Applications can
+//! implement [`TypedExtension`] for their own extension types to encode and
+//! decode them with [`Extension::from_typed()`] and [`Extensions::typed()`].
+//!
+//! Typed extensions can further be registered in an [`ExtensionRegistry`].
+//! The registry checks that a registered extension decodes correctly, passes
+//! its [`TypedExtension::validate()`] callback and only appears in the
+//! [`ExtensionContext`]s it is allowed in. A registry that is set on an
+//! [`MlsGroup`](crate::group::MlsGroup) or a
+//! [`PublicGroup`](crate::group::PublicGroup) is used to validate the key
+//! packages, leaf nodes and group context extensions of incoming commits.
+//!
+//! Extensions that are not registered are not checked.
+
+use std::{collections::BTreeMap, fmt::Debug, marker::PhantomData, sync::Arc};
+
+use tls_codec::{DeserializeBytes, Serialize as TlsSerializeTrait};
+
+use super::{errors::TypedExtensionError, Extension, ExtensionType, Extensions, UnknownExtension};
+use crate::{key_packages::KeyPackage, treesync::LeafNode};
+
+/// The places in which an extension can appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtensionContext {
+    /// The extensions of a [`KeyPackage`].
+    KeyPackage,
+    /// The extensions of a [`LeafNode`].
+    LeafNode,
+    /// The extensions of the group context.
+    GroupContext,
+    /// The extensions of a group info.
+    GroupInfo,
+}
+
+/// An application-defined extension with a TLS encoding.
+///
+/// The extension type must not be one of the extension types that are built
+/// into OpenMLS.
+pub trait TypedExtension: TlsSerializeTrait + DeserializeBytes + Sized + 'static {
+    /// The extension type of the extension.
+    const EXTENSION_TYPE: u16;
+
+    /// The contexts in which the extension may appear.
+    const CONTEXTS: &'static [ExtensionContext];
+
+    /// Validates the decoded extension. Returns
+    /// [`TypedExtensionError::Invalid`] if the extension must be rejected.
+    ///
+    /// The default implementation accepts all extensions.
+    fn validate(&self) -> Result<(), TypedExtensionError> {
+        Ok(())
+    }
+
+    /// Returns the [`ExtensionType`] of the extension.
+    fn extension_type() -> ExtensionType {
+        ExtensionType::from(Self::EXTENSION_TYPE)
+    }
+}
+
+/// The type-erased part of a registered [`TypedExtension`].
+trait RegisteredExtension: Send + Sync {
+    fn contexts(&self) -> &'static [ExtensionContext];
+
+    fn validate(&self, extension_data: &[u8]) -> Result<(), TypedExtensionError>;
+}
+
+struct Registration<T>(PhantomData<fn() -> T>);
+
+impl<T: TypedExtension> RegisteredExtension for Registration<T> {
+    fn contexts(&self) -> &'static [ExtensionContext] {
+        T::CONTEXTS
+    }
+
+    fn validate(&self, extension_data: &[u8]) -> Result<(), TypedExtensionError> {
+        decode::<T>(extension_data)?.validate()
+    }
+}
+
+/// Decodes `extension_data` as `T`. All bytes must be consumed.
+fn decode<T: TypedExtension>(extension_data: &[u8]) -> Result<T, TypedExtensionError> {
+    match T::tls_deserialize_bytes(extension_data) {
+        Ok((extension, [])) => Ok(extension),
+        _ => Err(TypedExtensionError::Decoding),
+    }
+}
+
+/// A set of registered [`TypedExtension`]s.
+///
+/// See the [module documentation](self) for details.
+#[derive(Clone, Default)]
+pub struct ExtensionRegistry {
+    extensions: BTreeMap<u16, Arc<dyn RegisteredExtension>>,
+}
+
+impl Debug for ExtensionRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExtensionRegistry")
+            .field("extensions", &self.extensions.keys())
+            .finish()
+    }
+}
+
+impl PartialEq for ExtensionRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        self.extensions.keys().eq(other.extensions.keys())
+    }
+}
+
+impl ExtensionRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the typed extension `T`.
+    ///
+    /// Returns an error if the extension type of `T` is built into OpenMLS or
+    /// if it is already registered.
+    pub fn register<T: TypedExtension>(&mut self) -> Result<(), TypedExtensionError> {
+        if !matches!(T::extension_type(), ExtensionType::Unknown(_)) {
+            return Err(TypedExtensionError::ReservedExtensionType);
+        }
+        if self.extensions.contains_key(&T::EXTENSION_TYPE) {
+            return Err(TypedExtensionError::AlreadyRegistered);
+        }
+
+        self.extensions
+            .insert(T::EXTENSION_TYPE, Arc::new(Registration::<T>(PhantomData)));
+
+        Ok(())
+    }
+
+    /// Returns `true` if an extension with the given extension type is
+    /// registered.
+    pub fn is_registered(&self, extension_type: ExtensionType) -> bool {
+        self.extensions.contains_key(&u16::from(extension_type))
+    }
+
+    /// Returns `true` if no extensions are registered.
+    pub fn is_empty(&self) -> bool {
+        self.extensions.is_empty()
+    }
+
+    /// Validates all registered extensions in `extensions`, which appear in
+    /// the given `context`.
+    pub fn validate(
+        &self,
+        extensions: &Extensions,
+        context: ExtensionContext,
+    ) -> Result<(), TypedExtensionError> {
+        for extension in extensions.iter() {
+            let Extension::Unknown(extension_type, UnknownExtension(extension_data)) = extension
+            else {
+                continue;
+            };
+            let Some(registered) = self.extensions.get(extension_type) else {
+                continue;
+            };
+            if !registered.contexts().contains(&context) {
+                return Err(TypedExtensionError::IllegalContext);
+            }
+            registered.validate(extension_data)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validates the registered extensions of a [`LeafNode`].
+    pub fn validate_leaf_node(&self, leaf_node: &LeafNode) -> Result<(), TypedExtensionError> {
+        self.validate(leaf_node.extensions(), ExtensionContext::LeafNode)
+    }
+
+    /// Validates the registered extensions of a [`KeyPackage`] and of its
+    /// [`LeafNode`].
+    pub fn validate_key_package(
+        &self,
+        key_package: &KeyPackage,
+    ) -> Result<(), TypedExtensionError> {
+        self.validate(key_package.extensions(), ExtensionContext::KeyPackage)?;
+        self.validate_leaf_node(key_package.leaf_node())
+    }
+}
+
+impl Extension {
+    /// Encodes the typed extension `extension` as an [`Extension`].
+    pub fn from_typed<T: TypedExtension>(extension: &T) -> Result<Self, TypedExtensionError> {
+        let extension_data = extension
+            .tls_serialize_detached()
+            .map_err(|_| TypedExtensionError::Encoding)?;
+
+        match T::extension_type() {
+            ExtensionType::Unknown(extension_type) => Ok(Extension::Unknown(
+                extension_type,
+                UnknownExtension(extension_data),
+            )),
+            _ => Err(TypedExtensionError::ReservedExtensionType),
+        }
+    }
+
+    /// Decodes this extension as the typed extension `T`.
+    ///
+    /// Returns an error if this extension is not of the extension type of
+    /// `T` or if the extension data can't be decoded.
+    pub fn as_typed<T: TypedExtension>(&self) -> Result<T, TypedExtensionError> {
+        match self {
+            Extension::Unknown(extension_type, UnknownExtension(extension_data))
+                if *extension_type == T::EXTENSION_TYPE =>
+            {
+                decode(extension_data)
+            }
+            _ => Err(TypedExtensionError::WrongExtensionType),
+        }
+    }
+}
+
+impl Extensions {
+    /// Decodes the typed extension `T` if there is any.
+    ///
+    /// Returns an error if the extension data can't be decoded.
+    pub fn typed<T: TypedExtension>(&self) -> Result<Option<T>, TypedExtensionError> {
+        self.find_by_type(T::extension_type())
+            .map(Extension::as_typed)
+            .transpose()
+    }
+}
@@ -68,3 +68,37 @@ pub enum ProtocolMessageError {
     #[error("Wrong wire format")]
     WrongWireFormat,
 }
+
+/// Safe AAD error
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum SafeAadError {
+    /// The AAD is not a valid safe AAD.
+    #[error("The AAD is not a valid safe AAD.")]
+    Malformed,
+    /// The items of the safe AAD are not sorted by component ID or a component
+    /// ID appears more than once.
+    #[error(
+        "The items of the safe AAD are not sorted by component ID or a component ID appears more than once."
+    )]
+    InvalidComponentOrder,
+}
+
+/// Targeted message error
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum TargetedMessageError {
+    /// See [`LibraryError`] for more details.
+    #[error(transparent)]
+    LibraryError(#[from] LibraryError),
+    /// The message is addressed to another member.
+    #[error("The message is addressed to another member.")]
+    WrongRecipient,
+    /// The decryption key of the own leaf node is missing.
+    #[error("The decryption key of the own leaf node is missing.")]
+    MissingDecryptionKey,
+    /// An error occurred during HPKE decryption.
+    #[error("An error occurred during HPKE decryption.")]
+    DecryptionFailed,
+    /// The content is malformed.
+    #[error("The content is malformed.")]
+    MalformedContent,
+}
@@ -7,12 +7,13 @@
 //!
 //! The [`MlsMessageIn`] struct is meant to be deserialized upon receiving it
 //! from the DS. After deserialization, its content (either a [`PublicMessage`],
-//! [`PrivateMessage`], [`KeyPackageIn`], [`Welcome`] or
+//! [`PrivateMessage`], [`TargetedMessage`], [`KeyPackageIn`], [`Welcome`] or
 //! [`GroupInfo`](crate::messages::group_info::GroupInfo)) can be extracted via
 //! [`MlsMessageIn::extract()`] for use with the [`MlsGroup`] API.
 //!
-//! If an [`MlsMessageIn`] contains a [`PublicMessage`] or [`PrivateMessage`],
-//! can be used to determine which group can be used to process the message.
+//! If an [`MlsMessageIn`] contains a [`PublicMessage`], [`PrivateMessage`] or
+//! [`TargetedMessage`], it can be used to determine which group can be used to
+//! process the message.
 
 use super::*;
 use crate::{
@@ -65,6 +66,8 @@ pub struct MlsMessageIn {
 ///             GroupInfo group_info;
 ///         case mls_key_package:
 ///             KeyPackage key_package;
+///         case mls_targeted_message:
+///             TargetedMessage targeted_message;
 ///     }
 /// } MLSMessage;
 /// ```
@@ -91,6 +94,10 @@ pub enum MlsMessageBodyIn {
     /// KeyPackage
     #[tls_codec(discriminant = 5)]
     KeyPackage(KeyPackageIn),
+
+    /// Message to a single group member
+    #[tls_codec(discriminant = 6)]
+    TargetedMessage(TargetedMessage),
 }
 
 impl MlsMessageIn {
@@ -102,6 +109,7 @@ impl MlsMessageIn {
             MlsMessageBodyIn::Welcome(_) => WireFormat::Welcome,
             MlsMessageBodyIn::GroupInfo(_) => WireFormat::GroupInfo,
             MlsMessageBodyIn::KeyPackage(_) => WireFormat::KeyPackage,
+            MlsMessageBodyIn::TargetedMessage(_) => WireFormat::TargetedMessage,
         }
     }
 
@@ -159,6 +167,7 @@ impl MlsMessageIn {
         match self.body {
             MlsMessageBodyIn::PublicMessage(m) => Some(m.into()),
             MlsMessageBodyIn::PrivateMessage(m) => Some(m.into()),
+            MlsMessageBodyIn::TargetedMessage(m) => Some(m.into()),
             _ => None,
         }
     }
@@ -173,14 +182,16 @@ impl MlsMessageIn {
 }
 
 /// Enum containing a message for use with `process_message` and an
-/// [`MlsGroup`]. Both [`PublicMessage`] and [`PrivateMessage`] implement
-/// [`Into<ProtocolMessage>`].
+/// [`MlsGroup`]. [`PublicMessage`], [`PrivateMessage`] and [`TargetedMessage`]
+/// implement [`Into<ProtocolMessage>`].
 #[derive(Debug, Clone)]
 pub enum ProtocolMessage {
     /// A [`ProtocolMessage`] containing a [`PrivateMessage`].
     PrivateMessage(PrivateMessageIn),
     /// A [`ProtocolMessage`] containing a [`PublicMessage`].
     PublicMessage(PublicMessageIn),
+    /// A [`ProtocolMessage`] containing a [`TargetedMessage`].
+    TargetedMessage(TargetedMessage),
 }
 
 impl ProtocolMessage {
@@ -189,6 +200,7 @@ impl ProtocolMessage {
         match self {
             ProtocolMessage::PrivateMessage(_) => WireFormat::PrivateMessage,
             ProtocolMessage::PublicMessage(_) => WireFormat::PublicMessage,
+            ProtocolMessage::TargetedMessage(_) => WireFormat::TargetedMessage,
         }
     }
 
@@ -197,6 +209,7 @@ impl ProtocolMessage {
         match self {
             ProtocolMessage::PrivateMessage(ref m) => m.group_id(),
             ProtocolMessage::PublicMessage(ref m) => m.group_id(),
+            ProtocolMessage::TargetedMessage(ref m) => m.group_id(),
         }
     }
 
@@ -205,6 +218,7 @@ impl ProtocolMessage {
         match self {
             ProtocolMessage::PrivateMessage(ref m) => m.epoch(),
             ProtocolMessage::PublicMessage(ref m) => m.epoch(),
+            ProtocolMessage::TargetedMessage(ref m) => m.epoch(),
         }
     }
 
@@ -213,6 +227,7 @@ impl ProtocolMessage {
         match self {
             ProtocolMessage::PrivateMessage(ref m) => m.content_type(),
             ProtocolMessage::PublicMessage(ref m) => m.content_type(),
+            ProtocolMessage::TargetedMessage(_) => ContentType::Application,
         }
     }
 
@@ -226,7 +241,7 @@ impl ProtocolMessage {
                 )
             }
             // external message cannot be encrypted
-            ProtocolMessage::PrivateMessage(_) => false,
+            ProtocolMessage::PrivateMessage(_) | ProtocolMessage::TargetedMessage(_) => false,
         }
     }
 
@@ -248,6 +263,12 @@ impl From<PublicMessageIn> for ProtocolMessage {
     }
 }
 
+impl From<TargetedMessage> for ProtocolMessage {
+    fn from(targeted_message: TargetedMessage) -> Self {
+        ProtocolMessage::TargetedMessage(targeted_message)
+    }
+}
+
 impl TryFrom<MlsMessageIn> for ProtocolMessage {
     type Error = ProtocolMessageError;
 
@@ -255,6 +276,7 @@ impl TryFrom<MlsMessageIn> for ProtocolMessage {
         match msg.body {
             MlsMessageBodyIn::PublicMessage(m) => Ok(ProtocolMessage::PublicMessage(m)),
             MlsMessageBodyIn::PrivateMessage(m) => Ok(ProtocolMessage::PrivateMessage(m)),
+            MlsMessageBodyIn::TargetedMessage(m) => Ok(ProtocolMessage::TargetedMessage(m)),
             _ => Err(ProtocolMessageError::WrongWireFormat),
         }
     }
@@ -51,6 +51,8 @@ pub struct MlsMessageOut {
 ///             GroupInfo group_info;
 ///         case mls_key_package:
 ///             KeyPackage key_package;
+///         case mls_targeted_message:
+///             TargetedMessage targeted_message;
 ///     }
 /// } MLSMessage;
 /// ```
@@ -77,6 +79,10 @@ pub enum MlsMessageBodyOut {
     #[tls_codec(discriminant = 5)]
     #[allow(dead_code)]
     KeyPackage(KeyPackage),
+
+    /// Message to a single group member
+    #[tls_codec(discriminant = 6)]
+    TargetedMessage(TargetedMessage),
 }
 
 impl From<PublicMessage> for MlsMessageOut {
@@ -141,6 +147,18 @@ impl MlsMessageOut {
         }
     }
 
+    /// Create an [`MlsMessageOut`] from a [`TargetedMessage`], as well as the
+    /// currently used [`ProtocolVersion`].
+    pub(crate) fn from_targeted_message(
+        targeted_message: TargetedMessage,
+        version: ProtocolVersion,
+    ) -> Self {
+        Self {
+            version,
+            body: MlsMessageBodyOut::TargetedMessage(targeted_message),
+        }
+    }
+
     /// Create an [`MlsMessageOut`] from a [`Welcome`] message and the currently
     /// used [`ProtocolVersion`].
     pub fn from_welcome(welcome: Welcome, version: ProtocolVersion) -> Self {
@@ -182,6 +200,7 @@ impl MlsMessageOut {
         match mls_message_in.extract() {
             MlsMessageBodyIn::PublicMessage(pm) => Some(pm.into()),
             MlsMessageBodyIn::PrivateMessage(pm) => Some(pm.into()),
+            MlsMessageBodyIn::TargetedMessage(tm) => Some(tm.into()),
             _ => None,
         }
     }
@@ -210,6 +229,7 @@ impl From<MlsMessageIn> for MlsMessageOut {
             MlsMessageBodyIn::KeyPackage(kp) => MlsMessageBodyOut::KeyPackage(kp.into()),
             MlsMessageBodyIn::PublicMessage(pm) => MlsMessageBodyOut::PublicMessage(pm.into()),
             MlsMessageBodyIn::PrivateMessage(pm) => MlsMessageBodyOut::PrivateMessage(pm.into()),
+            MlsMessageBodyIn::TargetedMessage(tm) => MlsMessageBodyOut::TargetedMessage(tm),
         };
         Self { version, body }
     }
@@ -227,6 +247,7 @@ impl From<MlsMessageOut> for MlsMessageIn {
                 MlsMessageBodyIn::GroupInfo(gi.into_verifiable_group_info())
             }
             MlsMessageBodyOut::KeyPackage(kp) => MlsMessageBodyIn::KeyPackage(kp.into()),
+            MlsMessageBodyOut::TargetedMessage(tm) => MlsMessageBodyIn::TargetedMessage(tm),
         };
         Self { version, body }
     }
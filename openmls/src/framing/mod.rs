@@ -63,7 +63,9 @@ pub(crate) mod private_message;
 pub(crate) mod private_message_in;
 pub(crate) mod public_message;
 pub(crate) mod public_message_in;
+pub(crate) mod safe_aad;
 pub(crate) mod sender;
+pub(crate) mod targeted_message;
 pub(crate) mod validation;
 pub(crate) use errors::*;
 
@@ -89,7 +91,9 @@ pub use private_message::*;
 pub use private_message_in::*;
 pub use public_message::*;
 pub use public_message_in::*;
+pub use safe_aad::{ComponentData, ComponentId, SafeAad};
 pub use sender::*;
+pub use targeted_message::TargetedMessage;
 pub use validation::*;
 
 // Tests
@@ -107,6 +111,7 @@ pub(crate) mod tests;
 /// | 0x0003          | mls_welcome              | Y           | RFC XXXX  |
 /// | 0x0004          | mls_group_info           | Y           | RFC XXXX  |
 /// | 0x0005          | mls_key_package          | Y           | RFC XXXX  |
+/// | 0x0006          | mls_targeted_message     | Y           | RFC XXXX  |
 /// | 0xf000 - 0xffff | Reserved for Private Use | N/A         | RFC XXXX  |
 #[derive(
     PartialEq,
@@ -133,6 +138,8 @@ pub enum WireFormat {
     GroupInfo = 4,
     /// KeyPackage
     KeyPackage = 5,
+    /// Message to a single group member
+    TargetedMessage = 6,
 }
 
 /// This struct is used to group common framing parameters
@@ -0,0 +1,159 @@
+//! # Safe AAD
+//!
+//! The safe AAD framing from draft-ietf-mls-extensions structures the
+//! additional authenticated data (AAD) of a message into items that belong
+//! to different application components. Each component is identified by a
+//! [`ComponentId`] and can only see and set its own item.
+//!
+//! ```c
+//! struct {
+//!     ComponentID component_id;
+//!     opaque data<V>;
+//! } ComponentData;
+//!
+//! struct {
+//!     ComponentData aad_items<V>;
+//! } SafeAAD;
+//! ```
+//!
+//! The items are sorted by component ID and each component ID appears at most
+//! once.
+
+use super::*;
+use crate::framing::errors::SafeAadError;
+use tls_codec::{
+    Serialize as TlsSerializeTrait, TlsDeserialize, TlsDeserializeBytes, TlsSerialize, TlsSize,
+};
+
+/// The identifier of an application component.
+pub type ComponentId = u32;
+
+/// The authenticated data of a single application component.
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Serialize,
+    Deserialize,
+    TlsSerialize,
+    TlsDeserialize,
+    TlsDeserializeBytes,
+    TlsSize,
+)]
+pub struct ComponentData {
+    component_id: ComponentId,
+    data: VLBytes,
+}
+
+impl ComponentData {
+    /// Returns the ID of the component.
+    pub fn component_id(&self) -> ComponentId {
+        self.component_id
+    }
+
+    /// Returns the data of the component.
+    pub fn data(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+}
+
+/// The additional authenticated data (AAD) of a message, structured into
+/// per-component items. See the [module documentation](self) for details.
+#[derive(
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    Serialize,
+    Deserialize,
+    TlsSerialize,
+    TlsDeserialize,
+    TlsDeserializeBytes,
+    TlsSize,
+)]
+pub struct SafeAad {
+    aad_items: Vec<ComponentData>,
+}
+
+impl SafeAad {
+    /// Creates an empty safe AAD.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the safe AAD from the AAD of a message. An empty AAD is parsed
+    /// as an empty safe AAD.
+    ///
+    /// Returns an error if the AAD is malformed or if the items are not
+    /// sorted by component ID.
+    pub fn from_aad(aad: &[u8]) -> Result<Self, SafeAadError> {
+        if aad.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let safe_aad = match Self::tls_deserialize_bytes(aad) {
+            Ok((safe_aad, [])) => safe_aad,
+            _ => return Err(SafeAadError::Malformed),
+        };
+        if !safe_aad
+            .aad_items
+            .windows(2)
+            .all(|items| items[0].component_id < items[1].component_id)
+        {
+            return Err(SafeAadError::InvalidComponentOrder);
+        }
+
+        Ok(safe_aad)
+    }
+
+    /// Encodes the safe AAD, so it can be used as the AAD of a message.
+    pub fn to_aad(&self) -> Result<Vec<u8>, SafeAadError> {
+        self.tls_serialize_detached()
+            .map_err(|_| SafeAadError::Malformed)
+    }
+
+    /// Sets the `data` of the component with the given ID. This replaces any
+    /// previous data of the component.
+    pub fn insert(&mut self, component_id: ComponentId, data: Vec<u8>) {
+        let item = ComponentData {
+            component_id,
+            data: data.into(),
+        };
+        match self
+            .aad_items
+            .binary_search_by_key(&component_id, ComponentData::component_id)
+        {
+            Ok(position) => self.aad_items[position] = item,
+            Err(position) => self.aad_items.insert(position, item),
+        }
+    }
+
+    /// Returns the data of the component with the given ID, if there is any.
+    pub fn get(&self, component_id: ComponentId) -> Option<&[u8]> {
+        self.aad_items
+            .binary_search_by_key(&component_id, ComponentData::component_id)
+            .ok()
+            .map(|position| self.aad_items[position].data())
+    }
+
+    /// Removes the data of the component with the given ID. Returns `false`
+    /// if there was none.
+    pub fn remove(&mut self, component_id: ComponentId) -> bool {
+        let length = self.aad_items.len();
+        self.aad_items
+            .retain(|item| item.component_id != component_id);
+        self.aad_items.len() != length
+    }
+
+    /// Returns an iterator over the items, sorted by component ID.
+    pub fn iter(&self) -> impl Iterator<Item = &ComponentData> {
+        self.aad_items.iter()
+    }
+
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.aad_items.is_empty()
+    }
+}
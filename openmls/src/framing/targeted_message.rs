@@ -0,0 +1,338 @@
+//! # Targeted messages
+//!
+//! A targeted message is an application message that is sent to a single
+//! member of a group, as defined in draft-ietf-mls-extensions. The message is
+//! signed with the sender's signature key and HPKE-encrypted to the
+//! encryption key in the recipient's leaf node.
+//!
+//! ```c
+//! struct {
+//!     opaque group_id<V>;
+//!     uint64 epoch;
+//!     uint32 recipient_leaf_index;
+//!     opaque authenticated_data<V>;
+//!     HPKECiphertext hpke_ciphertext;
+//! } TargetedMessage;
+//!
+//! struct {
+//!     uint32 sender_leaf_index;
+//!     opaque application_data<V>;
+//!     opaque signature<V>;
+//! } TargetedMessageContent;
+//! ```
+//!
+//! The signature is computed with the label `"TargetedMessageTBS"` over the
+//! following structure:
+//!
+//! ```c
+//! struct {
+//!     opaque group_id<V>;
+//!     uint64 epoch;
+//!     uint32 recipient_leaf_index;
+//!     opaque authenticated_data<V>;
+//!     uint32 sender_leaf_index;
+//!     opaque application_data<V>;
+//! } TargetedMessageTBS;
+//! ```
+//!
+//! The `TargetedMessageContent` is encrypted with the label
+//! `"TargetedMessageData"`. To bind the message to the group, the draft uses
+//! the HPKE PSK mode with a secret exported from the group. The crypto
+//! provider only offers the HPKE base mode, so the exported secret is part of
+//! the encryption context instead:
+//!
+//! ```c
+//! struct {
+//!     opaque group_id<V>;
+//!     uint64 epoch;
+//!     uint32 recipient_leaf_index;
+//!     opaque authenticated_data<V>;
+//!     opaque targeted_message_psk<V>;
+//! } TargetedMessageContext;
+//! ```
+//!
+//! where `targeted_message_psk` is
+//! `MLS-Exporter("targeted message psk", "", KDF.Nh)`.
+
+use openmls_traits::{
+    crypto::OpenMlsCrypto,
+    signatures::Signer,
+    types::{Ciphersuite, HpkeCiphertext},
+};
+use tls_codec::{
+    Deserialize as TlsDeserializeTrait, Serialize as TlsSerializeTrait, TlsDeserialize,
+    TlsDeserializeBytes, TlsSerialize, TlsSize, VLBytes,
+};
+
+use super::*;
+use crate::{
+    binary_tree::array_representation::LeafNodeIndex,
+    ciphersuite::signable::{Signable, SignatureError, SignedStruct, Verifiable, VerifiedStruct},
+    error::LibraryError,
+    treesync::node::encryption_keys::{EncryptionKey, EncryptionPrivateKey},
+};
+
+const SIGNATURE_TARGETED_MESSAGE_LABEL: &str = "TargetedMessageTBS";
+const ENCRYPTION_TARGETED_MESSAGE_LABEL: &str = "TargetedMessageData";
+
+/// The exporter label of the secret that binds targeted messages to the group.
+pub(crate) const TARGETED_MESSAGE_PSK_LABEL: &str = "targeted message psk";
+
+/// A message to a single member of a group. See the
+/// [module documentation](self) for details.
+#[derive(Debug, PartialEq, Clone, TlsSerialize, TlsDeserialize, TlsDeserializeBytes, TlsSize)]
+pub struct TargetedMessage {
+    group_id: GroupId,
+    epoch: GroupEpoch,
+    recipient_leaf_index: LeafNodeIndex,
+    authenticated_data: VLBytes,
+    hpke_ciphertext: HpkeCiphertext,
+}
+
+impl TargetedMessage {
+    /// Signs the `payload` and encrypts it to the `encryption_key` of the
+    /// recipient.
+    pub(crate) fn seal(
+        payload: TargetedMessageTbs,
+        crypto: &impl OpenMlsCrypto,
+        ciphersuite: Ciphersuite,
+        signer: &impl Signer,
+        encryption_key: &EncryptionKey,
+        targeted_message_psk: &[u8],
+    ) -> Result<Self, LibraryError> {
+        let group_id = payload.group_id.clone();
+        let epoch = payload.epoch;
+        let recipient_leaf_index = payload.recipient_leaf_index;
+        let authenticated_data = payload.authenticated_data.clone();
+
+        let authenticated_content = payload
+            .sign(signer)
+            .map_err(|_| LibraryError::custom("Signing failed"))?;
+        let plaintext = TargetedMessageContent::from(authenticated_content)
+            .tls_serialize_detached()
+            .map_err(LibraryError::missing_bound_check)?;
+
+        let context = TargetedMessageContext {
+            group_id: group_id.clone(),
+            epoch,
+            recipient_leaf_index,
+            authenticated_data: authenticated_data.clone(),
+            targeted_message_psk: targeted_message_psk.into(),
+        }
+        .tls_serialize_detached()
+        .map_err(LibraryError::missing_bound_check)?;
+        let hpke_ciphertext = encryption_key.encrypt_with_label(
+            crypto,
+            ciphersuite,
+            ENCRYPTION_TARGETED_MESSAGE_LABEL,
+            &context,
+            &plaintext,
+        )?;
+
+        Ok(Self {
+            group_id,
+            epoch,
+            recipient_leaf_index,
+            authenticated_data,
+            hpke_ciphertext,
+        })
+    }
+
+    /// Decrypts the message with the `private_key` of the recipient. The
+    /// signature of the returned content still has to be verified.
+    pub(crate) fn open(
+        &self,
+        crypto: &impl OpenMlsCrypto,
+        ciphersuite: Ciphersuite,
+        private_key: &EncryptionPrivateKey,
+        targeted_message_psk: &[u8],
+    ) -> Result<VerifiableTargetedMessageContent, TargetedMessageError> {
+        let context = TargetedMessageContext {
+            group_id: self.group_id.clone(),
+            epoch: self.epoch,
+            recipient_leaf_index: self.recipient_leaf_index,
+            authenticated_data: self.authenticated_data.clone(),
+            targeted_message_psk: targeted_message_psk.into(),
+        }
+        .tls_serialize_detached()
+        .map_err(LibraryError::missing_bound_check)?;
+        let plaintext = private_key
+            .decrypt_with_label(
+                crypto,
+                ciphersuite,
+                ENCRYPTION_TARGETED_MESSAGE_LABEL,
+                &context,
+                &self.hpke_ciphertext,
+            )
+            .map_err(|_| TargetedMessageError::DecryptionFailed)?;
+        let content = TargetedMessageContent::tls_deserialize_exact(plaintext)
+            .map_err(|_| TargetedMessageError::MalformedContent)?;
+
+        Ok(VerifiableTargetedMessageContent {
+            payload: TargetedMessageTbs {
+                group_id: self.group_id.clone(),
+                epoch: self.epoch,
+                recipient_leaf_index: self.recipient_leaf_index,
+                authenticated_data: self.authenticated_data.clone(),
+                sender_leaf_index: content.sender_leaf_index,
+                application_data: content.application_data,
+            },
+            signature: content.signature,
+        })
+    }
+
+    /// Returns the group ID.
+    pub fn group_id(&self) -> &GroupId {
+        &self.group_id
+    }
+
+    /// Returns the epoch.
+    pub fn epoch(&self) -> GroupEpoch {
+        self.epoch
+    }
+
+    /// Returns the leaf index of the recipient.
+    pub fn recipient_leaf_index(&self) -> LeafNodeIndex {
+        self.recipient_leaf_index
+    }
+
+    /// Returns the authenticated data.
+    pub fn authenticated_data(&self) -> &[u8] {
+        self.authenticated_data.as_slice()
+    }
+}
+
+/// The encryption context of a [`TargetedMessage`].
+#[derive(TlsSerialize, TlsSize)]
+struct TargetedMessageContext {
+    group_id: GroupId,
+    epoch: GroupEpoch,
+    recipient_leaf_index: LeafNodeIndex,
+    authenticated_data: VLBytes,
+    targeted_message_psk: VLBytes,
+}
+
+/// The encrypted content of a [`TargetedMessage`].
+#[derive(TlsSerialize, TlsDeserialize, TlsDeserializeBytes, TlsSize)]
+struct TargetedMessageContent {
+    sender_leaf_index: LeafNodeIndex,
+    application_data: VLBytes,
+    signature: Signature,
+}
+
+impl From<AuthenticatedTargetedMessageContent> for TargetedMessageContent {
+    fn from(authenticated_content: AuthenticatedTargetedMessageContent) -> Self {
+        Self {
+            sender_leaf_index: authenticated_content.payload.sender_leaf_index,
+            application_data: authenticated_content.payload.application_data,
+            signature: authenticated_content.signature,
+        }
+    }
+}
+
+/// The signed content of a [`TargetedMessage`].
+#[derive(Debug, TlsSerialize, TlsSize)]
+pub(crate) struct TargetedMessageTbs {
+    group_id: GroupId,
+    epoch: GroupEpoch,
+    recipient_leaf_index: LeafNodeIndex,
+    authenticated_data: VLBytes,
+    sender_leaf_index: LeafNodeIndex,
+    application_data: VLBytes,
+}
+
+impl TargetedMessageTbs {
+    pub(crate) fn new(
+        group_id: GroupId,
+        epoch: GroupEpoch,
+        recipient_leaf_index: LeafNodeIndex,
+        authenticated_data: &[u8],
+        sender_leaf_index: LeafNodeIndex,
+        application_data: &[u8],
+    ) -> Self {
+        Self {
+            group_id,
+            epoch,
+            recipient_leaf_index,
+            authenticated_data: authenticated_data.into(),
+            sender_leaf_index,
+            application_data: application_data.into(),
+        }
+    }
+}
+
+impl Signable for TargetedMessageTbs {
+    type SignedOutput = AuthenticatedTargetedMessageContent;
+
+    fn unsigned_payload(&self) -> Result<Vec<u8>, tls_codec::Error> {
+        self.tls_serialize_detached()
+    }
+
+    fn label(&self) -> &str {
+        SIGNATURE_TARGETED_MESSAGE_LABEL
+    }
+}
+
+/// The content of a [`TargetedMessage`] with a signature that was either
+/// created or verified.
+#[derive(Debug)]
+pub(crate) struct AuthenticatedTargetedMessageContent {
+    payload: TargetedMessageTbs,
+    signature: Signature,
+}
+
+impl AuthenticatedTargetedMessageContent {
+    pub(crate) fn into_application_data(self) -> Vec<u8> {
+        self.payload.application_data.into()
+    }
+}
+
+impl SignedStruct<TargetedMessageTbs> for AuthenticatedTargetedMessageContent {
+    fn from_payload(payload: TargetedMessageTbs, signature: Signature) -> Self {
+        Self { payload, signature }
+    }
+}
+
+impl VerifiedStruct for AuthenticatedTargetedMessageContent {}
+
+/// The decrypted content of a [`TargetedMessage`] with a signature that
+/// hasn't been verified yet.
+#[derive(Debug)]
+pub(crate) struct VerifiableTargetedMessageContent {
+    payload: TargetedMessageTbs,
+    signature: Signature,
+}
+
+impl VerifiableTargetedMessageContent {
+    pub(crate) fn sender_leaf_index(&self) -> LeafNodeIndex {
+        self.payload.sender_leaf_index
+    }
+}
+
+impl Verifiable for VerifiableTargetedMessageContent {
+    type VerifiedStruct = AuthenticatedTargetedMessageContent;
+
+    fn unsigned_payload(&self) -> Result<Vec<u8>, tls_codec::Error> {
+        self.payload.tls_serialize_detached()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn label(&self) -> &str {
+        SIGNATURE_TARGETED_MESSAGE_LABEL
+    }
+
+    fn verify(
+        self,
+        crypto: &impl OpenMlsCrypto,
+        pk: &OpenMlsSignaturePublicKey,
+    ) -> Result<Self::VerifiedStruct, SignatureError> {
+        self.verify_no_out(crypto, pk)?;
+        Ok(AuthenticatedTargetedMessageContent {
+            payload: self.payload,
+            signature: self.signature,
+        })
+    }
+}
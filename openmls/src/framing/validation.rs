@@ -338,6 +338,12 @@ impl ProcessedMessage {
         &self.authenticated_data
     }
 
+    /// Parses the additional authenticated data (AAD) of the message as
+    /// [`SafeAad`].
+    pub fn safe_aad(&self) -> Result<SafeAad, SafeAadError> {
+        SafeAad::from_aad(&self.authenticated_data)
+    }
+
     /// Returns the content of the message.
     pub fn content(&self) -> &ProcessedMessageContent {
         &self.content
@@ -385,6 +391,10 @@ pub enum ProcessedMessageContent {
     /// the commit should be merged into the group's state using
     /// [`MlsGroup::merge_staged_commit()`](crate::group::mls_group::MlsGroup::merge_staged_commit()).
     StagedCommitMessage(Box<StagedCommit>),
+    /// A [`TargetedMessage`] sent to this member only.
+    ///
+    /// The [`ApplicationMessage`] contains a vector of bytes that can be used right-away.
+    TargetedMessage(ApplicationMessage),
 }
 
 /// Application message received through a [ProcessedMessage].
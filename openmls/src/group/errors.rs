@@ -2,6 +2,7 @@
 //!
 //! This module contains errors that originate at lower levels and are partially re-exported in errors thrown by functions of the `MlsGroup` API.
 
+use openmls_traits::types::CryptoError;
 use thiserror::Error;
 
 pub use super::mls_group::errors::*;
@@ -9,7 +10,7 @@ use super::public_group::errors::CreationFromExternalError;
 use crate::{
     ciphersuite::signable::SignatureError,
     error::LibraryError,
-    extensions::errors::{ExtensionError, InvalidExtensionError},
+    extensions::errors::{ExtensionError, InvalidExtensionError, TypedExtensionError},
     framing::errors::MessageDecryptionError,
     key_packages::errors::{KeyPackageExtensionSupportError, KeyPackageVerifyError},
     messages::{group_info::GroupInfoError, GroupSecretsError},
@@ -90,6 +91,15 @@ pub enum WelcomeError<StorageError> {
     /// This error indicates that an error occurred while reading or writing from/to storage.
     #[error("An error occurred when querying storage")]
     StorageError(StorageError),
+    /// The init key of the key package is held by a key handle provider, but
+    /// none was given.
+    #[error(
+        "The init key of the key package is held by a key handle provider, but none was given."
+    )]
+    MissingKeyHandleProvider,
+    /// The key handle provider failed to delete the init key.
+    #[error("The key handle provider failed to delete the init key: {0}")]
+    KeyHandleError(CryptoError),
 }
 
 /// External Commit error
@@ -199,6 +209,10 @@ pub enum StageCommitError {
     GroupContextExtensionsProposalValidationError(
         #[from] GroupContextExtensionsProposalValidationError,
     ),
+    /// An extension that is registered in the group's
+    /// [`ExtensionRegistry`](crate::extensions::ExtensionRegistry) is invalid.
+    #[error(transparent)]
+    InvalidRegisteredExtension(#[from] TypedExtensionError),
 }
 
 /// Create commit error
@@ -344,6 +358,13 @@ pub enum ProposalValidationError {
     /// The sender could not be matched to a member of the group.
     #[error("The sender could not be matched to a member of the group.")]
     UnknownMember,
+    /// The proposal was denied by the group's proposal policy.
+    #[error("The proposal was denied by the group's proposal policy.")]
+    ProposalDenied,
+    /// The sender is not an admin of the group, but the proposal requires
+    /// admin rights.
+    #[error("The sender is not an admin of the group, but the proposal requires admin rights.")]
+    NotAnAdmin,
     /// Duplicate signature key in proposals and group.
     #[error("Duplicate signature key in proposals and group.")]
     DuplicateSignatureKey,
@@ -362,6 +383,9 @@ pub enum ProposalValidationError {
     /// The remove proposal referenced a non-existing member.
     #[error("The remove proposal referenced a non-existing member.")]
     UnknownMemberRemoval,
+    /// Found a SelfRemove proposal from a non-member.
+    #[error("Found a SelfRemove proposal from a non-member.")]
+    SelfRemoveFromNonMember,
     /// Found an update from a non-member.
     #[error("Found an update from a non-member.")]
     UpdateFromNonMember,
@@ -382,6 +406,10 @@ pub enum ProposalValidationError {
     /// The proposal type is not supported by all group members.
     #[error("The proposal type is not supported by all group members.")]
     UnsupportedProposalType,
+    /// A member does not support all media types that are required by the
+    /// group.
+    #[error("A member does not support all media types that are required by the group.")]
+    UnsupportedMediaTypes,
 }
 
 /// External Commit validaton error
@@ -443,6 +471,9 @@ pub(crate) enum ProposalQueueError {
     /// Update proposal from external sender.
     #[error("Update proposal from external sender.")]
     UpdateFromExternalSender,
+    /// SelfRemove proposal from external sender.
+    #[error("SelfRemove proposal from external sender.")]
+    SelfRemoveFromExternalSender,
 }
 
 /// Errors that can arise when creating a [`ProposalQueue`] from committed
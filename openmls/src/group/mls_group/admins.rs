@@ -0,0 +1,99 @@
+//! MLS group admins
+//!
+//! This module contains convenience functions to manage the admins of a group
+//! that uses the [`AdminsExtension`]. Promoting and demoting members is done
+//! through GroupContextExtensions proposals, which themselves can only be
+//! sent by admins.
+
+use openmls_traits::signatures::Signer;
+
+use super::{errors::AdminProposalError, *};
+use crate::{extensions::AdminsExtension, storage::OpenMlsProvider};
+
+impl MlsGroup {
+    /// Returns the [`AdminsExtension`] of the group, if there is one.
+    pub fn admins(&self) -> Option<&AdminsExtension> {
+        self.public_group.group_context().extensions().admins()
+    }
+
+    /// Returns `true` if the member at the given leaf index is an admin.
+    ///
+    /// If the group does not have an [`AdminsExtension`], there are no
+    /// admins and `false` is returned.
+    pub fn is_admin(&self, member: LeafNodeIndex) -> bool {
+        match (self.admins(), self.public_group.leaf(member)) {
+            (Some(admins), Some(leaf)) => admins.is_admin(leaf.credential()),
+            _ => false,
+        }
+    }
+
+    /// Creates a GroupContextExtensions proposal that adds the credential of
+    /// the member at the given leaf index to the [`AdminsExtension`].
+    ///
+    /// If the group does not have an [`AdminsExtension`] yet, one is created
+    /// with the member as the only admin. Note that this restricts all
+    /// other members once the proposal is committed.
+    ///
+    /// Returns an error if the member does not exist or is an admin already.
+    pub fn propose_promote_admin<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        member: LeafNodeIndex,
+    ) -> Result<(MlsMessageOut, ProposalRef), AdminProposalError<Provider::StorageError>> {
+        let credential = self.member_credential(member)?;
+        let mut admins = self.admins().cloned().unwrap_or_default();
+        if !admins.add_admin(credential) {
+            return Err(AdminProposalError::AlreadyAdmin);
+        }
+
+        self.propose_admins(provider, signer, admins)
+    }
+
+    /// Creates a GroupContextExtensions proposal that removes the credential
+    /// of the member at the given leaf index from the [`AdminsExtension`].
+    ///
+    /// Returns an error if the member does not exist or is not an admin.
+    pub fn propose_demote_admin<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        member: LeafNodeIndex,
+    ) -> Result<(MlsMessageOut, ProposalRef), AdminProposalError<Provider::StorageError>> {
+        let credential = self.member_credential(member)?;
+        let mut admins = self
+            .admins()
+            .cloned()
+            .ok_or(AdminProposalError::NotAnAdmin)?;
+        if !admins.remove_admin(&credential) {
+            return Err(AdminProposalError::NotAnAdmin);
+        }
+
+        self.propose_admins(provider, signer, admins)
+    }
+
+    /// Returns the credential of the member at the given leaf index.
+    fn member_credential<StorageError>(
+        &self,
+        member: LeafNodeIndex,
+    ) -> Result<Credential, AdminProposalError<StorageError>> {
+        self.public_group
+            .leaf(member)
+            .map(|leaf| leaf.credential().clone())
+            .ok_or(AdminProposalError::UnknownMember)
+    }
+
+    /// Proposes the current group context extensions with the given
+    /// [`AdminsExtension`].
+    fn propose_admins<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        admins: AdminsExtension,
+    ) -> Result<(MlsMessageOut, ProposalRef), AdminProposalError<Provider::StorageError>> {
+        let mut extensions = self.public_group.group_context().extensions().clone();
+        extensions.add_or_replace(Extension::Admins(admins));
+
+        Ok(self.propose_group_context_extensions(provider, extensions, signer)?)
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use openmls_traits::{signatures::Signer, types::Ciphersuite};
 use tls_codec::Serialize;
 
@@ -18,7 +20,7 @@ use crate::{
     },
     storage::OpenMlsProvider,
     tree::sender_ratchet::SenderRatchetConfiguration,
-    treesync::node::leaf_node::Capabilities,
+    treesync::{errors::LeafNodeValidationError, node::leaf_node::Capabilities},
 };
 
 use super::{past_secrets::MessageSecretsStore, MlsGroup, MlsGroupState};
@@ -71,6 +73,27 @@ impl MlsGroupBuilder {
             .unwrap_or_else(|| GroupId::random(provider.rand()));
         let ciphersuite = mls_group_create_config.ciphersuite;
 
+        // Make sure the creator's leaf node supports everything the group
+        // requires before signing anything.
+        mls_group_create_config
+            .capabilities
+            .check_group_requirements(
+                &mls_group_create_config.group_context_extensions,
+                &mls_group_create_config.leaf_node_extensions,
+            )
+            .map_err(|e| match e {
+                LeafNodeValidationError::UnsupportedProposals => {
+                    NewGroupError::UnsupportedProposalType
+                }
+                LeafNodeValidationError::UnsupportedCredentials => {
+                    NewGroupError::UnsupportedCredentialType
+                }
+                LeafNodeValidationError::UnsupportedMediaTypes => {
+                    NewGroupError::UnsupportedMediaTypes
+                }
+                _ => NewGroupError::UnsupportedExtensionType,
+            })?;
+
         let (public_group_builder, commit_secret, leaf_keypair) =
             PublicGroup::builder(group_id, ciphersuite, credential_with_key)
                 .with_group_context_extensions(
@@ -152,6 +175,7 @@ impl MlsGroupBuilder {
             mls_group_config: mls_group_create_config.join_config.clone(),
             own_leaf_nodes: vec![],
             aad: vec![],
+            components: BTreeSet::new(),
             group_state: MlsGroupState::Operational,
             public_group,
             group_epoch_secrets,
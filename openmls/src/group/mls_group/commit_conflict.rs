@@ -0,0 +1,188 @@
+//! Commit conflict resolution
+//!
+//! If this client created a commit and, before it was accepted, a commit of
+//! another member for the same epoch arrives, the own pending commit is
+//! superseded. This module contains the [`SupersededCommit`] report that
+//! [`MlsGroup::process_message()`] attaches to the incoming [`StagedCommit`]
+//! in that case, as well as the functionality to re-propose the own proposals
+//! that were dropped.
+
+use openmls_traits::signatures::Signer;
+
+use super::{
+    errors::{ProposalError, ReproposeError},
+    *,
+};
+use crate::{schedule::Psk, storage::OpenMlsProvider};
+
+/// Describes the own pending commit that was superseded by a commit of
+/// another member for the same epoch.
+///
+/// The own proposals of the pending commit, i.e. the inline proposals and the
+/// proposals by reference that were sent by this client, are split into the
+/// ones that are covered by the incoming commit as well and the ones that
+/// were dropped. The dropped proposals can be re-proposed in the new epoch
+/// with [`MlsGroup::repropose_dropped_proposals()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupersededCommit {
+    epoch: GroupEpoch,
+    included_proposals: Vec<QueuedProposal>,
+    dropped_proposals: Vec<QueuedProposal>,
+    // The members targeted by dropped remove proposals, as they were in the
+    // epoch of the superseded commit. This allows finding them again in the
+    // new epoch.
+    removed_members: Vec<Member>,
+}
+
+impl SupersededCommit {
+    /// Returns the epoch of the superseded commit.
+    pub fn epoch(&self) -> GroupEpoch {
+        self.epoch
+    }
+
+    /// Returns the own proposals that are covered by the incoming commit.
+    pub fn included_proposals(&self) -> impl Iterator<Item = &QueuedProposal> {
+        self.included_proposals.iter()
+    }
+
+    /// Returns the own proposals that are not covered by the incoming commit.
+    pub fn dropped_proposals(&self) -> impl Iterator<Item = &QueuedProposal> {
+        self.dropped_proposals.iter()
+    }
+
+    /// Returns `true` if all own proposals are covered by the incoming commit.
+    pub fn all_included(&self) -> bool {
+        self.dropped_proposals.is_empty()
+    }
+}
+
+impl MlsGroup {
+    /// Compares the own pending commit with the incoming `staged_commit`.
+    ///
+    /// Returns `None` if there is no pending commit created by this client.
+    pub(super) fn superseded_commit(
+        &self,
+        staged_commit: &StagedCommit,
+    ) -> Option<SupersededCommit> {
+        let MlsGroupState::PendingCommit(ref pending_commit_state) = self.group_state else {
+            return None;
+        };
+        let PendingCommitState::Member(ref pending_commit) = **pending_commit_state else {
+            return None;
+        };
+
+        let own_sender = Sender::Member(self.own_leaf_index());
+        let (included_proposals, dropped_proposals): (Vec<_>, Vec<_>) = pending_commit
+            .queued_proposals()
+            .filter(|own_proposal| own_proposal.sender() == &own_sender)
+            .cloned()
+            .partition(|own_proposal| {
+                // Inline proposals have different references, so we compare
+                // the proposals themselves as well.
+                staged_commit.queued_proposals().any(|proposal| {
+                    proposal.proposal_reference() == own_proposal.proposal_reference()
+                        || proposal.proposal() == own_proposal.proposal()
+                })
+            });
+
+        let removed_members = dropped_proposals
+            .iter()
+            .filter_map(|queued_proposal| match queued_proposal.proposal() {
+                Proposal::Remove(remove_proposal) => self
+                    .members()
+                    .find(|member| member.index == remove_proposal.removed()),
+                _ => None,
+            })
+            .collect();
+
+        Some(SupersededCommit {
+            epoch: self.epoch(),
+            included_proposals,
+            dropped_proposals,
+            removed_members,
+        })
+    }
+
+    /// Re-proposes the own proposals that were dropped when the pending commit
+    /// was superseded by the commit of another member.
+    ///
+    /// This has to be called after the superseding commit was merged. All
+    /// proposals are sent by reference and added to the proposal store.
+    /// Proposals that have become obsolete in the new epoch are skipped, i.e.
+    /// adds of clients whose signature key is already in the group and
+    /// removes of members that are no longer in the group. Update proposals
+    /// are skipped as well. Use [`MlsGroup::self_update()`] to update the own
+    /// leaf instead.
+    ///
+    /// Returns the proposal messages and references of the new proposals.
+    pub fn repropose_dropped_proposals<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        superseded_commit: &SupersededCommit,
+    ) -> Result<Vec<(MlsMessageOut, ProposalRef)>, ReproposeError<Provider::StorageError>> {
+        if self.epoch() <= superseded_commit.epoch() {
+            return Err(ReproposeError::SupersedingCommitNotMerged);
+        }
+
+        let mut proposals = Vec::new();
+        for queued_proposal in superseded_commit.dropped_proposals() {
+            let proposal = match queued_proposal.proposal().clone() {
+                Proposal::Add(add_proposal) => {
+                    let signature_key = add_proposal.key_package().leaf_node().signature_key();
+                    if self
+                        .members()
+                        .any(|member| member.signature_key == signature_key.as_slice())
+                    {
+                        continue;
+                    }
+                    self.propose_add_member(provider, signer, add_proposal.key_package())
+                        .map_err(ProposalError::from)?
+                }
+                Proposal::Remove(remove_proposal) => {
+                    let Some(removed_member) = superseded_commit
+                        .removed_members
+                        .iter()
+                        .find(|member| member.index == remove_proposal.removed())
+                    else {
+                        continue;
+                    };
+                    // The leaf index may have been reused in the new epoch,
+                    // so we look the member up by its signature key.
+                    let Some(member) = self
+                        .members()
+                        .find(|member| member.signature_key == removed_member.signature_key)
+                    else {
+                        continue;
+                    };
+                    self.propose_remove_member(provider, signer, member.index)
+                        .map_err(ProposalError::from)?
+                }
+                Proposal::PreSharedKey(psk_proposal) => {
+                    let psk_id = psk_proposal.into_psk_id();
+                    if !matches!(psk_id.psk(), Psk::External(_)) {
+                        continue;
+                    }
+                    self.propose_external_psk(provider, signer, psk_id)?
+                }
+                Proposal::GroupContextExtensions(gce_proposal) => self
+                    .propose_group_context_extensions(
+                        provider,
+                        gce_proposal.extensions().clone(),
+                        signer,
+                    )?,
+                Proposal::Custom(custom_proposal) => {
+                    self.propose_custom_proposal_by_reference(provider, signer, custom_proposal)?
+                }
+                Proposal::Update(_)
+                | Proposal::ReInit(_)
+                | Proposal::ExternalInit(_)
+                | Proposal::AppAck(_)
+                | Proposal::SelfRemove => continue,
+            };
+            proposals.push(proposal);
+        }
+
+        Ok(proposals)
+    }
+}
@@ -0,0 +1,286 @@
+//! Commit scheduling
+//!
+//! This module contains the [`CommitScheduler`], an optional helper on top of
+//! [`MlsGroup`] that decides which member commits the pending proposals of an
+//! epoch and when it does so.
+//!
+//! The scheduler does not hold any group state itself. The application feeds
+//! it the group and the current time whenever something changes (e.g. after
+//! storing a pending proposal) and the scheduler creates the commit once this
+//! client is the elected committer and the scheduled time has come.
+
+use std::{collections::HashSet, time::Duration};
+
+use openmls_traits::{random::OpenMlsRand, signatures::Signer};
+
+use super::{errors::CommitSchedulerError, *};
+use crate::{group::SupersededCommit, messages::group_info::GroupInfo, storage::OpenMlsProvider};
+
+/// The rule a [`CommitScheduler`] uses to elect the member that commits the
+/// pending proposals of an epoch.
+///
+/// All members of a group have to use the same rule in order to agree on the
+/// committer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitterElection {
+    /// The eligible member with the lowest leaf index commits.
+    LowestLeafIndex,
+    /// The sender of the oldest pending proposal commits. If that sender is
+    /// not an eligible member, e.g. because it is an external sender or
+    /// because it proposed its own removal, the eligible member with the
+    /// lowest leaf index commits instead.
+    Proposer,
+    /// The given member commits. If it is not an eligible member, the
+    /// eligible member with the lowest leaf index commits instead.
+    Designated(LeafNodeIndex),
+    /// Every eligible member commits after a random delay of at most
+    /// `max_delay`. The first commit that reaches the group wins and all
+    /// other members have to resolve the race with
+    /// [`CommitScheduler::handle_staged_commit()`].
+    RandomBackoff {
+        /// The upper bound of the random delay.
+        max_delay: Duration,
+    },
+}
+
+/// A [`CommitScheduler`] watches the pending proposals of an [`MlsGroup`] and
+/// commits them if this client is the committer elected by the configured
+/// [`CommitterElection`] rule.
+///
+/// Time is passed in by the application as a [`Duration`] since an arbitrary,
+/// but fixed, reference point. This keeps the scheduler independent of the
+/// platform's clock.
+///
+/// Members that are removed by one of the pending proposals are never elected.
+///
+/// The scheduler is not persisted. After loading a group from storage, a new
+/// scheduler can be created and will pick up the pending proposals on the
+/// next call to [`CommitScheduler::poll()`].
+#[derive(Debug, Clone)]
+pub struct CommitScheduler {
+    election: CommitterElection,
+    takeover_timeout: Option<Duration>,
+    // The epoch in which pending proposals were first observed and the time at
+    // which that happened.
+    pending_since: Option<(GroupEpoch, Duration)>,
+    // The delay drawn for the current epoch if the election rule is
+    // `RandomBackoff`.
+    backoff: Duration,
+}
+
+impl CommitScheduler {
+    /// Creates a new [`CommitScheduler`] that uses the given
+    /// [`CommitterElection`] rule.
+    pub fn new(election: CommitterElection) -> Self {
+        Self {
+            election,
+            takeover_timeout: None,
+            pending_since: None,
+            backoff: Duration::ZERO,
+        }
+    }
+
+    /// Sets a takeover timeout. If the elected committer did not commit the
+    /// pending proposals within `timeout` after they were first observed,
+    /// this client commits them instead, even if it was not elected.
+    pub fn with_takeover_timeout(mut self, timeout: Duration) -> Self {
+        self.takeover_timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the [`CommitterElection`] rule of this scheduler.
+    pub fn election(&self) -> CommitterElection {
+        self.election
+    }
+
+    /// Returns the leaf index of the member that is elected to commit the
+    /// pending proposals of the `group`.
+    ///
+    /// Returns `None` if there are no pending proposals, if no member is
+    /// eligible, or if the election rule is
+    /// [`CommitterElection::RandomBackoff`], where every eligible member may
+    /// commit.
+    pub fn elected_committer(&self, group: &MlsGroup) -> Option<LeafNodeIndex> {
+        let oldest_proposal = group.pending_proposals().next()?;
+        let eligible = Self::eligible_members(group);
+        let lowest = eligible.iter().min().copied();
+
+        match self.election {
+            CommitterElection::LowestLeafIndex => lowest,
+            CommitterElection::Proposer => match oldest_proposal.sender() {
+                Sender::Member(index) if eligible.contains(index) => Some(*index),
+                _ => lowest,
+            },
+            CommitterElection::Designated(index) => {
+                if eligible.contains(&index) {
+                    Some(index)
+                } else {
+                    lowest
+                }
+            }
+            CommitterElection::RandomBackoff { .. } => None,
+        }
+    }
+
+    /// Returns the time at which this client will commit the pending
+    /// proposals of the `group` if no other commit arrives before.
+    ///
+    /// Returns `None` if there is nothing to commit, if this client is not
+    /// going to commit, or if the proposals have not been observed by
+    /// [`CommitScheduler::poll()`] yet.
+    pub fn next_deadline(&self, group: &MlsGroup) -> Option<Duration> {
+        let (epoch, since) = self.pending_since?;
+        if epoch != group.epoch() || group.pending_proposals().next().is_none() {
+            return None;
+        }
+
+        let own_index = group.own_leaf_index();
+        let eligible = Self::eligible_members(group);
+        if !eligible.contains(&own_index) {
+            return None;
+        }
+
+        let deadline = match self.election {
+            CommitterElection::RandomBackoff { .. } => Some(since + self.backoff),
+            _ if self.elected_committer(group) == Some(own_index) => Some(since),
+            _ => None,
+        };
+
+        match (deadline, self.takeover_timeout) {
+            (Some(deadline), _) => Some(deadline),
+            (None, Some(timeout)) => Some(since + timeout),
+            (None, None) => None,
+        }
+    }
+
+    /// Observes the pending proposals of the `group` and commits them if this
+    /// client is the elected committer and the scheduled time has come.
+    ///
+    /// This should be called whenever the pending proposals change, e.g.
+    /// after [`MlsGroup::store_pending_proposal()`], and whenever the time
+    /// returned by [`CommitScheduler::next_deadline()`] has passed.
+    ///
+    /// If a commit was created, it returns the same values as
+    /// [`MlsGroup::commit_to_pending_proposals()`] and the group is in the
+    /// [`MlsGroupState::PendingCommit`] state. Otherwise it returns `None`.
+    // FIXME: #1217
+    #[allow(clippy::type_complexity)]
+    pub fn poll<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        group: &mut MlsGroup,
+        now: Duration,
+    ) -> Result<
+        Option<(MlsMessageOut, Option<MlsMessageOut>, Option<GroupInfo>)>,
+        CommitSchedulerError<Provider::StorageError>,
+    > {
+        // We already have a commit in flight.
+        if group.pending_commit().is_some() {
+            return Ok(None);
+        }
+
+        if group.pending_proposals().next().is_none() {
+            self.reset();
+            return Ok(None);
+        }
+
+        let epoch = group.epoch();
+        if !matches!(self.pending_since, Some((since_epoch, _)) if since_epoch == epoch) {
+            self.pending_since = Some((epoch, now));
+            self.backoff = match self.election {
+                CommitterElection::RandomBackoff { max_delay } => {
+                    random_delay(provider.rand(), max_delay)?
+                }
+                _ => Duration::ZERO,
+            };
+        }
+
+        match self.next_deadline(group) {
+            Some(deadline) if deadline <= now => {
+                let commit = group.commit_to_pending_proposals(provider, signer)?;
+                Ok(Some(commit))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Merges a [`StagedCommit`] that was received from another member.
+    ///
+    /// If this client has a pending commit for the same epoch, it lost the
+    /// race. The pending commit is cleared before the received commit is
+    /// merged and the returned [`SupersededCommit`] reports which of the own
+    /// proposals were dropped. These can be re-proposed with
+    /// [`MlsGroup::repropose_dropped_proposals()`].
+    pub fn handle_staged_commit<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        group: &mut MlsGroup,
+        staged_commit: StagedCommit,
+    ) -> Result<Option<SupersededCommit>, CommitSchedulerError<Provider::StorageError>> {
+        let superseded_commit = staged_commit.superseded_commit().cloned();
+        group
+            .clear_pending_commit(provider.storage())
+            .map_err(CommitSchedulerError::StorageError)?;
+        group.merge_staged_commit(provider, staged_commit)?;
+        self.reset();
+
+        Ok(superseded_commit)
+    }
+
+    /// Clears the pending commit of the `group` after the delivery service
+    /// rejected it, e.g. because the commit of another member was accepted
+    /// first.
+    ///
+    /// The pending proposals are rescheduled on the next call to
+    /// [`CommitScheduler::poll()`]. Usually the winning commit is processed
+    /// before and covers them.
+    pub fn commit_rejected<Storage: StorageProvider>(
+        &mut self,
+        storage: &Storage,
+        group: &mut MlsGroup,
+    ) -> Result<(), Storage::Error> {
+        group.clear_pending_commit(storage)?;
+        self.reset();
+        Ok(())
+    }
+
+    /// Resets the scheduling state of this scheduler.
+    pub fn reset(&mut self) {
+        self.pending_since = None;
+        self.backoff = Duration::ZERO;
+    }
+
+    /// Returns the leaf indices of all members that are not removed by one of
+    /// the pending proposals of the `group`.
+    fn eligible_members(group: &MlsGroup) -> HashSet<LeafNodeIndex> {
+        let removed: HashSet<LeafNodeIndex> = group
+            .pending_proposals()
+            .filter_map(|queued_proposal| match queued_proposal.proposal() {
+                Proposal::Remove(remove_proposal) => Some(remove_proposal.removed()),
+                _ => None,
+            })
+            .collect();
+
+        group
+            .members()
+            .map(|member| member.index)
+            .filter(|index| !removed.contains(index))
+            .collect()
+    }
+}
+
+/// Draws a random delay between zero and `max_delay`.
+fn random_delay(rand: &impl OpenMlsRand, max_delay: Duration) -> Result<Duration, LibraryError> {
+    let max_millis = max_delay.as_millis() as u64;
+    if max_millis == 0 {
+        return Ok(Duration::ZERO);
+    }
+    let bytes: [u8; 8] = rand
+        .random_array()
+        .map_err(|_| LibraryError::custom("Not enough randomness."))?;
+
+    Ok(Duration::from_millis(
+        u64::from_be_bytes(bytes) % (max_millis + 1),
+    ))
+}
@@ -243,6 +243,7 @@ impl MlsGroupCreateConfig {
 #[derive(Default, Debug)]
 pub struct MlsGroupCreateConfigBuilder {
     config: MlsGroupCreateConfig,
+    capabilities: Option<Capabilities>,
 }
 
 impl MlsGroupCreateConfigBuilder {
@@ -250,6 +251,7 @@ impl MlsGroupCreateConfigBuilder {
     fn new() -> Self {
         MlsGroupCreateConfigBuilder {
             config: MlsGroupCreateConfig::default(),
+            capabilities: None,
         }
     }
 
@@ -293,8 +295,13 @@ impl MlsGroupCreateConfigBuilder {
     }
 
     /// Sets the `capabilities` of the group creator's leaf node.
+    ///
+    /// If no capabilities are set, the default capabilities are extended with
+    /// everything the group context extensions and the leaf node extensions
+    /// require. See
+    /// [`CapabilitiesBuilder::for_group_context_extensions()`](crate::prelude::CapabilitiesBuilder::for_group_context_extensions).
     pub fn capabilities(mut self, capabilities: Capabilities) -> Self {
-        self.config.capabilities = capabilities;
+        self.capabilities = Some(capabilities);
         self
     }
 
@@ -340,11 +347,15 @@ impl MlsGroupCreateConfigBuilder {
         mut self,
         extensions: Extensions,
     ) -> Result<Self, InvalidExtensionError> {
-        // None of the default extensions are leaf node extensions, so only
-        // unknown extensions can be leaf node extensions.
-        let is_valid_in_leaf_node = extensions
-            .iter()
-            .all(|e| matches!(e.extension_type(), ExtensionType::Unknown(_)));
+        // The supported media types extension is the only known leaf node
+        // extension. Apart from that, only unknown extensions can be leaf node
+        // extensions.
+        let is_valid_in_leaf_node = extensions.iter().all(|e| {
+            matches!(
+                e.extension_type(),
+                ExtensionType::SupportedMediaTypes | ExtensionType::Unknown(_)
+            )
+        });
         if !is_valid_in_leaf_node {
             return Err(InvalidExtensionError::IllegalInLeafNodes);
         }
@@ -353,7 +364,13 @@ impl MlsGroupCreateConfigBuilder {
     }
 
     /// Finalizes the builder and retursn an `[MlsGroupCreateConfig`].
-    pub fn build(self) -> MlsGroupCreateConfig {
+    pub fn build(mut self) -> MlsGroupCreateConfig {
+        self.config.capabilities = self.capabilities.unwrap_or_else(|| {
+            Capabilities::builder()
+                .for_group_context_extensions(&self.config.group_context_extensions)
+                .for_leaf_node_extensions(&self.config.leaf_node_extensions)
+                .build()
+        });
         self.config
     }
 }
@@ -113,7 +113,8 @@ impl MlsGroup {
         .map_err(|e| match e {
             ProposalQueueError::LibraryError(e) => e.into(),
             ProposalQueueError::ProposalNotFound => CreateCommitError::MissingProposal,
-            ProposalQueueError::UpdateFromExternalSender => {
+            ProposalQueueError::UpdateFromExternalSender
+            | ProposalQueueError::SelfRemoveFromExternalSender => {
                 CreateCommitError::WrongProposalSenderType
             }
         })?;
@@ -148,6 +149,10 @@ impl MlsGroup {
         // ValSem108
         self.public_group
             .validate_remove_proposals(&proposal_queue)?;
+        self.public_group
+            .validate_admin_proposals(&proposal_queue)?;
+        self.public_group
+            .validate_media_types(&proposal_queue, &sender, None)?;
         self.public_group
             .validate_pre_shared_key_proposals(&proposal_queue)?;
         // Validate update proposals for member commits
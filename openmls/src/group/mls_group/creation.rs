@@ -1,5 +1,8 @@
 use errors::NewGroupError;
-use openmls_traits::{signatures::Signer, storage::StorageProvider as StorageProviderTrait};
+use openmls_traits::{
+    key_handles::KeyHandleProvider, signatures::Signer,
+    storage::StorageProvider as StorageProviderTrait,
+};
 
 use super::{builder::MlsGroupBuilder, *};
 use crate::{
@@ -197,6 +200,7 @@ impl MlsGroup {
             mls_group_config: mls_group_config.clone(),
             own_leaf_nodes: vec![],
             aad: vec![],
+            components: BTreeSet::new(),
             group_state: MlsGroupState::Operational,
             public_group,
             group_epoch_secrets,
@@ -249,6 +253,33 @@ impl ProcessedWelcome {
         provider: &Provider,
         mls_group_config: &MlsGroupJoinConfig,
         welcome: Welcome,
+    ) -> Result<Self, WelcomeError<Provider::StorageError>> {
+        Self::new(provider, None, mls_group_config, welcome)
+    }
+
+    /// Creates a new processed [`Welcome`] message for a key package that was
+    /// built with
+    /// [`KeyPackageBuilder::build_with_key_handles()`](crate::key_packages::KeyPackageBuilder::build_with_key_handles).
+    ///
+    /// The group secrets are decrypted by the `key_handles` provider, which
+    /// holds the private init key. Unless the key package is a last resort
+    /// key package, the init key is deleted from the provider afterwards.
+    ///
+    /// [`Welcome`]: crate::messages::Welcome
+    pub fn new_from_welcome_with_key_handles<Provider: OpenMlsProvider>(
+        provider: &Provider,
+        key_handles: &impl KeyHandleProvider,
+        mls_group_config: &MlsGroupJoinConfig,
+        welcome: Welcome,
+    ) -> Result<Self, WelcomeError<Provider::StorageError>> {
+        Self::new(provider, Some(key_handles), mls_group_config, welcome)
+    }
+
+    fn new<Provider: OpenMlsProvider>(
+        provider: &Provider,
+        key_handles: Option<&dyn KeyHandleProvider>,
+        mls_group_config: &MlsGroupJoinConfig,
+        welcome: Welcome,
     ) -> Result<Self, WelcomeError<Provider::StorageError>> {
         let (resumption_psk_store, key_package_bundle) =
             keys_for_welcome(mls_group_config, &welcome, provider)?;
@@ -266,13 +297,31 @@ impl ProcessedWelcome {
             log::debug!("new_from_welcome {:?}", e);
             return Err(e);
         }
-        let group_secrets = GroupSecrets::try_from_ciphertext(
-            key_package_bundle.init_private_key(),
-            egs.encrypted_group_secrets(),
-            welcome.encrypted_group_info(),
-            ciphersuite,
-            provider.crypto(),
-        )?;
+        let group_secrets = match (key_package_bundle.init_key_handle(), key_handles) {
+            (None, _) => GroupSecrets::try_from_ciphertext(
+                key_package_bundle.init_private_key(),
+                egs.encrypted_group_secrets(),
+                welcome.encrypted_group_info(),
+                ciphersuite,
+                provider.crypto(),
+            )?,
+            (Some(handle), Some(key_handles)) => {
+                let group_secrets = GroupSecrets::try_from_ciphertext_with_handle(
+                    key_handles,
+                    handle,
+                    egs.encrypted_group_secrets(),
+                    welcome.encrypted_group_info(),
+                    ciphersuite,
+                )?;
+                if !key_package_bundle.key_package().last_resort() {
+                    key_handles
+                        .delete_key(handle)
+                        .map_err(WelcomeError::KeyHandleError)?;
+                }
+                group_secrets
+            }
+            (Some(_), None) => return Err(WelcomeError::MissingKeyHandleProvider),
+        };
         let psk_secret = {
             let psks = load_psks(
                 provider.storage(),
@@ -499,6 +548,28 @@ impl StagedWelcome {
         processed_welcome.into_staged_welcome(provider, ratchet_tree)
     }
 
+    /// Creates a new staged welcome from a [`Welcome`] message for a key
+    /// package whose init key is held by the `key_handles` provider. See
+    /// [`ProcessedWelcome::new_from_welcome_with_key_handles()`].
+    ///
+    /// [`Welcome`]: crate::messages::Welcome
+    pub fn new_from_welcome_with_key_handles<Provider: OpenMlsProvider>(
+        provider: &Provider,
+        key_handles: &impl KeyHandleProvider,
+        mls_group_config: &MlsGroupJoinConfig,
+        welcome: Welcome,
+        ratchet_tree: Option<RatchetTreeIn>,
+    ) -> Result<Self, WelcomeError<Provider::StorageError>> {
+        let processed_welcome = ProcessedWelcome::new_from_welcome_with_key_handles(
+            provider,
+            key_handles,
+            mls_group_config,
+            welcome,
+        )?;
+
+        processed_welcome.into_staged_welcome(provider, ratchet_tree)
+    }
+
     /// Returns the [`LeafNodeIndex`] of the group member that authored the [`Welcome`] message.
     ///
     /// [`Welcome`]: crate::messages::Welcome
@@ -537,6 +608,7 @@ impl StagedWelcome {
             mls_group_config: self.mls_group_config,
             own_leaf_nodes: vec![],
             aad: vec![],
+            components: BTreeSet::new(),
             group_state: MlsGroupState::Operational,
             public_group: self.public_group,
             group_epoch_secrets: self.group_epoch_secrets,
@@ -10,6 +10,7 @@ use thiserror::Error;
 use crate::{
     error::LibraryError,
     extensions::errors::InvalidExtensionError,
+    framing::errors::{SafeAadError, TargetedMessageError},
     group::{
         errors::{
             CreateAddProposalError, CreateCommitError, MergeCommitError, StageCommitError,
@@ -42,6 +43,13 @@ pub enum NewGroupError<StorageError> {
     /// Unsupported extension type in required capabilities.
     #[error("Unsupported extension type in required capabilities.")]
     UnsupportedExtensionType,
+    /// Unsupported credential type in required capabilities.
+    #[error("Unsupported credential type in required capabilities.")]
+    UnsupportedCredentialType,
+    /// The group creator's leaf node does not support all required media
+    /// types.
+    #[error("The group creator's leaf node does not support all required media types.")]
+    UnsupportedMediaTypes,
     /// Invalid extensions set in configuration
     #[error("Invalid extensions set in configuration")]
     InvalidExtensions(#[from] InvalidExtensionError),
@@ -116,6 +124,12 @@ pub enum ProcessMessageError {
     /// The proposal is invalid for the Sender of type [External](crate::prelude::Sender::External)
     #[error("The proposal is invalid for the Sender of type External")]
     UnsupportedProposalType,
+    /// The proposal was denied by the group's proposal policy.
+    #[error("The proposal was denied by the group's proposal policy.")]
+    ProposalDenied,
+    /// See [`TargetedMessageError`] for more details.
+    #[error(transparent)]
+    InvalidTargetedMessage(#[from] TargetedMessageError),
 }
 
 /// Create message error
@@ -129,6 +143,20 @@ pub enum CreateMessageError {
     GroupStateError(#[from] MlsGroupStateError),
 }
 
+/// Create targeted message error
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum CreateTargetedMessageError {
+    /// See [`LibraryError`] for more details.
+    #[error(transparent)]
+    LibraryError(#[from] LibraryError),
+    /// See [`MlsGroupStateError`] for more details.
+    #[error(transparent)]
+    GroupStateError(#[from] MlsGroupStateError),
+    /// The recipient is not a member of the group.
+    #[error("The recipient is not a member of the group.")]
+    UnknownRecipient,
+}
+
 /// Add members error
 #[derive(Error, Debug, PartialEq, Clone)]
 pub enum AddMembersError<StorageError> {
@@ -221,6 +249,9 @@ pub enum LeaveGroupError<StorageError> {
     /// An error ocurred while writing to storage
     #[error("An error ocurred while writing to storage")]
     StorageError(StorageError),
+    /// Not all members of the group support SelfRemove proposals.
+    #[error("Not all members of the group support SelfRemove proposals.")]
+    SelfRemoveNotSupported,
 }
 
 /// Self update error
@@ -278,6 +309,23 @@ pub enum CommitToPendingProposalsError<StorageError> {
     StorageError(StorageError),
 }
 
+/// Commit scheduler error
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum CommitSchedulerError<StorageError> {
+    /// See [`LibraryError`] for more details.
+    #[error(transparent)]
+    LibraryError(#[from] LibraryError),
+    /// See [`CommitToPendingProposalsError`] for more details.
+    #[error(transparent)]
+    CommitToPendingProposalsError(#[from] CommitToPendingProposalsError<StorageError>),
+    /// See [`MergeCommitError`] for more details.
+    #[error(transparent)]
+    MergeCommitError(#[from] MergeCommitError<StorageError>),
+    /// Error accessing the storage.
+    #[error("Error accessing the storage.")]
+    StorageError(StorageError),
+}
+
 /// Errors that can happen when exporting a group info object.
 #[derive(Error, Debug, PartialEq, Clone)]
 pub enum ExportGroupInfoError {
@@ -303,6 +351,34 @@ pub enum ExportSecretError {
     GroupStateError(#[from] MlsGroupStateError),
 }
 
+/// Application component error
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum ComponentError {
+    /// The component is already registered.
+    #[error("The component is already registered.")]
+    AlreadyRegistered,
+    /// The component is not registered.
+    #[error("The component is not registered.")]
+    UnregisteredComponent,
+    /// See [`SafeAadError`] for more details.
+    #[error(transparent)]
+    SafeAad(#[from] SafeAadError),
+}
+
+/// Safe export secret error
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum SafeExportSecretError {
+    /// See [`LibraryError`] for more details.
+    #[error(transparent)]
+    LibraryError(#[from] LibraryError),
+    /// The component is not registered.
+    #[error("The component is not registered.")]
+    UnregisteredComponent,
+    /// See [`MlsGroupStateError`] for more details.
+    #[error(transparent)]
+    GroupStateError(#[from] MlsGroupStateError),
+}
+
 /// Propose PSK error
 #[derive(Error, Debug, PartialEq, Clone)]
 pub enum ProposePskError {
@@ -349,6 +425,40 @@ pub enum ProposalError<StorageError> {
     StorageError(StorageError),
 }
 
+/// Admin proposal error
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum AdminProposalError<StorageError> {
+    /// See [`LibraryError`] for more details.
+    #[error(transparent)]
+    LibraryError(#[from] LibraryError),
+    /// The member could not be found in the group.
+    #[error("The member could not be found in the group.")]
+    UnknownMember,
+    /// The member is already an admin.
+    #[error("The member is already an admin.")]
+    AlreadyAdmin,
+    /// The member is not an admin.
+    #[error("The member is not an admin.")]
+    NotAnAdmin,
+    /// See [`ProposalError`] for more details.
+    #[error(transparent)]
+    ProposalError(#[from] ProposalError<StorageError>),
+}
+
+/// Re-propose error
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum ReproposeError<StorageError> {
+    /// See [`LibraryError`] for more details.
+    #[error(transparent)]
+    LibraryError(#[from] LibraryError),
+    /// The commit that superseded the pending commit has not been merged yet.
+    #[error("The commit that superseded the pending commit has not been merged yet.")]
+    SupersedingCommitNotMerged,
+    /// See [`ProposalError`] for more details.
+    #[error(transparent)]
+    ProposalError(#[from] ProposalError<StorageError>),
+}
+
 /// Remove proposal error
 #[derive(Error, Debug, PartialEq, Clone)]
 pub enum RemoveProposalError<StorageError> {
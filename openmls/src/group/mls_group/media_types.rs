@@ -0,0 +1,52 @@
+//! MLS group media types
+//!
+//! This module contains convenience functions to query the media types the
+//! members of a group support, as advertised in their
+//! [`SupportedMediaTypesExtension`](crate::extensions::SupportedMediaTypesExtension).
+
+use super::*;
+use crate::extensions::{MediaType, RequiredMediaTypesExtension};
+
+impl MlsGroup {
+    /// Returns the [`RequiredMediaTypesExtension`] of the group, if there is
+    /// one.
+    pub fn required_media_types(&self) -> Option<&RequiredMediaTypesExtension> {
+        self.public_group
+            .group_context()
+            .extensions()
+            .required_media_types()
+    }
+
+    /// Returns `true` if the member at the given leaf index supports the
+    /// `media_type`.
+    pub fn member_supports_media_type(
+        &self,
+        member: LeafNodeIndex,
+        media_type: &MediaType,
+    ) -> bool {
+        self.public_group.leaf(member).is_some_and(|leaf| {
+            leaf.extensions()
+                .supported_media_types()
+                .is_some_and(|supported| supported.supports(media_type))
+        })
+    }
+
+    /// Returns `true` if all members of the group support the `media_type`,
+    /// i.e. application messages of this media type can be rendered by
+    /// everyone.
+    pub fn all_members_support_media_type(&self, media_type: &MediaType) -> bool {
+        self.public_group
+            .members()
+            .all(|member| self.member_supports_media_type(member.index, media_type))
+    }
+
+    /// Returns the leaf indices of the members that don't support the
+    /// `media_type`.
+    pub fn members_without_media_type(&self, media_type: &MediaType) -> Vec<LeafNodeIndex> {
+        self.public_group
+            .members()
+            .map(|member| member.index)
+            .filter(|index| !self.member_supports_media_type(*index, media_type))
+            .collect()
+    }
+}
@@ -262,6 +262,58 @@ impl MlsGroup {
         Ok(self.content_to_mls_message(remove_proposal, provider)?)
     }
 
+    /// Leave the group using a SelfRemove proposal.
+    ///
+    /// Creates a SelfRemove Proposal that needs to be covered by a Commit from
+    /// a different member. Unlike the Remove Proposal created by
+    /// [`Self::leave_group()`], a SelfRemove Proposal can only ever remove its
+    /// sender, so a delivery service can tell a voluntary leave from a
+    /// removal and commit it on the member's behalf (see
+    /// [`PublicGroup::pending_self_removes()`]). This requires handshake
+    /// messages to be sent as [`PublicMessage`](crate::framing::PublicMessage)s.
+    /// The SelfRemove Proposal is returned as a [`MlsMessageOut`].
+    ///
+    /// Returns an error if there is a pending commit or if not all members of
+    /// the group support SelfRemove proposals.
+    pub fn leave_group_via_self_remove<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+    ) -> Result<MlsMessageOut, LeaveGroupError<Provider::StorageError>> {
+        self.is_operational()?;
+
+        if !self
+            .public_group()
+            .members_support_proposal_type(ProposalType::SelfRemove)
+        {
+            return Err(LeaveGroupError::SelfRemoveNotSupported);
+        }
+
+        let self_remove_proposal =
+            self.create_self_remove_proposal(self.framing_parameters(), signer)?;
+
+        let ciphersuite = self.ciphersuite();
+        let queued_self_remove_proposal = QueuedProposal::from_authenticated_content_by_ref(
+            ciphersuite,
+            provider.crypto(),
+            self_remove_proposal.clone(),
+        )?;
+
+        provider
+            .storage()
+            .queue_proposal(
+                self.group_id(),
+                &queued_self_remove_proposal.proposal_reference(),
+                &queued_self_remove_proposal,
+            )
+            .map_err(LeaveGroupError::StorageError)?;
+
+        self.proposal_store_mut().add(queued_self_remove_proposal);
+
+        self.reset_aad();
+        Ok(self.content_to_mls_message(self_remove_proposal, provider)?)
+    }
+
     /// Returns a list of [`Member`]s in the group.
     pub fn members(&self) -> impl Iterator<Item = Member> + '_ {
         self.public_group().members()
@@ -3,6 +3,8 @@
 //! This module contains [`MlsGroup`] and its submodules.
 //!
 
+use std::collections::BTreeSet;
+
 use create_commit::{CommitType, CreateCommitParams};
 use past_secrets::MessageSecretsStore;
 use proposal_store::ProposalQueue;
@@ -24,10 +26,11 @@ use crate::{
     error::LibraryError,
     framing::{mls_auth_content::AuthenticatedContent, *},
     group::{
-        CreateCommitError, CreateGroupContextExtProposalError, Extension, ExtensionType,
-        Extensions, ExternalPubExtension, GroupContext, GroupEpoch, GroupId, MlsGroupJoinConfig,
-        MlsGroupStateError, OutgoingWireFormatPolicy, ProposalQueueError, PublicGroup,
-        RatchetTreeExtension, RequiredCapabilitiesExtension, StagedCommit,
+        CreateCommitError, CreateGroupContextExtProposalError, Extension, ExtensionRegistry,
+        ExtensionType, Extensions, ExternalPubExtension, GroupContext, GroupEpoch, GroupId,
+        MlsGroupJoinConfig, MlsGroupStateError, OutgoingWireFormatPolicy, ProposalPolicy,
+        ProposalQueueError, PublicGroup, RatchetTreeExtension, RequiredCapabilitiesExtension,
+        StagedCommit,
     },
     key_packages::KeyPackageBundle,
     messages::{
@@ -50,15 +53,21 @@ use crate::{
 use openmls_traits::{signatures::Signer, storage::StorageProvider as _, types::Ciphersuite};
 
 // Private
+mod admins;
 mod application;
 mod builder;
 mod creation;
 mod exporting;
+mod media_types;
+mod safe_application;
+mod targeted_message;
 mod updates;
 
 use config::*;
 
 // Crate
+pub(crate) mod commit_conflict;
+pub(crate) mod commit_scheduler;
 pub(crate) mod config;
 pub(crate) mod create_commit;
 pub(crate) mod errors;
@@ -249,6 +258,10 @@ pub struct MlsGroup {
     // is ephemeral and will be reset by every API call that successfully
     // returns an [`MlsMessageOut`].
     aad: Vec<u8>,
+    // Application components that are registered for safe exporters and the
+    // safe AAD. This is not persisted and has to be registered again after
+    // the group was loaded from storage.
+    components: BTreeSet<ComponentId>,
     // A variable that indicates the state of the group. See [`MlsGroupState`]
     // for more information.
     group_state: MlsGroupState,
@@ -272,6 +285,36 @@ impl MlsGroup {
         storage.write_mls_join_config(self.group_id(), mls_group_config)
     }
 
+    /// Registers the [`ProposalPolicy`] that authorizes incoming proposals,
+    /// whether they are sent standalone or committed by reference or inline.
+    /// This replaces any previously registered policy.
+    ///
+    /// The policy is not persisted and has to be registered again after the
+    /// group was loaded from storage.
+    pub fn set_proposal_policy(&mut self, policy: impl ProposalPolicy + 'static) {
+        self.public_group.set_proposal_policy(policy);
+    }
+
+    /// Removes the registered [`ProposalPolicy`], if any.
+    pub fn remove_proposal_policy(&mut self) {
+        self.public_group.remove_proposal_policy();
+    }
+
+    /// Sets the [`ExtensionRegistry`] that is used to validate the key
+    /// packages, leaf nodes and group context extensions of incoming commits.
+    /// This replaces any previously set registry.
+    ///
+    /// The registry is not persisted and has to be set again after the group
+    /// was loaded from storage.
+    pub fn set_extension_registry(&mut self, extension_registry: ExtensionRegistry) {
+        self.public_group.set_extension_registry(extension_registry);
+    }
+
+    /// Returns the [`ExtensionRegistry`] of the group.
+    pub fn extension_registry(&self) -> &ExtensionRegistry {
+        self.public_group.extension_registry()
+    }
+
     /// Sets the additional authenticated data (AAD) for the next outgoing
     /// message. This is ephemeral and will be reset by every API call that
     /// successfully returns an [`MlsMessageOut`].
@@ -437,6 +480,7 @@ impl MlsGroup {
                 mls_group_config: mls_group_config?,
                 own_leaf_nodes,
                 aad: vec![],
+                components: BTreeSet::new(),
                 group_state: group_state?,
             })
         };
@@ -22,6 +22,12 @@ impl MlsGroup {
     /// and semantic validation of the message. It returns a [ProcessedMessage]
     /// enum.
     ///
+    /// If the message is a commit of another member and this client has a
+    /// pending commit for the same epoch, the pending commit is superseded.
+    /// The returned [`StagedCommit`] then reports which of the own proposals
+    /// were dropped (see [`StagedCommit::superseded_commit()`]). The pending
+    /// commit is cleared when the staged commit is merged.
+    ///
     /// # Errors:
     /// Returns an [`ProcessMessageError`] when the validation checks fail
     /// with the exact reason of the failure.
@@ -38,6 +44,11 @@ impl MlsGroup {
         }
         let message = message.into();
 
+        // Targeted messages are not framed like other application messages.
+        if let ProtocolMessage::TargetedMessage(targeted_message) = message {
+            return self.process_targeted_message(provider, targeted_message);
+        }
+
         // Check that handshake messages are compatible with the incoming wire format policy
         if !message.is_external()
             && message.is_handshake_message()
@@ -284,7 +295,13 @@ impl MlsGroup {
                             application_message.as_slice().to_owned(),
                         ))
                     }
-                    FramedContentBody::Proposal(_) => {
+                    FramedContentBody::Proposal(proposal) => {
+                        if !self
+                            .public_group
+                            .proposal_allowed(&sender, &credential, proposal)
+                        {
+                            return Err(ProcessMessageError::ProposalDenied);
+                        }
                         let proposal = Box::new(QueuedProposal::from_authenticated_content_by_ref(
                             self.ciphersuite(),
                             provider.crypto(),
@@ -298,12 +315,15 @@ impl MlsGroup {
                         }
                     }
                     FramedContentBody::Commit(_) => {
-                        let staged_commit = self.stage_commit(
+                        let mut staged_commit = self.stage_commit(
                             &content,
                             old_epoch_keypairs,
                             leaf_node_keypairs,
                             provider,
                         )?;
+                        // If we have a pending commit, it is superseded by
+                        // this one.
+                        staged_commit.set_superseded_commit(self.superseded_commit(&staged_commit));
                         ProcessedMessageContent::StagedCommitMessage(Box::new(staged_commit))
                     }
                 };
@@ -324,7 +344,15 @@ impl MlsGroup {
                     FramedContentBody::Application(_) => {
                         Err(ProcessMessageError::UnauthorizedExternalApplicationMessage)
                     }
-                    FramedContentBody::Proposal(Proposal::Remove(_)) => {
+                    FramedContentBody::Proposal(proposal)
+                        if proposal.is_valid_for_external_sender() =>
+                    {
+                        if !self
+                            .public_group
+                            .proposal_allowed(&sender, &credential, proposal)
+                        {
+                            return Err(ProcessMessageError::ProposalDenied);
+                        }
                         let content = ProcessedMessageContent::ProposalMessage(Box::new(
                             QueuedProposal::from_authenticated_content_by_ref(
                                 self.ciphersuite(),
@@ -341,7 +369,8 @@ impl MlsGroup {
                             credential,
                         ))
                     }
-                    // TODO #151/#106
+                    // Update and ExternalInit proposals can't be sent by external
+                    // senders.
                     FramedContentBody::Proposal(_) => {
                         Err(ProcessMessageError::UnsupportedProposalType)
                     }
@@ -405,6 +434,8 @@ impl MlsGroup {
                     sender_ratchet_configuration,
                 )
             }
+            // Targeted messages are processed separately.
+            ProtocolMessage::TargetedMessage(_) => Err(ValidationError::WrongWireFormat),
         }
     }
 }
@@ -299,7 +299,8 @@ impl MlsGroup {
     }
 
     /// Creates proposals to remove members from the group.
-    /// The `member` has to be the member's credential.
+    /// The `member` has to be the member's credential. Credentials are matched
+    /// by their [`Credential::identity()`] if it is known.
     ///
     /// Returns an error if there is a pending commit.
     pub fn propose_remove_member_by_credential<Provider: OpenMlsProvider>(
@@ -313,7 +314,7 @@ impl MlsGroup {
         let member_index = self
             .public_group()
             .members()
-            .find(|m| &m.credential == member)
+            .find(|m| m.credential.same_identity(member))
             .map(|m| m.index);
 
         if let Some(member_index) = member_index {
@@ -324,7 +325,8 @@ impl MlsGroup {
     }
 
     /// Creates proposals to remove members from the group.
-    /// The `member` has to be the member's credential.
+    /// The `member` has to be the member's credential. Credentials are matched
+    /// by their [`Credential::identity()`] if it is known.
     ///
     /// Returns an error if there is a pending commit.
     pub fn propose_remove_member_by_credential_by_value<Provider: OpenMlsProvider>(
@@ -337,7 +339,7 @@ impl MlsGroup {
         let member_index = self
             .public_group()
             .members()
-            .find(|m| &m.credential == member)
+            .find(|m| m.credential.same_identity(member))
             .map(|m| m.index);
 
         if let Some(member_index) = member_index {
@@ -534,6 +536,22 @@ impl MlsGroup {
         .map_err(ValidationError::LibraryError)
     }
 
+    // draft-ietf-mls-extensions: SelfRemove
+    // struct {} SelfRemove;
+    pub(crate) fn create_self_remove_proposal(
+        &self,
+        framing_parameters: FramingParameters,
+        signer: &impl Signer,
+    ) -> Result<AuthenticatedContent, LibraryError> {
+        AuthenticatedContent::member_proposal(
+            framing_parameters,
+            self.own_leaf_index(),
+            Proposal::SelfRemove,
+            self.context(),
+            signer,
+        )
+    }
+
     // 12.1.4. PreSharedKey
     // struct {
     //     PreSharedKeyID psk;
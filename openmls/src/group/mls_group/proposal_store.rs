@@ -246,6 +246,11 @@ impl ProposalQueue {
                             }
                         }
                     }
+                    // A SelfRemove proposal removes its sender and can thus
+                    // only be committed by reference.
+                    if let Proposal::SelfRemove = proposal {
+                        return Err(FromCommittedProposalsError::SelfRemoval);
+                    }
 
                     QueuedProposal::from_proposal_and_sender(ciphersuite, crypto, proposal, sender)?
                 }
@@ -261,6 +266,11 @@ impl ProposalQueue {
                                     }
                                 }
                             }
+                            if let Proposal::SelfRemove = queued_proposal.proposal {
+                                if queued_proposal.sender() == sender {
+                                    return Err(FromCommittedProposalsError::SelfRemoval);
+                                }
+                            }
 
                             queued_proposal.clone()
                         }
@@ -348,6 +358,20 @@ impl ProposalQueue {
         })
     }
 
+    /// Returns an iterator over the leaf indices of the senders of all
+    /// SelfRemove proposals in the queue in the order of the the Commit
+    /// message
+    pub(crate) fn self_remove_proposals(&self) -> impl Iterator<Item = LeafNodeIndex> + '_ {
+        self.queued_proposals()
+            .filter_map(|queued_proposal| match queued_proposal.proposal() {
+                Proposal::SelfRemove => match queued_proposal.sender() {
+                    Sender::Member(leaf_index) => Some(*leaf_index),
+                    _ => None,
+                },
+                _ => None,
+            })
+    }
+
     /// Returns an iterator over all Update in the queue
     /// in the order of the the Commit message
     pub(crate) fn update_proposals(&self) -> impl Iterator<Item = QueuedUpdateProposal> {
@@ -398,6 +422,7 @@ impl ProposalQueue {
     /// - Check for invalid indexes and drop proposal
     /// - Check for presence of Removes and delete Updates
     /// - Only keep the last Update
+    /// - Drop SelfRemove proposals of the committer
     ///
     /// Return a [`ProposalQueue`] and a bool that indicates whether Updates for
     /// the own node were included
@@ -498,6 +523,23 @@ impl ProposalQueue {
                     valid_proposals.add(queued_proposal.proposal_reference());
                     proposal_pool.insert(queued_proposal.proposal_reference(), queued_proposal);
                 }
+                Proposal::SelfRemove => {
+                    // Only members can send SelfRemove proposals
+                    let leaf_index = match queued_proposal.sender {
+                        Sender::Member(leaf_index) => leaf_index,
+                        _ => return Err(ProposalQueueError::SelfRemoveFromExternalSender),
+                    };
+                    // The committer can't remove itself
+                    if leaf_index != own_index {
+                        members
+                            .entry(leaf_index)
+                            .or_default()
+                            .removes
+                            .push(queued_proposal.clone());
+                        let proposal_reference = queued_proposal.proposal_reference();
+                        proposal_pool.insert(proposal_reference, queued_proposal);
+                    }
+                }
                 Proposal::AppAck(_) => unimplemented!("See #291"),
                 Proposal::Custom(_) => {
                     // Other/unknown proposals are always considered valid and
@@ -0,0 +1,103 @@
+//! MLS group safe application API
+//!
+//! This module contains the safe exporters and the safe AAD from
+//! draft-ietf-mls-extensions. Application components register a
+//! [`ComponentId`] with the group. Each component derives its own exported
+//! secret and sets its own item in the AAD of outgoing messages, so
+//! components can't collide on exporter labels or on the AAD.
+
+use errors::{ComponentError, SafeExportSecretError};
+
+use super::*;
+
+impl MlsGroup {
+    /// Registers the application component with the given ID.
+    ///
+    /// The registered components are not persisted and have to be registered
+    /// again after the group was loaded from storage.
+    pub fn register_component(&mut self, component_id: ComponentId) -> Result<(), ComponentError> {
+        if !self.components.insert(component_id) {
+            return Err(ComponentError::AlreadyRegistered);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the application component with the given ID is
+    /// registered.
+    pub fn is_component_registered(&self, component_id: ComponentId) -> bool {
+        self.components.contains(&component_id)
+    }
+
+    /// Exports the secret of the registered application component with the
+    /// given ID from the current epoch. The secret has the length of the
+    /// ciphersuite's hash output.
+    ///
+    /// Returns [`SafeExportSecretError::UnregisteredComponent`] if the
+    /// component is not registered and
+    /// [`SafeExportSecretError::GroupStateError(MlsGroupStateError::UseAfterEviction)`](MlsGroupStateError::UseAfterEviction)
+    /// if the group is not active.
+    pub fn safe_export_secret<Provider: OpenMlsProvider>(
+        &self,
+        provider: &Provider,
+        component_id: ComponentId,
+    ) -> Result<Vec<u8>, SafeExportSecretError> {
+        if !self.is_component_registered(component_id) {
+            return Err(SafeExportSecretError::UnregisteredComponent);
+        }
+        if !self.is_active() {
+            return Err(SafeExportSecretError::GroupStateError(
+                MlsGroupStateError::UseAfterEviction,
+            ));
+        }
+
+        Ok(self
+            .group_epoch_secrets
+            .exporter_secret()
+            .derive_safe_exported_secret(self.ciphersuite(), provider.crypto(), component_id)
+            .map_err(LibraryError::unexpected_crypto_error)?)
+    }
+
+    /// Sets the `data` of the registered application component with the given
+    /// ID in the additional authenticated data (AAD) of the next outgoing
+    /// message. The AAD is encoded as [`SafeAad`] and, like the AAD set with
+    /// [`MlsGroup::set_aad()`], reset by every API call that successfully
+    /// returns an [`MlsMessageOut`].
+    ///
+    /// Returns an error if the component is not registered or if the AAD was
+    /// set to something other than a [`SafeAad`] before.
+    pub fn set_safe_aad(
+        &mut self,
+        component_id: ComponentId,
+        data: Vec<u8>,
+    ) -> Result<(), ComponentError> {
+        if !self.is_component_registered(component_id) {
+            return Err(ComponentError::UnregisteredComponent);
+        }
+
+        let mut safe_aad = self.safe_aad()?;
+        safe_aad.insert(component_id, data);
+        self.aad = safe_aad.to_aad()?;
+
+        Ok(())
+    }
+
+    /// Returns the additional authenticated data (AAD) for the next outgoing
+    /// message as [`SafeAad`].
+    pub fn safe_aad(&self) -> Result<SafeAad, SafeAadError> {
+        SafeAad::from_aad(&self.aad)
+    }
+
+    /// Returns the data of the registered application component with the
+    /// given ID in the AAD of the processed `message`, if there is any.
+    pub fn component_aad(
+        &self,
+        message: &ProcessedMessage,
+        component_id: ComponentId,
+    ) -> Result<Option<Vec<u8>>, ComponentError> {
+        if !self.is_component_registered(component_id) {
+            return Err(ComponentError::UnregisteredComponent);
+        }
+
+        Ok(message.safe_aad()?.get(component_id).map(<[u8]>::to_vec))
+    }
+}
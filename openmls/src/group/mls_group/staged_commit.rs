@@ -21,6 +21,7 @@ use crate::{
         diff::{apply_proposals::ApplyProposalsValues, StagedPublicGroupDiff},
         staged_commit::PublicStagedCommitState,
     },
+    group::SupersededCommit,
     schedule::{CommitSecret, EpochAuthenticator, EpochSecrets, InitSecret, PreSharedKeyId},
     treesync::node::encryption_keys::EncryptionKeyPair,
 };
@@ -445,6 +446,9 @@ pub(crate) enum StagedCommitState {
 pub struct StagedCommit {
     staged_proposal_queue: ProposalQueue,
     state: StagedCommitState,
+    // Only set on incoming commits that superseded an own pending commit.
+    #[serde(skip)]
+    superseded_commit: Option<SupersededCommit>,
 }
 
 impl StagedCommit {
@@ -454,9 +458,23 @@ impl StagedCommit {
         StagedCommit {
             staged_proposal_queue,
             state,
+            superseded_commit: None,
         }
     }
 
+    /// Sets the report about the own pending commit that is superseded by
+    /// this commit.
+    pub(crate) fn set_superseded_commit(&mut self, superseded_commit: Option<SupersededCommit>) {
+        self.superseded_commit = superseded_commit;
+    }
+
+    /// Returns the own pending commit that is superseded by this commit, if
+    /// there was one when this commit was processed. See
+    /// [`SupersededCommit`] for more information.
+    pub fn superseded_commit(&self) -> Option<&SupersededCommit> {
+        self.superseded_commit.as_ref()
+    }
+
     /// Returns the Add proposals that are covered by the Commit message as in iterator over [QueuedAddProposal].
     pub fn add_proposals(&self) -> impl Iterator<Item = QueuedAddProposal> {
         self.staged_proposal_queue.add_proposals()
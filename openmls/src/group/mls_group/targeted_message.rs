@@ -0,0 +1,145 @@
+//! MLS group targeted messages
+//!
+//! This module contains the API to send a [`TargetedMessage`] to a single
+//! member of the group and the processing of received targeted messages.
+
+use errors::{CreateTargetedMessageError, ProcessMessageError};
+use openmls_traits::{crypto::OpenMlsCrypto, signatures::Signer};
+
+use super::*;
+use crate::{
+    ciphersuite::{signable::Verifiable, OpenMlsSignaturePublicKey},
+    framing::{
+        errors::TargetedMessageError,
+        targeted_message::{TargetedMessageTbs, TARGETED_MESSAGE_PSK_LABEL},
+    },
+    group::ValidationError,
+};
+
+impl MlsGroup {
+    /// Creates a [`TargetedMessage`] with the given `message` that only the
+    /// member at the leaf index `recipient` can decrypt. The message is
+    /// signed with the `signer` and carries the AAD set with
+    /// [`MlsGroup::set_aad()`].
+    ///
+    /// Targeted messages can only be processed in the epoch they were
+    /// created in.
+    ///
+    /// Returns [`CreateTargetedMessageError::UnknownRecipient`] if there is no
+    /// member at the leaf index `recipient`.
+    pub fn create_targeted_message<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        recipient: LeafNodeIndex,
+        message: &[u8],
+    ) -> Result<MlsMessageOut, CreateTargetedMessageError> {
+        if !self.is_active() {
+            return Err(CreateTargetedMessageError::GroupStateError(
+                MlsGroupStateError::UseAfterEviction,
+            ));
+        }
+
+        let encryption_key = self
+            .public_group()
+            .leaf(recipient)
+            .ok_or(CreateTargetedMessageError::UnknownRecipient)?
+            .encryption_key();
+        let payload = TargetedMessageTbs::new(
+            self.group_id().clone(),
+            self.context().epoch(),
+            recipient,
+            &self.aad,
+            self.own_leaf_index(),
+            message,
+        );
+        let targeted_message = TargetedMessage::seal(
+            payload,
+            provider.crypto(),
+            self.ciphersuite(),
+            signer,
+            encryption_key,
+            &self.targeted_message_psk(provider.crypto())?,
+        )?;
+
+        self.reset_aad();
+        Ok(MlsMessageOut::from_targeted_message(
+            targeted_message,
+            self.version(),
+        ))
+    }
+
+    /// Decrypts the [`TargetedMessage`] and verifies the signature of the
+    /// sender.
+    pub(super) fn process_targeted_message<Provider: OpenMlsProvider>(
+        &self,
+        provider: &Provider,
+        targeted_message: TargetedMessage,
+    ) -> Result<ProcessedMessage, ProcessMessageError> {
+        if targeted_message.group_id() != self.group_id() {
+            return Err(ValidationError::WrongGroupId.into());
+        }
+        // The exporter secret and the decryption key are only available for
+        // the current epoch.
+        if targeted_message.epoch() != self.context().epoch() {
+            return Err(ValidationError::WrongEpoch.into());
+        }
+        if targeted_message.recipient_leaf_index() != self.own_leaf_index() {
+            return Err(TargetedMessageError::WrongRecipient.into());
+        }
+
+        let own_leaf = self
+            .own_leaf_node()
+            .ok_or_else(|| LibraryError::custom("The tree is broken. Couldn't find own leaf."))?;
+        let encryption_keypair = self
+            .read_epoch_keypairs(provider.storage())
+            .into_iter()
+            .find(|keypair| keypair.public_key() == own_leaf.encryption_key())
+            .ok_or(TargetedMessageError::MissingDecryptionKey)?;
+        let verifiable_content = targeted_message.open(
+            provider.crypto(),
+            self.ciphersuite(),
+            encryption_keypair.private_key(),
+            &self.targeted_message_psk(provider.crypto())?,
+        )?;
+
+        let sender = verifiable_content.sender_leaf_index();
+        let sender_leaf = self
+            .public_group()
+            .leaf(sender)
+            .ok_or(ValidationError::UnknownMember)?;
+        let signature_key = OpenMlsSignaturePublicKey::from_signature_key(
+            sender_leaf.signature_key().clone(),
+            self.ciphersuite().signature_algorithm(),
+        );
+        let content = verifiable_content
+            .verify(provider.crypto(), &signature_key)
+            .map_err(|_| ValidationError::InvalidSignature)?;
+
+        Ok(ProcessedMessage::new(
+            self.group_id().clone(),
+            targeted_message.epoch(),
+            Sender::Member(sender),
+            targeted_message.authenticated_data().to_vec(),
+            ProcessedMessageContent::TargetedMessage(ApplicationMessage::new(
+                content.into_application_data(),
+            )),
+            sender_leaf.credential().clone(),
+        ))
+    }
+
+    /// Exports the secret that binds targeted messages to the current epoch
+    /// of the group.
+    fn targeted_message_psk(&self, crypto: &impl OpenMlsCrypto) -> Result<Vec<u8>, LibraryError> {
+        self.group_epoch_secrets
+            .exporter_secret()
+            .derive_exported_secret(
+                self.ciphersuite(),
+                crypto,
+                TARGETED_MESSAGE_PSK_LABEL,
+                &[],
+                self.ciphersuite().hash_length(),
+            )
+            .map_err(LibraryError::unexpected_crypto_error)
+    }
+}
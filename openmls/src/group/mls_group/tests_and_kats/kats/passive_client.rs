@@ -246,6 +246,7 @@ impl PassiveClient {
             key_package: key_package.clone(),
             private_init_key: init_priv,
             private_encryption_key: encryption_priv.clone().into(),
+            init_key_handle: None,
         };
 
         // Store key package.
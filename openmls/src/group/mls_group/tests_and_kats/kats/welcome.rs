@@ -165,6 +165,7 @@ pub fn run_test_vector(test_vector: WelcomeTestVector) -> Result<(), &'static st
         key_package: key_package.clone(),
         private_init_key: init_priv,
         private_encryption_key: EncryptionPrivateKey::from(vec![]),
+        init_key_handle: None,
     };
 
     let hash_ref = key_package.hash_ref(provider.crypto()).unwrap();
@@ -0,0 +1,236 @@
+use openmls_basic_credential::SignatureKeyPair;
+use openmls_traits::{types::Ciphersuite, OpenMlsProvider as _};
+
+use crate::{
+    credentials::test_utils::new_credential,
+    extensions::{
+        AdminsExtension, Extension, ExtensionType, Extensions, RequiredCapabilitiesExtension,
+    },
+    framing::ProcessedMessageContent,
+    group::{
+        errors::{AddMembersError, AdminProposalError, CreateCommitError, ProposalValidationError},
+        MlsGroup, MlsGroupJoinConfig, StagedWelcome, PURE_PLAINTEXT_WIRE_FORMAT_POLICY,
+    },
+    key_packages::{KeyPackage, KeyPackageBundle},
+    storage::OpenMlsProvider,
+    treesync::node::leaf_node::Capabilities,
+};
+
+fn admin_capabilities() -> Capabilities {
+    Capabilities::new(None, None, Some(&[ExtensionType::Admins]), None, None)
+}
+
+fn key_package<Provider: OpenMlsProvider>(
+    identity: &[u8],
+    ciphersuite: Ciphersuite,
+    provider: &Provider,
+) -> (KeyPackageBundle, SignatureKeyPair) {
+    let (credential_with_key, signer) =
+        new_credential(provider, identity, ciphersuite.signature_algorithm());
+    let key_package_bundle = KeyPackage::builder()
+        .leaf_node_capabilities(admin_capabilities())
+        .build(ciphersuite, provider, &signer, credential_with_key)
+        .expect("error building key package");
+
+    (key_package_bundle, signer)
+}
+
+/// Alice creates a group in which she is the only admin and adds Bob.
+fn setup_admin_group<Provider: OpenMlsProvider>(
+    ciphersuite: Ciphersuite,
+    provider: &Provider,
+) -> (MlsGroup, SignatureKeyPair, MlsGroup, SignatureKeyPair) {
+    let (alice_credential_with_key, alice_signer) =
+        new_credential(provider, b"Alice", ciphersuite.signature_algorithm());
+    let (bob_key_package_bundle, bob_signer) = key_package(b"Bob", ciphersuite, provider);
+
+    let group_context_extensions = Extensions::from_vec(vec![
+        Extension::RequiredCapabilities(RequiredCapabilitiesExtension::new(
+            &[ExtensionType::Admins],
+            &[],
+            &[],
+        )),
+        Extension::Admins(AdminsExtension::new(vec![alice_credential_with_key
+            .credential
+            .clone()])),
+    ])
+    .unwrap();
+
+    let mut alice_group = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .with_wire_format_policy(PURE_PLAINTEXT_WIRE_FORMAT_POLICY)
+        .with_capabilities(admin_capabilities())
+        .with_group_context_extensions(group_context_extensions)
+        .expect("error setting group context extensions")
+        .build(provider, &alice_signer, alice_credential_with_key)
+        .expect("error creating group");
+
+    let (_commit, welcome, _group_info) = alice_group
+        .add_members(
+            provider,
+            &alice_signer,
+            &[bob_key_package_bundle.key_package().clone()],
+        )
+        .expect("error adding Bob");
+    alice_group.merge_pending_commit(provider).unwrap();
+
+    let bob_group = StagedWelcome::new_from_welcome(
+        provider,
+        &MlsGroupJoinConfig::builder()
+            .wire_format_policy(PURE_PLAINTEXT_WIRE_FORMAT_POLICY)
+            .build(),
+        welcome.into_welcome().unwrap(),
+        Some(alice_group.export_ratchet_tree().into()),
+    )
+    .and_then(|staged_join| staged_join.into_group(provider))
+    .expect("error joining group");
+
+    (alice_group, alice_signer, bob_group, bob_signer)
+}
+
+#[openmls_test::openmls_test]
+fn only_admins_add_members() {
+    let (mut alice_group, alice_signer, mut bob_group, bob_signer) =
+        setup_admin_group(ciphersuite, provider);
+    let (charlie_key_package_bundle, _charlie_signer) =
+        key_package(b"Charlie", ciphersuite, provider);
+
+    assert!(alice_group.is_admin(alice_group.own_leaf_index()));
+    assert!(!bob_group.is_admin(bob_group.own_leaf_index()));
+
+    // Bob can't commit an add.
+    let err = bob_group
+        .add_members(
+            provider,
+            &bob_signer,
+            &[charlie_key_package_bundle.key_package().clone()],
+        )
+        .expect_err("Bob is not an admin");
+    assert!(matches!(
+        err,
+        AddMembersError::CreateCommitError(CreateCommitError::ProposalValidationError(
+            ProposalValidationError::NotAnAdmin
+        ))
+    ));
+
+    // Alice can't commit an add proposed by Bob either.
+    let (proposal, _proposal_ref) = bob_group
+        .propose_add_member(
+            provider,
+            &bob_signer,
+            charlie_key_package_bundle.key_package(),
+        )
+        .expect("error creating add proposal");
+    let processed_message = alice_group
+        .process_message(provider, proposal.into_protocol_message().unwrap())
+        .expect("error processing proposal");
+    let ProcessedMessageContent::ProposalMessage(queued_proposal) =
+        processed_message.into_content()
+    else {
+        panic!("expected a proposal message");
+    };
+    alice_group
+        .store_pending_proposal(provider.storage(), *queued_proposal)
+        .unwrap();
+    assert!(alice_group
+        .commit_to_pending_proposals(provider, &alice_signer)
+        .is_err());
+}
+
+#[openmls_test::openmls_test]
+fn promote_and_demote_admin() {
+    let (mut alice_group, alice_signer, mut bob_group, bob_signer) =
+        setup_admin_group(ciphersuite, provider);
+    let (charlie_key_package_bundle, _charlie_signer) =
+        key_package(b"Charlie", ciphersuite, provider);
+    let bob_index = bob_group.own_leaf_index();
+
+    // Bob can propose to promote himself, but he can't commit it.
+    let _ = bob_group
+        .propose_promote_admin(provider, &bob_signer, bob_index)
+        .expect("error creating promotion proposal");
+    assert!(bob_group
+        .commit_to_pending_proposals(provider, &bob_signer)
+        .is_err());
+    bob_group
+        .clear_pending_proposals(provider.storage())
+        .unwrap();
+
+    // Alice promotes Bob.
+    let alice_index = alice_group.own_leaf_index();
+    assert!(matches!(
+        alice_group
+            .propose_promote_admin(provider, &alice_signer, alice_index)
+            .expect_err("Alice is an admin already"),
+        AdminProposalError::AlreadyAdmin
+    ));
+    let (proposal, _proposal_ref) = alice_group
+        .propose_promote_admin(provider, &alice_signer, bob_index)
+        .expect("error promoting Bob");
+    let processed_message = bob_group
+        .process_message(provider, proposal.into_protocol_message().unwrap())
+        .expect("error processing proposal");
+    let ProcessedMessageContent::ProposalMessage(queued_proposal) =
+        processed_message.into_content()
+    else {
+        panic!("expected a proposal message");
+    };
+    bob_group
+        .store_pending_proposal(provider.storage(), *queued_proposal)
+        .unwrap();
+
+    let (commit, _welcome, _group_info) = alice_group
+        .commit_to_pending_proposals(provider, &alice_signer)
+        .expect("error committing promotion");
+    alice_group.merge_pending_commit(provider).unwrap();
+    let processed_message = bob_group
+        .process_message(provider, commit.into_protocol_message().unwrap())
+        .expect("error processing commit");
+    let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+        processed_message.into_content()
+    else {
+        panic!("expected a commit");
+    };
+    bob_group
+        .merge_staged_commit(provider, *staged_commit)
+        .unwrap();
+    assert!(bob_group.is_admin(bob_index));
+
+    // Bob may now add Charlie and Alice accepts the commit.
+    let (commit, _welcome, _group_info) = bob_group
+        .add_members(
+            provider,
+            &bob_signer,
+            &[charlie_key_package_bundle.key_package().clone()],
+        )
+        .expect("error adding Charlie");
+    bob_group.merge_pending_commit(provider).unwrap();
+    let processed_message = alice_group
+        .process_message(provider, commit.into_protocol_message().unwrap())
+        .expect("error processing commit");
+    let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+        processed_message.into_content()
+    else {
+        panic!("expected a commit");
+    };
+    alice_group
+        .merge_staged_commit(provider, *staged_commit)
+        .unwrap();
+    assert_eq!(alice_group.members().count(), 3);
+
+    // Alice demotes Bob again.
+    let _ = alice_group
+        .propose_demote_admin(provider, &alice_signer, bob_index)
+        .expect("error demoting Bob");
+    let _ = alice_group
+        .commit_to_pending_proposals(provider, &alice_signer)
+        .expect("error committing demotion");
+    alice_group.merge_pending_commit(provider).unwrap();
+    assert!(!alice_group.is_admin(bob_index));
+    assert!(matches!(
+        alice_group
+            .propose_demote_admin(provider, &alice_signer, bob_index)
+            .expect_err("Bob is not an admin anymore"),
+        AdminProposalError::NotAnAdmin
+    ));
+}
@@ -0,0 +1,145 @@
+use crate::{
+    credentials::{test_utils::new_credential, CredentialType},
+    extensions::{
+        Extension, ExtensionType, Extensions, RequiredCapabilitiesExtension, UnknownExtension,
+    },
+    group::{errors::NewGroupError, MlsGroup},
+    key_packages::{errors::KeyPackageNewError, KeyPackage},
+    messages::proposals::ProposalType,
+    treesync::{errors::LeafNodeValidationError, node::leaf_node::Capabilities},
+};
+
+const REQUIRED_EXTENSION_TYPE: u16 = 0xff02;
+const REQUIRED_PROPOSAL_TYPE: u16 = 0xff03;
+const LEAF_EXTENSION_TYPE: u16 = 0xff04;
+
+fn group_context_extensions() -> Extensions {
+    Extensions::single(Extension::RequiredCapabilities(
+        RequiredCapabilitiesExtension::new(
+            &[ExtensionType::Unknown(REQUIRED_EXTENSION_TYPE)],
+            &[ProposalType::Custom(REQUIRED_PROPOSAL_TYPE)],
+            &[CredentialType::Basic],
+        ),
+    ))
+}
+
+fn leaf_node_extensions() -> Extensions {
+    Extensions::single(Extension::Unknown(
+        LEAF_EXTENSION_TYPE,
+        UnknownExtension(vec![1, 2, 3]),
+    ))
+}
+
+#[test]
+fn capabilities_builder_infers_requirements() {
+    let capabilities = Capabilities::builder()
+        .for_group_context_extensions(&group_context_extensions())
+        .for_leaf_node_extensions(&leaf_node_extensions())
+        .build();
+
+    assert!(capabilities.extensions().ends_with(&[
+        ExtensionType::Unknown(REQUIRED_EXTENSION_TYPE),
+        ExtensionType::Unknown(LEAF_EXTENSION_TYPE)
+    ]));
+    assert!(capabilities
+        .proposals()
+        .ends_with(&[ProposalType::Custom(REQUIRED_PROPOSAL_TYPE)]));
+    assert!(capabilities.credentials().contains(&CredentialType::Basic));
+    assert_eq!(
+        capabilities.check_group_requirements(&group_context_extensions(), &leaf_node_extensions()),
+        Ok(())
+    );
+
+    // Adding the same types again doesn't list them twice.
+    let capabilities = Capabilities::builder()
+        .for_group_context_extensions(&group_context_extensions())
+        .add_extensions(&[ExtensionType::Unknown(REQUIRED_EXTENSION_TYPE)])
+        .add_proposals(&[ProposalType::Custom(REQUIRED_PROPOSAL_TYPE)])
+        .build();
+    assert_eq!(
+        capabilities.extensions().len(),
+        Capabilities::default().extensions().len() + 1
+    );
+    assert_eq!(
+        capabilities.proposals().len(),
+        Capabilities::default().proposals().len() + 1
+    );
+
+    // Without any requirements, the defaults are used.
+    assert_eq!(
+        Capabilities::builder()
+            .for_group_context_extensions(&Extensions::empty())
+            .build(),
+        Capabilities::default()
+    );
+}
+
+#[openmls_test::openmls_test]
+fn builders_check_group_requirements() {
+    let (alice_credential_with_key, alice_signer) =
+        new_credential(provider, b"Alice", ciphersuite.signature_algorithm());
+
+    // Without explicit capabilities, they are inferred.
+    let alice_group = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .with_group_context_extensions(group_context_extensions())
+        .unwrap()
+        .with_leaf_node_extensions(leaf_node_extensions())
+        .unwrap()
+        .build(provider, &alice_signer, alice_credential_with_key.clone())
+        .unwrap();
+    let own_capabilities = alice_group.own_leaf_node().unwrap().capabilities();
+    assert!(own_capabilities
+        .extensions()
+        .contains(&ExtensionType::Unknown(REQUIRED_EXTENSION_TYPE)));
+    assert!(own_capabilities
+        .proposals()
+        .contains(&ProposalType::Custom(REQUIRED_PROPOSAL_TYPE)));
+
+    // Explicit capabilities that lack the required proposal are rejected.
+    let insufficient_capabilities = Capabilities::new(
+        None,
+        None,
+        Some(&[ExtensionType::Unknown(REQUIRED_EXTENSION_TYPE)]),
+        None,
+        None,
+    );
+    let err = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .with_group_context_extensions(group_context_extensions())
+        .unwrap()
+        .with_capabilities(insufficient_capabilities.clone())
+        .build(provider, &alice_signer, alice_credential_with_key)
+        .expect_err("the capabilities don't support the required proposal");
+    assert!(matches!(err, NewGroupError::UnsupportedProposalType));
+
+    // The same goes for key packages for the group.
+    let (bob_credential_with_key, bob_signer) =
+        new_credential(provider, b"Bob", ciphersuite.signature_algorithm());
+    let err = KeyPackage::builder()
+        .leaf_node_capabilities(insufficient_capabilities)
+        .group_context_extensions(group_context_extensions())
+        .build(
+            ciphersuite,
+            provider,
+            &bob_signer,
+            bob_credential_with_key.clone(),
+        )
+        .expect_err("the capabilities don't support the required proposal");
+    assert_eq!(
+        err,
+        KeyPackageNewError::LeafNodeValidation(LeafNodeValidationError::UnsupportedProposals)
+    );
+
+    let bob_key_package = KeyPackage::builder()
+        .group_context_extensions(group_context_extensions())
+        .leaf_node_extensions(leaf_node_extensions())
+        .build(ciphersuite, provider, &bob_signer, bob_credential_with_key)
+        .unwrap();
+    assert!(bob_key_package
+        .key_package()
+        .leaf_node()
+        .capabilities()
+        .proposals()
+        .contains(&ProposalType::Custom(REQUIRED_PROPOSAL_TYPE)));
+}
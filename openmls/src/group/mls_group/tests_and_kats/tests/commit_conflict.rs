@@ -0,0 +1,109 @@
+use openmls_traits::OpenMlsProvider as _;
+
+use crate::{
+    framing::ProcessedMessageContent,
+    group::{
+        errors::ReproposeError,
+        mls_group::tests_and_kats::utils::{setup_alice_bob_group, setup_client},
+    },
+    messages::proposals::Proposal,
+};
+
+#[openmls_test::openmls_test]
+fn superseded_commit_reports_dropped_proposals() {
+    let (mut alice_group, alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+    let (_charlie_credential, charlie_key_package_bundle, _charlie_signer, _charlie_pk) =
+        setup_client("Charlie", ciphersuite, provider);
+
+    // Alice adds Charlie, Bob updates his leaf in the same epoch.
+    let _ = alice_group
+        .add_members(
+            provider,
+            &alice_signer,
+            &[charlie_key_package_bundle.key_package().clone()],
+        )
+        .expect("error adding Charlie");
+    let (bob_commit, _, _) = bob_group
+        .self_update(provider, &bob_signer, Default::default())
+        .expect("error creating self update");
+
+    // The DS accepts Bob's commit.
+    let processed_message = alice_group
+        .process_message(provider, bob_commit.into_protocol_message().unwrap())
+        .expect("error processing commit");
+    let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+        processed_message.into_content()
+    else {
+        panic!("expected a commit");
+    };
+
+    let superseded_commit = staged_commit
+        .superseded_commit()
+        .expect("Alice's commit should have been superseded")
+        .clone();
+    assert_eq!(superseded_commit.epoch(), alice_group.epoch());
+    assert!(!superseded_commit.all_included());
+    assert_eq!(superseded_commit.included_proposals().count(), 0);
+    let dropped_proposals: Vec<_> = superseded_commit.dropped_proposals().collect();
+    assert_eq!(dropped_proposals.len(), 1);
+    assert!(matches!(dropped_proposals[0].proposal(), Proposal::Add(_)));
+
+    // Re-proposing only works once the superseding commit was merged.
+    let err = alice_group
+        .repropose_dropped_proposals(provider, &alice_signer, &superseded_commit)
+        .expect_err("re-proposing before merging should fail");
+    assert!(matches!(err, ReproposeError::SupersedingCommitNotMerged));
+
+    alice_group
+        .clear_pending_commit(provider.storage())
+        .unwrap();
+    alice_group
+        .merge_staged_commit(provider, *staged_commit)
+        .unwrap();
+    bob_group.merge_pending_commit(provider).unwrap();
+
+    let proposals = alice_group
+        .repropose_dropped_proposals(provider, &alice_signer, &superseded_commit)
+        .expect("error re-proposing");
+    assert_eq!(proposals.len(), 1);
+    assert_eq!(alice_group.pending_proposals().count(), 1);
+
+    // Bob can commit the re-proposed add.
+    let (proposal, _proposal_ref) = proposals.into_iter().next().unwrap();
+    let processed_message = bob_group
+        .process_message(provider, proposal.into_protocol_message().unwrap())
+        .expect("error processing proposal");
+    let ProcessedMessageContent::ProposalMessage(queued_proposal) =
+        processed_message.into_content()
+    else {
+        panic!("expected a proposal message");
+    };
+    bob_group
+        .store_pending_proposal(provider.storage(), *queued_proposal)
+        .unwrap();
+    let (_commit, welcome, _group_info) = bob_group
+        .commit_to_pending_proposals(provider, &bob_signer)
+        .expect("error committing to pending proposals");
+    assert!(welcome.is_some());
+}
+
+#[openmls_test::openmls_test]
+fn no_superseded_commit_without_pending_commit() {
+    let (mut alice_group, _alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+
+    let (bob_commit, _, _) = bob_group
+        .self_update(provider, &bob_signer, Default::default())
+        .expect("error creating self update");
+
+    let processed_message = alice_group
+        .process_message(provider, bob_commit.into_protocol_message().unwrap())
+        .expect("error processing commit");
+    let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+        processed_message.into_content()
+    else {
+        panic!("expected a commit");
+    };
+    assert!(staged_commit.superseded_commit().is_none());
+}
@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use openmls_traits::OpenMlsProvider as _;
+
+use crate::{
+    framing::ProcessedMessageContent,
+    group::{
+        mls_group::tests_and_kats::utils::{setup_alice_bob_group, setup_client},
+        CommitScheduler, CommitterElection,
+    },
+};
+
+#[openmls_test::openmls_test]
+fn lowest_leaf_index_commits() {
+    let (mut alice_group, alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+    let (_charlie_credential, charlie_key_package_bundle, _charlie_signer, _charlie_pk) =
+        setup_client("Charlie", ciphersuite, provider);
+
+    let mut alice_scheduler = CommitScheduler::new(CommitterElection::LowestLeafIndex);
+    let mut bob_scheduler = CommitScheduler::new(CommitterElection::LowestLeafIndex);
+
+    // Bob proposes to add Charlie.
+    let (proposal, _proposal_ref) = bob_group
+        .propose_add_member(
+            provider,
+            &bob_signer,
+            charlie_key_package_bundle.key_package(),
+        )
+        .expect("error creating add proposal");
+
+    let processed_message = alice_group
+        .process_message(provider, proposal.into_protocol_message().unwrap())
+        .expect("error processing proposal");
+    let ProcessedMessageContent::ProposalMessage(queued_proposal) =
+        processed_message.into_content()
+    else {
+        panic!("expected a proposal message");
+    };
+    alice_group
+        .store_pending_proposal(provider.storage(), *queued_proposal)
+        .unwrap();
+
+    // Both agree that Alice has to commit.
+    assert_eq!(
+        alice_scheduler.elected_committer(&alice_group),
+        Some(alice_group.own_leaf_index())
+    );
+    assert_eq!(
+        bob_scheduler.elected_committer(&bob_group),
+        Some(alice_group.own_leaf_index())
+    );
+
+    // Bob does not commit, Alice does.
+    assert!(bob_scheduler
+        .poll(provider, &bob_signer, &mut bob_group, Duration::ZERO)
+        .unwrap()
+        .is_none());
+    assert!(bob_scheduler.next_deadline(&bob_group).is_none());
+    let (commit, welcome, _group_info) = alice_scheduler
+        .poll(provider, &alice_signer, &mut alice_group, Duration::ZERO)
+        .unwrap()
+        .expect("Alice should have committed");
+    assert!(welcome.is_some());
+    assert!(alice_group.pending_commit().is_some());
+
+    // Polling again while the commit is pending doesn't do anything.
+    assert!(alice_scheduler
+        .poll(provider, &alice_signer, &mut alice_group, Duration::ZERO)
+        .unwrap()
+        .is_none());
+
+    alice_group.merge_pending_commit(provider).unwrap();
+
+    let processed_message = bob_group
+        .process_message(provider, commit.into_protocol_message().unwrap())
+        .expect("error processing commit");
+    let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+        processed_message.into_content()
+    else {
+        panic!("expected a commit");
+    };
+    bob_scheduler
+        .handle_staged_commit(provider, &mut bob_group, *staged_commit)
+        .unwrap();
+
+    assert_eq!(alice_group.epoch(), bob_group.epoch());
+    assert_eq!(bob_group.members().count(), 3);
+}
+
+#[openmls_test::openmls_test]
+fn takeover_after_timeout() {
+    let (alice_group, _alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+    let (_charlie_credential, charlie_key_package_bundle, _charlie_signer, _charlie_pk) =
+        setup_client("Charlie", ciphersuite, provider);
+
+    // Alice is the designated committer, but she is offline.
+    let mut bob_scheduler =
+        CommitScheduler::new(CommitterElection::Designated(alice_group.own_leaf_index()))
+            .with_takeover_timeout(Duration::from_secs(10));
+
+    let _ = bob_group
+        .propose_add_member(
+            provider,
+            &bob_signer,
+            charlie_key_package_bundle.key_package(),
+        )
+        .expect("error creating add proposal");
+
+    assert!(bob_scheduler
+        .poll(
+            provider,
+            &bob_signer,
+            &mut bob_group,
+            Duration::from_secs(1)
+        )
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        bob_scheduler.next_deadline(&bob_group),
+        Some(Duration::from_secs(11))
+    );
+    assert!(bob_scheduler
+        .poll(
+            provider,
+            &bob_signer,
+            &mut bob_group,
+            Duration::from_secs(5)
+        )
+        .unwrap()
+        .is_none());
+    assert!(bob_scheduler
+        .poll(
+            provider,
+            &bob_signer,
+            &mut bob_group,
+            Duration::from_secs(11)
+        )
+        .unwrap()
+        .is_some());
+    assert!(bob_group.pending_commit().is_some());
+}
+
+#[openmls_test::openmls_test]
+fn lost_commit_race() {
+    let (mut alice_group, alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+
+    let election = CommitterElection::RandomBackoff {
+        max_delay: Duration::ZERO,
+    };
+    let mut alice_scheduler = CommitScheduler::new(election);
+    let mut bob_scheduler = CommitScheduler::new(election);
+
+    // Bob proposes to update his leaf and Alice stores the proposal.
+    let (proposal, _proposal_ref) = bob_group
+        .propose_self_update(provider, &bob_signer, Default::default())
+        .expect("error creating update proposal");
+    let processed_message = alice_group
+        .process_message(provider, proposal.into_protocol_message().unwrap())
+        .expect("error processing proposal");
+    let ProcessedMessageContent::ProposalMessage(queued_proposal) =
+        processed_message.into_content()
+    else {
+        panic!("expected a proposal message");
+    };
+    alice_group
+        .store_pending_proposal(provider.storage(), *queued_proposal)
+        .unwrap();
+
+    // Both commit at the same time.
+    let (alice_commit, _, _) = alice_scheduler
+        .poll(provider, &alice_signer, &mut alice_group, Duration::ZERO)
+        .unwrap()
+        .expect("Alice should have committed");
+    let _ = bob_scheduler
+        .poll(provider, &bob_signer, &mut bob_group, Duration::ZERO)
+        .unwrap()
+        .expect("Bob should have committed");
+
+    // The DS accepts Alice's commit. Bob lost the race.
+    let processed_message = bob_group
+        .process_message(provider, alice_commit.into_protocol_message().unwrap())
+        .expect("error processing commit");
+    let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+        processed_message.into_content()
+    else {
+        panic!("expected a commit");
+    };
+    bob_scheduler
+        .handle_staged_commit(provider, &mut bob_group, *staged_commit)
+        .unwrap();
+    alice_group.merge_pending_commit(provider).unwrap();
+
+    assert!(bob_group.pending_commit().is_none());
+    assert_eq!(alice_group.epoch(), bob_group.epoch());
+    assert_eq!(
+        alice_group.export_ratchet_tree(),
+        bob_group.export_ratchet_tree()
+    );
+}
@@ -0,0 +1,191 @@
+use openmls_basic_credential::SignatureKeyPair;
+use openmls_traits::OpenMlsProvider as _;
+use tls_codec::{
+    DeserializeBytes, Serialize as _, TlsDeserializeBytes, TlsSerialize, TlsSize, VLBytes,
+};
+
+use crate::{
+    credentials::{
+        errors::CredentialError, register_credential_kind, test_utils::new_credential,
+        BasicCredential, Credential, CredentialKind, CredentialType, CredentialWithKey,
+    },
+    group::{MlsGroup, MlsGroupJoinConfig, StagedWelcome, PURE_PLAINTEXT_WIRE_FORMAT_POLICY},
+    key_packages::KeyPackage,
+    treesync::node::leaf_node::Capabilities,
+};
+
+const JWT_CREDENTIAL_TYPE: u16 = 0xF0A1;
+
+/// A token-based credential. Its identity is the subject, the expiry doesn't
+/// change the identity.
+#[derive(Debug, Clone, PartialEq, TlsSerialize, TlsDeserializeBytes, TlsSize)]
+struct JwtCredential {
+    subject: VLBytes,
+    expiry: u64,
+}
+
+impl CredentialKind for JwtCredential {
+    const CREDENTIAL_TYPE: u16 = JWT_CREDENTIAL_TYPE;
+
+    fn decode(serialized_content: &[u8]) -> Result<Self, CredentialError> {
+        match Self::tls_deserialize_bytes(serialized_content) {
+            Ok((credential, [])) => Ok(credential),
+            _ => Err(CredentialError::InvalidCredential),
+        }
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, CredentialError> {
+        self.tls_serialize_detached()
+            .map_err(|_| CredentialError::InvalidCredential)
+    }
+
+    fn identity(&self) -> Vec<u8> {
+        self.subject.as_slice().to_vec()
+    }
+}
+
+/// Another credential kind that uses the same credential type.
+struct OtherCredential;
+
+impl CredentialKind for OtherCredential {
+    const CREDENTIAL_TYPE: u16 = JWT_CREDENTIAL_TYPE;
+
+    fn decode(_serialized_content: &[u8]) -> Result<Self, CredentialError> {
+        Ok(Self)
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, CredentialError> {
+        Ok(vec![])
+    }
+
+    fn identity(&self) -> Vec<u8> {
+        vec![]
+    }
+}
+
+/// A credential kind that uses the basic credential type.
+struct NotBasicCredential;
+
+impl CredentialKind for NotBasicCredential {
+    const CREDENTIAL_TYPE: u16 = 1;
+
+    fn decode(_serialized_content: &[u8]) -> Result<Self, CredentialError> {
+        Ok(Self)
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, CredentialError> {
+        Ok(vec![])
+    }
+
+    fn identity(&self) -> Vec<u8> {
+        vec![]
+    }
+}
+
+fn jwt_credential(subject: &[u8], expiry: u64) -> Credential {
+    Credential::from_kind(&JwtCredential {
+        subject: subject.into(),
+        expiry,
+    })
+    .unwrap()
+}
+
+#[test]
+fn credential_kind_registration() {
+    register_credential_kind::<JwtCredential>().unwrap();
+    // Registering the same kind again is fine.
+    register_credential_kind::<JwtCredential>().unwrap();
+    assert_eq!(
+        register_credential_kind::<OtherCredential>(),
+        Err(CredentialError::AlreadyRegistered)
+    );
+    assert_eq!(
+        register_credential_kind::<NotBasicCredential>(),
+        Err(CredentialError::ReservedCredentialType)
+    );
+
+    // Registered credential types are supported by default.
+    assert!(Capabilities::default()
+        .credentials()
+        .contains(&CredentialType::Other(JWT_CREDENTIAL_TYPE)));
+
+    // Typed parsing and identities
+    let credential = jwt_credential(b"alice", 1);
+    assert_eq!(
+        credential.as_kind::<JwtCredential>().unwrap(),
+        JwtCredential {
+            subject: b"alice".as_slice().into(),
+            expiry: 1,
+        }
+    );
+    assert_eq!(credential.identity(), Some(b"alice".to_vec()));
+    assert!(credential.same_identity(&jwt_credential(b"alice", 2)));
+    assert!(!credential.same_identity(&jwt_credential(b"bob", 1)));
+
+    let basic_credential: Credential = BasicCredential::new(b"alice".to_vec()).into();
+    assert_eq!(basic_credential.identity(), Some(b"alice".to_vec()));
+    assert!(!credential.same_identity(&basic_credential));
+    assert_eq!(
+        basic_credential.as_kind::<JwtCredential>(),
+        Err(CredentialError::WrongCredentialType)
+    );
+    assert_eq!(
+        Credential::new(CredentialType::Other(JWT_CREDENTIAL_TYPE), vec![1, 2, 3])
+            .as_kind::<JwtCredential>(),
+        Err(CredentialError::InvalidCredential)
+    );
+}
+
+#[openmls_test::openmls_test]
+fn remove_member_by_credential_kind_identity() {
+    register_credential_kind::<JwtCredential>().unwrap();
+
+    let (alice_credential_with_key, alice_signer) =
+        new_credential(provider, b"Alice", ciphersuite.signature_algorithm());
+    let bob_signer = SignatureKeyPair::new(ciphersuite.signature_algorithm()).unwrap();
+    bob_signer.store(provider.storage()).unwrap();
+    let bob_credential_with_key = CredentialWithKey {
+        credential: jwt_credential(b"bob", 1),
+        signature_key: bob_signer.public().into(),
+    };
+
+    let bob_key_package = KeyPackage::builder()
+        .build(ciphersuite, provider, &bob_signer, bob_credential_with_key)
+        .unwrap();
+
+    let mut alice_group = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .with_wire_format_policy(PURE_PLAINTEXT_WIRE_FORMAT_POLICY)
+        .build(provider, &alice_signer, alice_credential_with_key)
+        .unwrap();
+    let (_commit, welcome, _group_info) = alice_group
+        .add_members(
+            provider,
+            &alice_signer,
+            &[bob_key_package.key_package().clone()],
+        )
+        .unwrap();
+    alice_group.merge_pending_commit(provider).unwrap();
+
+    let bob_group = StagedWelcome::new_from_welcome(
+        provider,
+        &MlsGroupJoinConfig::default(),
+        welcome.into_welcome().unwrap(),
+        Some(alice_group.export_ratchet_tree().into()),
+    )
+    .and_then(|staged_join| staged_join.into_group(provider))
+    .unwrap();
+    let bob_index = bob_group.own_leaf_index();
+
+    // Bob's token was renewed in the meantime, but the identity is the same.
+    alice_group
+        .propose_remove_member_by_credential(provider, &alice_signer, &jwt_credential(b"bob", 2))
+        .unwrap();
+    alice_group
+        .commit_to_pending_proposals(provider, &alice_signer)
+        .unwrap();
+    alice_group.merge_pending_commit(provider).unwrap();
+
+    assert_eq!(alice_group.members().count(), 1);
+    assert!(alice_group.member(bob_index).is_none());
+}
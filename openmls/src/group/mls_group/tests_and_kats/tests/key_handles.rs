@@ -0,0 +1,106 @@
+use openmls_rust_crypto::{RustCrypto, SoftwareKeyHandles};
+use openmls_traits::{key_handles::HandleSigner, storage::StorageProvider, OpenMlsProvider as _};
+
+use crate::{
+    credentials::{test_utils::new_credential, BasicCredential, CredentialWithKey},
+    framing::ProcessedMessageContent,
+    group::{errors::WelcomeError, MlsGroup, MlsGroupJoinConfig, StagedWelcome},
+    key_packages::KeyPackage,
+};
+
+/// Bob keeps his signature key and his init key in a token. He joins Alice's
+/// group and sends a message without the private keys ever leaving the token.
+#[openmls_test::openmls_test]
+fn join_with_key_handles() {
+    // The software token uses the RustCrypto primitives.
+    if RustCrypto::default().supports(ciphersuite).is_err() {
+        return;
+    }
+    let key_handles = SoftwareKeyHandles::default();
+
+    let bob_signer = HandleSigner::generate(&key_handles, ciphersuite.signature_algorithm())
+        .expect("error generating signature key");
+    let bob_credential_with_key = CredentialWithKey {
+        credential: BasicCredential::new(b"Bob".to_vec()).into(),
+        signature_key: bob_signer.public_key().into(),
+    };
+    let bob_key_package_bundle = KeyPackage::builder()
+        .build_with_key_handles(
+            ciphersuite,
+            provider,
+            &key_handles,
+            &bob_signer,
+            bob_credential_with_key,
+        )
+        .expect("error building key package");
+    let init_key_handle = bob_key_package_bundle
+        .init_key_handle()
+        .cloned()
+        .expect("the init key is held by the token");
+    assert!(bob_key_package_bundle.init_private_key().is_empty());
+    assert!(key_handles.contains(&init_key_handle));
+
+    let (alice_credential_with_key, alice_signer) =
+        new_credential(provider, b"Alice", ciphersuite.signature_algorithm());
+    let mut alice_group = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .build(provider, &alice_signer, alice_credential_with_key)
+        .expect("error creating group");
+    let (_commit, welcome, _group_info) = alice_group
+        .add_members(
+            provider,
+            &alice_signer,
+            &[bob_key_package_bundle.key_package().clone()],
+        )
+        .expect("error adding Bob");
+    alice_group.merge_pending_commit(provider).unwrap();
+    let welcome = welcome.into_welcome().unwrap();
+
+    // The Welcome can't be decrypted without the token.
+    let err = StagedWelcome::new_from_welcome(
+        provider,
+        &MlsGroupJoinConfig::default(),
+        welcome.clone(),
+        Some(alice_group.export_ratchet_tree().into()),
+    )
+    .expect_err("the init key is held by the token");
+    assert!(matches!(err, WelcomeError::MissingKeyHandleProvider));
+
+    // The failed attempt consumed the key package, so it's stored again.
+    provider
+        .storage()
+        .write_key_package(
+            &bob_key_package_bundle
+                .key_package()
+                .hash_ref(provider.crypto())
+                .unwrap(),
+            &bob_key_package_bundle,
+        )
+        .unwrap();
+    let mut bob_group = StagedWelcome::new_from_welcome_with_key_handles(
+        provider,
+        &key_handles,
+        &MlsGroupJoinConfig::default(),
+        welcome,
+        Some(alice_group.export_ratchet_tree().into()),
+    )
+    .and_then(|staged_join| staged_join.into_group(provider))
+    .expect("error joining group");
+
+    // The init key was used up.
+    assert!(!key_handles.contains(&init_key_handle));
+
+    // Bob's message is signed by the token.
+    let message = bob_group
+        .create_message(provider, &bob_signer, b"Hello Alice")
+        .expect("error creating message");
+    let processed_message = alice_group
+        .process_message(provider, message.into_protocol_message().unwrap())
+        .expect("error processing message");
+    let ProcessedMessageContent::ApplicationMessage(application_message) =
+        processed_message.into_content()
+    else {
+        panic!("expected an application message");
+    };
+    assert_eq!(application_message.into_bytes(), b"Hello Alice");
+}
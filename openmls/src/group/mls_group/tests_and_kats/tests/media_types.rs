@@ -0,0 +1,168 @@
+use openmls_traits::types::Ciphersuite;
+use tls_codec::{Deserialize, Serialize};
+
+use crate::{
+    credentials::test_utils::new_credential,
+    extensions::{
+        Extension, ExtensionType, Extensions, MediaType, RequiredMediaTypesExtension,
+        SupportedMediaTypesExtension,
+    },
+    group::{
+        errors::{
+            AddMembersError, CreateCommitError, CreateGroupContextExtProposalError, NewGroupError,
+            ProposalValidationError,
+        },
+        MlsGroup, PURE_PLAINTEXT_WIRE_FORMAT_POLICY,
+    },
+    key_packages::{KeyPackage, KeyPackageBundle},
+    storage::OpenMlsProvider,
+    treesync::node::leaf_node::Capabilities,
+};
+
+fn required_media_types(media_types: &[&str]) -> Extensions {
+    Extensions::single(Extension::RequiredMediaTypes(
+        RequiredMediaTypesExtension::new(media_types.iter().copied().map(MediaType::new).collect()),
+    ))
+}
+
+fn supported_media_types(media_types: &[&str]) -> Extensions {
+    Extensions::single(Extension::SupportedMediaTypes(
+        SupportedMediaTypesExtension::new(
+            media_types.iter().copied().map(MediaType::new).collect(),
+        ),
+    ))
+}
+
+fn key_package<Provider: OpenMlsProvider>(
+    identity: &[u8],
+    leaf_node_extensions: Extensions,
+    ciphersuite: Ciphersuite,
+    provider: &Provider,
+) -> KeyPackageBundle {
+    let (credential_with_key, signer) =
+        new_credential(provider, identity, ciphersuite.signature_algorithm());
+    let capabilities = Capabilities::builder()
+        .add_extensions(&[ExtensionType::RequiredMediaTypes])
+        .for_leaf_node_extensions(&leaf_node_extensions)
+        .build();
+    KeyPackage::builder()
+        .leaf_node_capabilities(capabilities)
+        .leaf_node_extensions(leaf_node_extensions)
+        .build(ciphersuite, provider, &signer, credential_with_key)
+        .expect("error creating key package")
+}
+
+#[test]
+fn media_types_extensions_codec() {
+    let extension = Extension::SupportedMediaTypes(SupportedMediaTypesExtension::new(vec![
+        "text/plain".into(),
+        "text/markdown;variant=GFM".into(),
+    ]));
+    let serialized = extension.tls_serialize_detached().unwrap();
+    let deserialized = Extension::tls_deserialize_exact(serialized).unwrap();
+    assert_eq!(extension, deserialized);
+
+    let supported = deserialized.as_supported_media_types_extension().unwrap();
+    assert_eq!(
+        supported.media_types()[1].as_str(),
+        Some("text/markdown;variant=GFM")
+    );
+    assert!(supported.supports(&MediaType::new("text/plain")));
+    assert!(!supported.supports(&MediaType::new("image/png")));
+
+    let required = RequiredMediaTypesExtension::new(vec!["text/plain".into()]);
+    assert!(required.is_supported_by(&Extensions::single(deserialized.clone())));
+    assert!(!required.is_supported_by(&Extensions::empty()));
+    assert!(RequiredMediaTypesExtension::default().is_supported_by(&Extensions::empty()));
+}
+
+#[openmls_test::openmls_test]
+fn required_media_types_are_enforced() {
+    let (alice_credential_with_key, alice_signer) =
+        new_credential(provider, b"Alice", ciphersuite.signature_algorithm());
+
+    // Alice can't create a group that requires media types she doesn't
+    // support.
+    let err = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .with_group_context_extensions(required_media_types(&["text/plain"]))
+        .unwrap()
+        .with_leaf_node_extensions(supported_media_types(&["text/markdown"]))
+        .unwrap()
+        .build(provider, &alice_signer, alice_credential_with_key.clone())
+        .expect_err("Alice doesn't support text/plain");
+    assert!(matches!(err, NewGroupError::UnsupportedMediaTypes));
+
+    let mut alice_group = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .with_wire_format_policy(PURE_PLAINTEXT_WIRE_FORMAT_POLICY)
+        .with_group_context_extensions(required_media_types(&["text/plain"]))
+        .unwrap()
+        .with_leaf_node_extensions(supported_media_types(&["text/plain", "text/markdown"]))
+        .unwrap()
+        .build(provider, &alice_signer, alice_credential_with_key)
+        .expect("error creating group");
+
+    // Charlie doesn't advertise any media types and can't be added.
+    let charlie_key_package = key_package(b"Charlie", Extensions::empty(), ciphersuite, provider);
+    let err = alice_group
+        .add_members(
+            provider,
+            &alice_signer,
+            &[charlie_key_package.key_package().clone()],
+        )
+        .expect_err("Charlie doesn't support text/plain");
+    assert!(matches!(
+        err,
+        AddMembersError::CreateCommitError(CreateCommitError::ProposalValidationError(
+            ProposalValidationError::UnsupportedMediaTypes
+        ))
+    ));
+
+    // Bob supports plain text only.
+    let bob_key_package = key_package(
+        b"Bob",
+        supported_media_types(&["text/plain"]),
+        ciphersuite,
+        provider,
+    );
+    alice_group
+        .add_members(
+            provider,
+            &alice_signer,
+            &[bob_key_package.key_package().clone()],
+        )
+        .expect("error adding Bob");
+    alice_group.merge_pending_commit(provider).unwrap();
+
+    let plain_text = MediaType::new("text/plain");
+    let markdown = MediaType::new("text/markdown");
+    assert!(alice_group.all_members_support_media_type(&plain_text));
+    assert!(!alice_group.all_members_support_media_type(&markdown));
+    let bob_index = alice_group
+        .members()
+        .find(|member| member.index != alice_group.own_leaf_index())
+        .unwrap()
+        .index;
+    assert_eq!(
+        alice_group.members_without_media_type(&markdown),
+        vec![bob_index]
+    );
+
+    // Bob doesn't support markdown, so it can't be required.
+    let err = alice_group
+        .update_group_context_extensions(
+            provider,
+            required_media_types(&["text/plain", "text/markdown"]),
+            &alice_signer,
+        )
+        .expect_err("Bob doesn't support text/markdown");
+    assert!(matches!(
+        err,
+        CreateGroupContextExtProposalError::CreateCommitError(
+            CreateCommitError::ProposalValidationError(
+                ProposalValidationError::UnsupportedMediaTypes
+            )
+        )
+    ));
+}
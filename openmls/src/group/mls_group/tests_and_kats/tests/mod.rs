@@ -1,7 +1,19 @@
 //! Test and Known Answer Test (KAT) modules for the MLS group.
 
+mod admins;
+mod capabilities_builder;
+mod commit_conflict;
+mod commit_scheduler;
 mod create_commit_params;
+mod credential_kind;
 mod external_init;
+mod key_handles;
+mod media_types;
 mod mls_group;
 mod past_secrets;
+mod proposal_policy;
 mod proposals;
+mod safe_application;
+mod self_remove;
+mod targeted_message;
+mod typed_extensions;
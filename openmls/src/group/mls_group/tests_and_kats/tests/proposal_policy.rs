@@ -0,0 +1,100 @@
+use crate::{
+    credentials::Credential,
+    framing::{ProcessedMessageContent, Sender},
+    group::{
+        errors::{ProcessMessageError, ProposalValidationError, StageCommitError},
+        mls_group::tests_and_kats::utils::{setup_alice_bob_group, setup_client},
+        GroupContext, ProposalDecision, ProposalPolicy,
+    },
+    messages::proposals::Proposal,
+};
+
+/// Only the member in leaf 0 may add members.
+#[derive(Debug)]
+struct OnlyCreatorAdds;
+
+impl ProposalPolicy for OnlyCreatorAdds {
+    fn check(
+        &self,
+        _group_context: &GroupContext,
+        sender: &Sender,
+        _credential: &Credential,
+        proposal: &Proposal,
+    ) -> ProposalDecision {
+        match (sender, proposal) {
+            (Sender::Member(index), Proposal::Add(_)) if index.u32() != 0 => ProposalDecision::Deny,
+            _ => ProposalDecision::Allow,
+        }
+    }
+}
+
+#[openmls_test::openmls_test]
+fn denied_standalone_proposal() {
+    let (mut alice_group, _alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+    let (_charlie_credential, charlie_key_package_bundle, _charlie_signer, _charlie_pk) =
+        setup_client("Charlie", ciphersuite, provider);
+
+    alice_group.set_proposal_policy(OnlyCreatorAdds);
+
+    let (proposal, _proposal_ref) = bob_group
+        .propose_add_member(
+            provider,
+            &bob_signer,
+            charlie_key_package_bundle.key_package(),
+        )
+        .expect("error creating add proposal");
+    let err = alice_group
+        .process_message(provider, proposal.into_protocol_message().unwrap())
+        .expect_err("the add proposal should have been denied");
+    assert_eq!(err, ProcessMessageError::ProposalDenied);
+
+    // Allowed proposals are processed as usual.
+    let (proposal, _proposal_ref) = bob_group
+        .propose_self_update(provider, &bob_signer, Default::default())
+        .expect("error creating update proposal");
+    let processed_message = alice_group
+        .process_message(provider, proposal.into_protocol_message().unwrap())
+        .expect("error processing update proposal");
+    assert!(matches!(
+        processed_message.into_content(),
+        ProcessedMessageContent::ProposalMessage(_)
+    ));
+}
+
+#[openmls_test::openmls_test]
+fn denied_inline_proposal() {
+    let (mut alice_group, _alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+    let (_charlie_credential, charlie_key_package_bundle, _charlie_signer, _charlie_pk) =
+        setup_client("Charlie", ciphersuite, provider);
+
+    alice_group.set_proposal_policy(OnlyCreatorAdds);
+
+    let (commit, _welcome, _group_info) = bob_group
+        .add_members(
+            provider,
+            &bob_signer,
+            &[charlie_key_package_bundle.key_package().clone()],
+        )
+        .expect("error adding Charlie");
+    let err = alice_group
+        .process_message(provider, commit.clone().into_protocol_message().unwrap())
+        .expect_err("the commit should have been rejected");
+    assert_eq!(
+        err,
+        ProcessMessageError::InvalidCommit(StageCommitError::ProposalValidationError(
+            ProposalValidationError::ProposalDenied
+        ))
+    );
+
+    // Without the policy, the commit is accepted.
+    alice_group.remove_proposal_policy();
+    let processed_message = alice_group
+        .process_message(provider, commit.into_protocol_message().unwrap())
+        .expect("error processing commit");
+    assert!(matches!(
+        processed_message.into_content(),
+        ProcessedMessageContent::StagedCommitMessage(_)
+    ));
+}
@@ -0,0 +1,134 @@
+use tls_codec::Serialize;
+
+use crate::{
+    framing::{errors::SafeAadError, ComponentData, ProcessedMessageContent, SafeAad},
+    group::{
+        errors::{ComponentError, SafeExportSecretError},
+        mls_group::tests_and_kats::utils::setup_alice_bob_group,
+    },
+};
+
+const CALLING: u32 = 1;
+const FILE_SHARING: u32 = 2;
+const PRESENCE: u32 = 3;
+
+#[test]
+fn safe_aad_codec() {
+    let mut safe_aad = SafeAad::new();
+    safe_aad.insert(FILE_SHARING, b"file".to_vec());
+    safe_aad.insert(CALLING, b"call".to_vec());
+    safe_aad.insert(FILE_SHARING, b"other file".to_vec());
+    assert_eq!(
+        safe_aad
+            .iter()
+            .map(ComponentData::component_id)
+            .collect::<Vec<_>>(),
+        vec![CALLING, FILE_SHARING]
+    );
+    assert_eq!(safe_aad.get(FILE_SHARING), Some(b"other file".as_slice()));
+    assert_eq!(safe_aad.get(PRESENCE), None);
+
+    let aad = safe_aad.to_aad().unwrap();
+    assert_eq!(SafeAad::from_aad(&aad), Ok(safe_aad.clone()));
+    assert_eq!(SafeAad::from_aad(&[]), Ok(SafeAad::new()));
+    assert_eq!(
+        SafeAad::from_aad(&[aad.as_slice(), b"trailing bytes"].concat()),
+        Err(SafeAadError::Malformed)
+    );
+
+    // Items must be sorted by component ID.
+    let mut items = safe_aad.iter().cloned().collect::<Vec<_>>();
+    items.reverse();
+    let unsorted = items.tls_serialize_detached().unwrap();
+    assert_eq!(
+        SafeAad::from_aad(&unsorted),
+        Err(SafeAadError::InvalidComponentOrder)
+    );
+
+    assert!(safe_aad.remove(CALLING));
+    assert!(!safe_aad.remove(CALLING));
+}
+
+#[openmls_test::openmls_test]
+fn safe_export_secret() {
+    let (mut alice_group, _alice_signer, mut bob_group, _bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+
+    assert_eq!(
+        alice_group.safe_export_secret(provider, CALLING),
+        Err(SafeExportSecretError::UnregisteredComponent)
+    );
+
+    for group in [&mut alice_group, &mut bob_group] {
+        group.register_component(CALLING).unwrap();
+        group.register_component(FILE_SHARING).unwrap();
+    }
+    assert_eq!(
+        alice_group.register_component(CALLING),
+        Err(ComponentError::AlreadyRegistered)
+    );
+
+    let calling_secret = alice_group.safe_export_secret(provider, CALLING).unwrap();
+    assert_eq!(calling_secret.len(), ciphersuite.hash_length());
+    assert_eq!(
+        calling_secret,
+        bob_group.safe_export_secret(provider, CALLING).unwrap()
+    );
+    assert_ne!(
+        calling_secret,
+        alice_group
+            .safe_export_secret(provider, FILE_SHARING)
+            .unwrap()
+    );
+}
+
+#[openmls_test::openmls_test]
+fn safe_aad() {
+    let (mut alice_group, alice_signer, mut bob_group, _bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+    alice_group.register_component(CALLING).unwrap();
+    alice_group.register_component(FILE_SHARING).unwrap();
+    bob_group.register_component(FILE_SHARING).unwrap();
+
+    assert_eq!(
+        alice_group.set_safe_aad(PRESENCE, b"online".to_vec()),
+        Err(ComponentError::UnregisteredComponent)
+    );
+    alice_group
+        .set_safe_aad(FILE_SHARING, b"file".to_vec())
+        .unwrap();
+    alice_group.set_safe_aad(CALLING, b"call".to_vec()).unwrap();
+
+    let message = alice_group
+        .create_message(provider, &alice_signer, b"Hello Bob")
+        .unwrap();
+    // The AAD is reset after the message was created.
+    assert!(alice_group.safe_aad().unwrap().is_empty());
+
+    let processed_message = bob_group
+        .process_message(provider, message.into_protocol_message().unwrap())
+        .unwrap();
+    assert!(matches!(
+        processed_message.content(),
+        ProcessedMessageContent::ApplicationMessage(_)
+    ));
+    assert_eq!(
+        processed_message.safe_aad().unwrap().get(CALLING),
+        Some(b"call".as_slice())
+    );
+    assert_eq!(
+        bob_group.component_aad(&processed_message, FILE_SHARING),
+        Ok(Some(b"file".to_vec()))
+    );
+    assert_eq!(
+        bob_group.component_aad(&processed_message, CALLING),
+        Err(ComponentError::UnregisteredComponent)
+    );
+
+    // A raw AAD can't be extended with component data.
+    alice_group.set_aad(b"raw".to_vec());
+    assert_eq!(
+        alice_group.set_safe_aad(CALLING, b"call".to_vec()),
+        Err(ComponentError::SafeAad(SafeAadError::Malformed))
+    );
+}
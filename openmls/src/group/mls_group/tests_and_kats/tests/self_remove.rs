@@ -0,0 +1,194 @@
+use openmls_basic_credential::SignatureKeyPair;
+use openmls_traits::{types::Ciphersuite, OpenMlsProvider as _};
+
+use crate::{
+    credentials::test_utils::new_credential,
+    framing::{ProcessedMessageContent, ProtocolMessage},
+    group::{
+        errors::LeaveGroupError, mls_group::tests_and_kats::utils::setup_alice_bob_group,
+        proposal_store::ProposalStore, MlsGroup, MlsGroupJoinConfig, PublicGroup, StagedWelcome,
+        PURE_PLAINTEXT_WIRE_FORMAT_POLICY,
+    },
+    key_packages::KeyPackage,
+    messages::proposals::{Proposal, ProposalType},
+    storage::OpenMlsProvider,
+    treesync::node::leaf_node::Capabilities,
+};
+
+fn self_remove_capabilities() -> Capabilities {
+    Capabilities::new(None, None, None, Some(&[ProposalType::SelfRemove]), None)
+}
+
+/// Alice creates a group in which all members support SelfRemove proposals
+/// and adds Bob. A public group tracks the group on behalf of the DS.
+fn setup_self_remove_group<Provider: OpenMlsProvider>(
+    ciphersuite: Ciphersuite,
+    provider: &Provider,
+) -> (
+    MlsGroup,
+    SignatureKeyPair,
+    MlsGroup,
+    SignatureKeyPair,
+    PublicGroup,
+) {
+    let (alice_credential_with_key, alice_signer) =
+        new_credential(provider, b"Alice", ciphersuite.signature_algorithm());
+    let (bob_credential_with_key, bob_signer) =
+        new_credential(provider, b"Bob", ciphersuite.signature_algorithm());
+    let bob_key_package_bundle = KeyPackage::builder()
+        .leaf_node_capabilities(self_remove_capabilities())
+        .build(ciphersuite, provider, &bob_signer, bob_credential_with_key)
+        .expect("error building key package");
+
+    let mut alice_group = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .with_wire_format_policy(PURE_PLAINTEXT_WIRE_FORMAT_POLICY)
+        .with_capabilities(self_remove_capabilities())
+        .build(provider, &alice_signer, alice_credential_with_key)
+        .expect("error creating group");
+
+    let (commit, welcome, _group_info) = alice_group
+        .add_members(
+            provider,
+            &alice_signer,
+            &[bob_key_package_bundle.key_package().clone()],
+        )
+        .expect("error adding Bob");
+
+    // The DS starts tracking the group before Bob is added.
+    let verifiable_group_info = alice_group
+        .export_group_info(provider, &alice_signer, false)
+        .unwrap()
+        .into_verifiable_group_info()
+        .unwrap();
+    let (mut public_group, _group_info) = PublicGroup::from_external(
+        provider.crypto(),
+        provider.storage(),
+        alice_group.export_ratchet_tree().into(),
+        verifiable_group_info,
+        ProposalStore::new(),
+    )
+    .expect("error creating public group");
+    let processed_message = public_group
+        .process_message(provider.crypto(), commit.into_protocol_message().unwrap())
+        .expect("error processing commit");
+    let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+        processed_message.into_content()
+    else {
+        panic!("expected a commit");
+    };
+    public_group
+        .merge_commit(provider.storage(), *staged_commit)
+        .unwrap();
+    alice_group.merge_pending_commit(provider).unwrap();
+
+    let bob_group = StagedWelcome::new_from_welcome(
+        provider,
+        &MlsGroupJoinConfig::builder()
+            .wire_format_policy(PURE_PLAINTEXT_WIRE_FORMAT_POLICY)
+            .build(),
+        welcome.into_welcome().unwrap(),
+        Some(alice_group.export_ratchet_tree().into()),
+    )
+    .and_then(|staged_join| staged_join.into_group(provider))
+    .expect("error joining group");
+
+    (
+        alice_group,
+        alice_signer,
+        bob_group,
+        bob_signer,
+        public_group,
+    )
+}
+
+#[openmls_test::openmls_test]
+fn leave_group_via_self_remove() {
+    let (mut alice_group, alice_signer, mut bob_group, bob_signer, mut public_group) =
+        setup_self_remove_group(ciphersuite, provider);
+    let bob_index = bob_group.own_leaf_index();
+
+    let proposal = bob_group
+        .leave_group_via_self_remove(provider, &bob_signer)
+        .expect("error creating self remove proposal");
+    let proposal: ProtocolMessage = proposal.into_protocol_message().unwrap();
+
+    // The DS recognizes the SelfRemove proposal.
+    let processed_message = public_group
+        .process_message(provider.crypto(), proposal.clone())
+        .expect("error processing proposal");
+    let ProcessedMessageContent::ProposalMessage(queued_proposal) =
+        processed_message.into_content()
+    else {
+        panic!("expected a proposal message");
+    };
+    assert_eq!(queued_proposal.proposal(), &Proposal::SelfRemove);
+    public_group
+        .add_proposal(provider.storage(), *queued_proposal)
+        .unwrap();
+    let pending_self_removes: Vec<_> = public_group.pending_self_removes().collect();
+    assert_eq!(pending_self_removes.len(), 1);
+    assert_eq!(pending_self_removes[0].1, bob_index);
+
+    // Alice commits the proposal.
+    let processed_message = alice_group
+        .process_message(provider, proposal)
+        .expect("error processing proposal");
+    let ProcessedMessageContent::ProposalMessage(queued_proposal) =
+        processed_message.into_content()
+    else {
+        panic!("expected a proposal message");
+    };
+    alice_group
+        .store_pending_proposal(provider.storage(), *queued_proposal)
+        .unwrap();
+    let (commit, _welcome, _group_info) = alice_group
+        .commit_to_pending_proposals(provider, &alice_signer)
+        .expect("error committing self remove");
+    alice_group.merge_pending_commit(provider).unwrap();
+    assert_eq!(alice_group.members().count(), 1);
+
+    // The DS processes the commit.
+    let processed_message = public_group
+        .process_message(
+            provider.crypto(),
+            commit.clone().into_protocol_message().unwrap(),
+        )
+        .expect("error processing commit");
+    let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+        processed_message.into_content()
+    else {
+        panic!("expected a commit");
+    };
+    public_group
+        .merge_commit(provider.storage(), *staged_commit)
+        .unwrap();
+    assert_eq!(public_group.members().count(), 1);
+    assert_eq!(public_group.pending_self_removes().count(), 0);
+
+    // Bob processes the commit and is no longer part of the group.
+    let processed_message = bob_group
+        .process_message(provider, commit.into_protocol_message().unwrap())
+        .expect("error processing commit");
+    let ProcessedMessageContent::StagedCommitMessage(staged_commit) =
+        processed_message.into_content()
+    else {
+        panic!("expected a commit");
+    };
+    assert!(staged_commit.self_removed());
+    bob_group
+        .merge_staged_commit(provider, *staged_commit)
+        .unwrap();
+    assert!(!bob_group.is_active());
+}
+
+#[openmls_test::openmls_test]
+fn self_remove_requires_support() {
+    let (_alice_group, _alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+
+    let err = bob_group
+        .leave_group_via_self_remove(provider, &bob_signer)
+        .expect_err("SelfRemove is not supported by the group");
+    assert!(matches!(err, LeaveGroupError::SelfRemoveNotSupported));
+}
@@ -0,0 +1,79 @@
+use tls_codec::Deserialize;
+
+use crate::{
+    binary_tree::LeafNodeIndex,
+    framing::{
+        errors::TargetedMessageError, MlsMessageIn, ProcessedMessageContent, Sender, WireFormat,
+    },
+    group::{
+        errors::{CreateTargetedMessageError, ProcessMessageError, ValidationError},
+        mls_group::tests_and_kats::utils::setup_alice_bob_group,
+    },
+    treesync::LeafNodeParameters,
+};
+
+#[openmls_test::openmls_test]
+fn targeted_message() {
+    let (mut alice_group, alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+    let bob_index = bob_group.own_leaf_index();
+
+    alice_group.set_aad(b"targeted aad".to_vec());
+    let message = alice_group
+        .create_targeted_message(provider, &alice_signer, bob_index, b"Hi Bob")
+        .unwrap();
+    assert!(alice_group.aad().is_empty());
+
+    let message = MlsMessageIn::tls_deserialize_exact(message.to_bytes().unwrap()).unwrap();
+    assert_eq!(message.wire_format(), WireFormat::TargetedMessage);
+    let message = message.try_into_protocol_message().unwrap();
+
+    // Only Bob can process the message.
+    let err = alice_group
+        .process_message(provider, message.clone())
+        .expect_err("the message is addressed to Bob");
+    assert_eq!(
+        err,
+        ProcessMessageError::InvalidTargetedMessage(TargetedMessageError::WrongRecipient)
+    );
+
+    let processed_message = bob_group.process_message(provider, message).unwrap();
+    assert_eq!(
+        processed_message.sender(),
+        &Sender::Member(alice_group.own_leaf_index())
+    );
+    assert_eq!(processed_message.aad(), b"targeted aad");
+    match processed_message.into_content() {
+        ProcessedMessageContent::TargetedMessage(application_message) => {
+            assert_eq!(application_message.into_bytes(), b"Hi Bob")
+        }
+        _ => panic!("Expected a targeted message."),
+    }
+
+    // There is no member at an unused leaf index.
+    let err = alice_group
+        .create_targeted_message(provider, &alice_signer, LeafNodeIndex::new(5), b"Hi")
+        .expect_err("there is no member at leaf index 5");
+    assert_eq!(err, CreateTargetedMessageError::UnknownRecipient);
+
+    // Targeted messages can't be processed in another epoch.
+    let message = bob_group
+        .create_targeted_message(
+            provider,
+            &bob_signer,
+            alice_group.own_leaf_index(),
+            b"Hi Alice",
+        )
+        .unwrap();
+    alice_group
+        .self_update(provider, &alice_signer, LeafNodeParameters::default())
+        .unwrap();
+    alice_group.merge_pending_commit(provider).unwrap();
+    let err = alice_group
+        .process_message(provider, message.into_protocol_message().unwrap())
+        .expect_err("the message is from the previous epoch");
+    assert_eq!(
+        err,
+        ProcessMessageError::ValidationError(ValidationError::WrongEpoch)
+    );
+}
@@ -0,0 +1,150 @@
+use openmls_traits::OpenMlsProvider as _;
+use tls_codec::{TlsDeserializeBytes, TlsSerialize, TlsSize};
+
+use crate::{
+    credentials::test_utils::new_credential,
+    extensions::{
+        errors::TypedExtensionError, Extension, ExtensionContext, ExtensionRegistry, ExtensionType,
+        Extensions, TypedExtension,
+    },
+    group::{
+        errors::{ProcessMessageError, StageCommitError},
+        mls_group::tests_and_kats::utils::setup_alice_bob_group,
+    },
+    key_packages::{KeyPackage, KeyPackageIn},
+    treesync::node::leaf_node::Capabilities,
+    versions::ProtocolVersion,
+};
+
+const COLOR_EXTENSION_TYPE: u16 = 0xff01;
+
+/// A leaf node extension with the member's favorite color. The color `0` is
+/// invalid.
+#[derive(Debug, Clone, PartialEq, TlsSerialize, TlsDeserializeBytes, TlsSize)]
+struct ColorExtension {
+    color: u8,
+}
+
+impl TypedExtension for ColorExtension {
+    const EXTENSION_TYPE: u16 = COLOR_EXTENSION_TYPE;
+    const CONTEXTS: &'static [ExtensionContext] = &[ExtensionContext::LeafNode];
+
+    fn validate(&self) -> Result<(), TypedExtensionError> {
+        if self.color == 0 {
+            return Err(TypedExtensionError::Invalid);
+        }
+        Ok(())
+    }
+}
+
+fn color_registry() -> ExtensionRegistry {
+    let mut registry = ExtensionRegistry::new();
+    registry.register::<ColorExtension>().unwrap();
+    registry
+}
+
+#[test]
+fn typed_extension_registry() {
+    let mut registry = color_registry();
+    assert_eq!(
+        registry.register::<ColorExtension>(),
+        Err(TypedExtensionError::AlreadyRegistered)
+    );
+    assert!(registry.is_registered(ExtensionType::Unknown(COLOR_EXTENSION_TYPE)));
+
+    // Typed round trip
+    let extensions =
+        Extensions::single(Extension::from_typed(&ColorExtension { color: 7 }).unwrap());
+    assert_eq!(
+        extensions.typed::<ColorExtension>(),
+        Ok(Some(ColorExtension { color: 7 }))
+    );
+    assert_eq!(Extensions::empty().typed::<ColorExtension>(), Ok(None));
+
+    // Context and validation checks
+    assert_eq!(
+        registry.validate(&extensions, ExtensionContext::LeafNode),
+        Ok(())
+    );
+    assert_eq!(
+        registry.validate(&extensions, ExtensionContext::GroupContext),
+        Err(TypedExtensionError::IllegalContext)
+    );
+    let invalid = Extensions::single(Extension::from_typed(&ColorExtension { color: 0 }).unwrap());
+    assert_eq!(
+        registry.validate(&invalid, ExtensionContext::LeafNode),
+        Err(TypedExtensionError::Invalid)
+    );
+    let malformed = Extensions::single(Extension::Unknown(
+        COLOR_EXTENSION_TYPE,
+        crate::extensions::UnknownExtension(vec![1, 2]),
+    ));
+    assert_eq!(
+        registry.validate(&malformed, ExtensionContext::LeafNode),
+        Err(TypedExtensionError::Decoding)
+    );
+}
+
+#[openmls_test::openmls_test]
+fn invalid_registered_extension_is_rejected() {
+    let (mut alice_group, _alice_signer, mut bob_group, bob_signer, _bob_credential) =
+        setup_alice_bob_group(ciphersuite, provider);
+    alice_group.set_extension_registry(color_registry());
+
+    // Charlie's key package contains an invalid color.
+    let (charlie_credential_with_key, charlie_signer) =
+        new_credential(provider, b"Charlie", ciphersuite.signature_algorithm());
+    let charlie_key_package = KeyPackage::builder()
+        .leaf_node_capabilities(Capabilities::new(
+            None,
+            None,
+            Some(&[ExtensionType::Unknown(COLOR_EXTENSION_TYPE)]),
+            None,
+            None,
+        ))
+        .leaf_node_extensions(Extensions::single(
+            Extension::from_typed(&ColorExtension { color: 0 }).unwrap(),
+        ))
+        .build(
+            ciphersuite,
+            provider,
+            &charlie_signer,
+            charlie_credential_with_key,
+        )
+        .unwrap()
+        .key_package()
+        .clone();
+
+    let key_package_in = KeyPackageIn::from(charlie_key_package.clone());
+    assert!(key_package_in
+        .clone()
+        .validate(provider.crypto(), ProtocolVersion::Mls10)
+        .is_ok());
+    assert!(matches!(
+        key_package_in.validate_with_registry(
+            provider.crypto(),
+            ProtocolVersion::Mls10,
+            &color_registry()
+        ),
+        Err(
+            crate::key_packages::errors::KeyPackageVerifyError::InvalidRegisteredExtension(
+                TypedExtensionError::Invalid
+            )
+        )
+    ));
+
+    // Bob doesn't know about the extension and adds Charlie. Alice rejects
+    // the commit.
+    let (commit, _welcome, _group_info) = bob_group
+        .add_members(provider, &bob_signer, &[charlie_key_package])
+        .unwrap();
+    let err = alice_group
+        .process_message(provider, commit.into_protocol_message().unwrap())
+        .expect_err("the commit contains an invalid registered extension");
+    assert!(matches!(
+        err,
+        ProcessMessageError::InvalidCommit(StageCommitError::InvalidRegisteredExtension(
+            TypedExtensionError::Invalid
+        ))
+    ));
+}
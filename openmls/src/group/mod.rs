@@ -18,16 +18,20 @@ use crate::utils::*;
 // Crate
 pub(crate) mod errors;
 pub(crate) mod mls_group;
+pub(crate) mod proposal_policy;
 pub(crate) mod public_group;
 
 // Public
 pub use errors::*;
 pub use group_context::GroupContext;
+pub use mls_group::commit_conflict::*;
+pub use mls_group::commit_scheduler::*;
 pub use mls_group::config::*;
 pub use mls_group::membership::*;
 pub use mls_group::proposal_store::*;
 pub use mls_group::staged_commit::StagedCommit;
 pub use mls_group::{Member, *};
+pub use proposal_policy::{ProposalDecision, ProposalPolicy};
 pub use public_group::*;
 
 // Private
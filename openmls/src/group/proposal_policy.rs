@@ -0,0 +1,162 @@
+//! Proposal policies
+//!
+//! A [`ProposalPolicy`] allows the application to authorize proposals as part
+//! of the validation performed by [`MlsGroup`] and [`PublicGroup`]. This is
+//! the place to enforce rules such as "only administrators may add or remove
+//! members".
+//!
+//! The policy is consulted for every proposal a group processes: standalone
+//! proposals in [`MlsGroup::process_message()`], as well as proposals that
+//! are committed by reference or inline. A denied standalone proposal is
+//! rejected with [`ProcessMessageError::ProposalDenied`]. A commit that
+//! covers a denied proposal is rejected with
+//! [`ProposalValidationError::ProposalDenied`].
+//!
+//! Policies are not persisted. They have to be registered again after a
+//! group was loaded from storage.
+
+use std::{fmt::Debug, sync::Arc};
+
+use super::{GroupContext, PublicGroup};
+#[cfg(doc)]
+use crate::group::{errors::ProcessMessageError, MlsGroup};
+use crate::{
+    credentials::Credential,
+    framing::Sender,
+    group::{errors::ProposalValidationError, proposal_store::ProposalQueue},
+    messages::{proposals::Proposal, Commit},
+};
+
+/// The decision of a [`ProposalPolicy`] about a proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalDecision {
+    /// The proposal is allowed.
+    Allow,
+    /// The proposal is denied.
+    Deny,
+}
+
+/// An application-defined policy that decides whether a proposal is allowed
+/// in a group.
+///
+/// See the [module documentation](self) for when the policy is consulted.
+pub trait ProposalPolicy: Debug + Send + Sync {
+    /// Decides whether the `proposal` sent by `sender` is allowed in the
+    /// group with the given `group_context`.
+    ///
+    /// The `credential` is the credential of the sender: the credential of
+    /// the member's leaf for member senders, the credential in the
+    /// [`ExternalSendersExtension`](crate::extensions::ExternalSendersExtension)
+    /// for external senders, and the credential of the new member for
+    /// external joins. For inline proposals, the sender is the committer.
+    fn check(
+        &self,
+        group_context: &GroupContext,
+        sender: &Sender,
+        credential: &Credential,
+        proposal: &Proposal,
+    ) -> ProposalDecision;
+}
+
+/// Holds the [`ProposalPolicy`] of a group, if any.
+///
+/// The policy is not part of the group state, so two slots always compare
+/// as equal.
+#[derive(Clone, Default)]
+pub(crate) struct ProposalPolicySlot(Option<Arc<dyn ProposalPolicy>>);
+
+impl Debug for ProposalPolicySlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ProposalPolicySlot").field(&self.0).finish()
+    }
+}
+
+impl PartialEq for ProposalPolicySlot {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl PublicGroup {
+    /// Registers the [`ProposalPolicy`] that authorizes the proposals of this
+    /// group. This replaces any previously registered policy.
+    pub fn set_proposal_policy(&mut self, policy: impl ProposalPolicy + 'static) {
+        self.proposal_policy = ProposalPolicySlot(Some(Arc::new(policy)));
+    }
+
+    /// Removes the registered [`ProposalPolicy`], if any. All proposals are
+    /// allowed afterwards.
+    pub fn remove_proposal_policy(&mut self) {
+        self.proposal_policy = ProposalPolicySlot(None);
+    }
+
+    /// Returns `true` if the registered [`ProposalPolicy`] allows the
+    /// `proposal`, or if there is no policy.
+    pub(crate) fn proposal_allowed(
+        &self,
+        sender: &Sender,
+        credential: &Credential,
+        proposal: &Proposal,
+    ) -> bool {
+        match &self.proposal_policy.0 {
+            Some(policy) => {
+                policy.check(self.group_context(), sender, credential, proposal)
+                    == ProposalDecision::Allow
+            }
+            None => true,
+        }
+    }
+
+    /// Checks all proposals in the `proposal_queue` of the `commit` against
+    /// the registered [`ProposalPolicy`].
+    pub(crate) fn validate_proposal_policy(
+        &self,
+        proposal_queue: &ProposalQueue,
+        commit: &Commit,
+    ) -> Result<(), ProposalValidationError> {
+        if self.proposal_policy.0.is_none() {
+            return Ok(());
+        }
+
+        for queued_proposal in proposal_queue.queued_proposals() {
+            let sender = queued_proposal.sender();
+            let proposal = queued_proposal.proposal();
+            let credential = self
+                .sender_credential(sender, proposal, commit)
+                .ok_or(ProposalValidationError::UnknownMember)?;
+            if !self.proposal_allowed(sender, credential, proposal) {
+                return Err(ProposalValidationError::ProposalDenied);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the credential of the `sender` of a committed `proposal`.
+    fn sender_credential<'a>(
+        &'a self,
+        sender: &Sender,
+        proposal: &'a Proposal,
+        commit: &'a Commit,
+    ) -> Option<&'a Credential> {
+        match sender {
+            Sender::Member(leaf_index) => self.leaf(*leaf_index).map(|leaf| leaf.credential()),
+            Sender::External(sender_index) => self
+                .group_context()
+                .extensions()
+                .external_senders()?
+                .get(sender_index.index())
+                .map(|external_sender| external_sender.credential()),
+            Sender::NewMemberProposal => match proposal {
+                Proposal::Add(add_proposal) => {
+                    Some(add_proposal.key_package().leaf_node().credential())
+                }
+                _ => None,
+            },
+            Sender::NewMemberCommit => commit
+                .path
+                .as_ref()
+                .map(|path| path.leaf_node().credential()),
+        }
+    }
+}
@@ -57,11 +57,15 @@ impl TempBuilderPG1 {
         mut self,
         extensions: Extensions,
     ) -> Result<Self, InvalidExtensionError> {
-        // None of the default extensions are leaf node extensions, so only
-        // unknown extensions can be leaf node extensions.
-        let is_valid_in_leaf_node = extensions
-            .iter()
-            .all(|e| matches!(e.extension_type(), ExtensionType::Unknown(_)));
+        // The supported media types extension is the only known leaf node
+        // extension. Apart from that, only unknown extensions can be leaf node
+        // extensions.
+        let is_valid_in_leaf_node = extensions.iter().all(|e| {
+            matches!(
+                e.extension_type(),
+                ExtensionType::SupportedMediaTypes | ExtensionType::Unknown(_)
+            )
+        });
         if !is_valid_in_leaf_node {
             return Err(InvalidExtensionError::IllegalInLeafNodes);
         }
@@ -46,7 +46,7 @@ impl ApplyProposalsValues {
 ///
 /// If an `own_leaf_index` is provided, `self_removed` in the returned
 /// `ApplyProposalValues` is set to `true` if that leaf index is targeted by a
-/// Remove proposal.
+/// Remove proposal or sent a SelfRemove proposal.
 ///
 /// Returns an error if the proposals have not been validated before.
 impl<'a> PublicGroupDiff<'a> {
@@ -106,6 +106,16 @@ impl<'a> PublicGroupDiff<'a> {
             }
         }
 
+        // Process self removes
+        for removed in proposal_queue.self_remove_proposals() {
+            // Check if we removed ourselves from the group
+            if own_leaf_index == Some(removed) {
+                self_removed = true
+            }
+            // Blank the direct path of the sender
+            self.diff.blank_leaf(removed);
+        }
+
         // Process adds
         let add_proposals = proposal_queue
             .filtered_by_type(ProposalType::Add)
@@ -22,6 +22,7 @@ use self::{
     errors::CreationFromExternalError,
 };
 use super::{
+    proposal_policy::ProposalPolicySlot,
     proposal_store::{ProposalStore, QueuedProposal},
     GroupContext, GroupId, Member, StagedCommit,
 };
@@ -31,8 +32,8 @@ use crate::{
     binary_tree::{array_representation::TreeSize, LeafNodeIndex},
     ciphersuite::{hash_ref::ProposalRef, signable::Verifiable},
     error::LibraryError,
-    extensions::RequiredCapabilitiesExtension,
-    framing::InterimTranscriptHashInput,
+    extensions::{ExtensionRegistry, RequiredCapabilitiesExtension},
+    framing::{InterimTranscriptHashInput, Sender},
     messages::{
         group_info::{GroupInfo, VerifiableGroupInfo},
         proposals::{Proposal, ProposalOrRefType, ProposalType},
@@ -72,6 +73,10 @@ pub struct PublicGroup {
     interim_transcript_hash: Vec<u8>,
     // Most recent confirmation tag. Kept here for verification purposes.
     confirmation_tag: ConfirmationTag,
+    // The application's proposal policy. This is not persisted.
+    pub(crate) proposal_policy: ProposalPolicySlot,
+    // The application's registered extensions. This is not persisted.
+    extension_registry: ExtensionRegistry,
 }
 
 /// This is a wrapper type, because we can't implement the storage traits on `Vec<u8>`.
@@ -103,6 +108,8 @@ impl PublicGroup {
             group_context,
             interim_transcript_hash,
             confirmation_tag: initial_confirmation_tag,
+            proposal_policy: ProposalPolicySlot::default(),
+            extension_registry: ExtensionRegistry::default(),
         })
     }
 
@@ -173,6 +180,8 @@ impl PublicGroup {
             interim_transcript_hash,
             confirmation_tag: group_info.confirmation_tag().clone(),
             proposal_store,
+            proposal_policy: ProposalPolicySlot::default(),
+            extension_registry: ExtensionRegistry::default(),
         };
 
         public_group
@@ -319,6 +328,37 @@ impl PublicGroup {
     ) -> Result<Vec<(ProposalRef, QueuedProposal)>, Storage::PublicError> {
         storage.queued_proposals(self.group_id())
     }
+
+    /// Sets the [`ExtensionRegistry`] that is used to validate the key
+    /// packages, leaf nodes and group context extensions of incoming commits.
+    /// This replaces any previously set registry.
+    pub fn set_extension_registry(&mut self, extension_registry: ExtensionRegistry) {
+        self.extension_registry = extension_registry;
+    }
+
+    /// Returns the [`ExtensionRegistry`] of the group.
+    pub fn extension_registry(&self) -> &ExtensionRegistry {
+        &self.extension_registry
+    }
+
+    /// Returns the [`ProposalRef`]s of all pending SelfRemove proposals
+    /// together with the leaf index of the member that wants to leave the
+    /// group.
+    ///
+    /// A delivery service can use this to trigger a Commit that covers these
+    /// proposals, e.g. by asking one of the remaining members to commit.
+    pub fn pending_self_removes(&self) -> impl Iterator<Item = (ProposalRef, LeafNodeIndex)> + '_ {
+        self.proposal_store
+            .proposals()
+            .filter_map(|queued_proposal| {
+                match (queued_proposal.proposal(), queued_proposal.sender()) {
+                    (Proposal::SelfRemove, Sender::Member(leaf_index)) => {
+                        Some((queued_proposal.proposal_reference(), *leaf_index))
+                    }
+                    _ => None,
+                }
+            })
+    }
 }
 
 // Getters
@@ -364,6 +404,14 @@ impl PublicGroup {
         self.treesync().leaf(leaf_index)
     }
 
+    /// Returns `true` if all members of the group support the given proposal
+    /// type in their capabilities.
+    pub(crate) fn members_support_proposal_type(&self, proposal_type: ProposalType) -> bool {
+        self.treesync()
+            .full_leaves()
+            .all(|leaf| leaf.capabilities().proposals().contains(&proposal_type))
+    }
+
     /// Returns the tree size
     pub(crate) fn tree_size(&self) -> TreeSize {
         self.treesync().tree_size()
@@ -435,6 +483,8 @@ impl PublicGroup {
                 group_context: group_context?,
                 interim_transcript_hash: interim_transcript_hash?.0,
                 confirmation_tag: confirmation_tag?,
+                proposal_policy: ProposalPolicySlot::default(),
+                extension_registry: ExtensionRegistry::default(),
             })
         };
 
@@ -16,7 +16,6 @@ use crate::{
         errors::ValidationError, mls_group::errors::ProcessMessageError,
         past_secrets::MessageSecretsStore, proposal_store::QueuedProposal,
     },
-    messages::proposals::Proposal,
 };
 
 use super::PublicGroup;
@@ -142,7 +141,7 @@ impl PublicGroup {
         self.validate_framing(&protocol_message)?;
 
         let decrypted_message = match protocol_message {
-            ProtocolMessage::PrivateMessage(_) => {
+            ProtocolMessage::PrivateMessage(_) | ProtocolMessage::TargetedMessage(_) => {
                 return Err(ProcessMessageError::IncompatibleWireFormat)
             }
             ProtocolMessage::PublicMessage(public_message) => {
@@ -214,7 +213,10 @@ impl PublicGroup {
                             application_message.as_slice().to_owned(),
                         ))
                     }
-                    FramedContentBody::Proposal(_) => {
+                    FramedContentBody::Proposal(proposal) => {
+                        if !self.proposal_allowed(&sender, &credential, proposal) {
+                            return Err(ProcessMessageError::ProposalDenied);
+                        }
                         let proposal = Box::new(QueuedProposal::from_authenticated_content_by_ref(
                             self.ciphersuite(),
                             crypto,
@@ -248,7 +250,12 @@ impl PublicGroup {
                     FramedContentBody::Application(_) => {
                         Err(ProcessMessageError::UnauthorizedExternalApplicationMessage)
                     }
-                    FramedContentBody::Proposal(Proposal::Remove(_)) => {
+                    FramedContentBody::Proposal(proposal)
+                        if proposal.is_valid_for_external_sender() =>
+                    {
+                        if !self.proposal_allowed(&sender, &credential, proposal) {
+                            return Err(ProcessMessageError::ProposalDenied);
+                        }
                         let content = ProcessedMessageContent::ProposalMessage(Box::new(
                             QueuedProposal::from_authenticated_content_by_ref(
                                 self.ciphersuite(),
@@ -265,7 +272,8 @@ impl PublicGroup {
                             credential,
                         ))
                     }
-                    // TODO #151/#106
+                    // Update and ExternalInit proposals can't be sent by external
+                    // senders.
                     FramedContentBody::Proposal(_) => {
                         Err(ProcessMessageError::UnsupportedProposalType)
                     }
@@ -109,6 +109,15 @@ impl PublicGroup {
         // ValSem107
         // ValSem108
         self.validate_remove_proposals(&proposal_queue)?;
+        // Only admins may add, remove and change the group context extensions
+        // if the group has an admins extension.
+        self.validate_admin_proposals(&proposal_queue)?;
+        // All members must support the media types the group requires.
+        self.validate_media_types(
+            &proposal_queue,
+            sender,
+            commit.path().as_ref().map(|path| path.leaf_node()),
+        )?;
         // ValSem113: All Proposals: The proposal type must be supported by all
         // members of the group
         self.validate_proposal_type_support(&proposal_queue)?;
@@ -119,6 +128,10 @@ impl PublicGroup {
         // ValSem402
         // ValSem403
         self.validate_pre_shared_key_proposals(&proposal_queue)?;
+        // Application-defined proposal authorization
+        self.validate_proposal_policy(&proposal_queue, commit)?;
+        // Application-defined extensions
+        self.validate_registered_extensions(&proposal_queue, commit)?;
 
         match sender {
             Sender::Member(leaf_index) => {
@@ -69,7 +69,9 @@ fn public_group<Provider: OpenMlsProvider>(ciphersuite: Ciphersuite, provider: &
         .expect("error merging pending commit");
 
     let public_message = match message.into_protocol_message().unwrap() {
-        ProtocolMessage::PrivateMessage(_) => panic!("Unexpected message type."),
+        ProtocolMessage::PrivateMessage(_) | ProtocolMessage::TargetedMessage(_) => {
+            panic!("Unexpected message type.")
+        }
         ProtocolMessage::PublicMessage(public_message) => public_message,
     };
     let processed_message = public_group
@@ -80,7 +82,8 @@ fn public_group<Provider: OpenMlsProvider>(ciphersuite: Ciphersuite, provider: &
     match processed_message.into_content() {
         ProcessedMessageContent::ApplicationMessage(_)
         | ProcessedMessageContent::ProposalMessage(_)
-        | ProcessedMessageContent::ExternalJoinProposalMessage(_) => {
+        | ProcessedMessageContent::ExternalJoinProposalMessage(_)
+        | ProcessedMessageContent::TargetedMessage(_) => {
             panic!("Unexpected message type.")
         }
         ProcessedMessageContent::StagedCommitMessage(staged_commit) => {
@@ -185,7 +188,8 @@ fn public_group<Provider: OpenMlsProvider>(ciphersuite: Ciphersuite, provider: &
     match ppm.into_content() {
         ProcessedMessageContent::ApplicationMessage(_)
         | ProcessedMessageContent::ExternalJoinProposalMessage(_)
-        | ProcessedMessageContent::StagedCommitMessage(_) => panic!("Unexpected message type."),
+        | ProcessedMessageContent::StagedCommitMessage(_)
+        | ProcessedMessageContent::TargetedMessage(_) => panic!("Unexpected message type."),
         ProcessedMessageContent::ProposalMessage(p) => {
             match p.proposal() {
                 Proposal::Remove(r) => assert_eq!(r.removed(), LeafNodeIndex::new(1)),
@@ -294,7 +298,9 @@ fn public_group<Provider: OpenMlsProvider>(ciphersuite: Ciphersuite, provider: &
 // A helper function
 fn into_public_message(message: MlsMessageOut) -> PublicMessageIn {
     match message.into_protocol_message().unwrap() {
-        ProtocolMessage::PrivateMessage(_) => panic!("Unexpected message type."),
+        ProtocolMessage::PrivateMessage(_) | ProtocolMessage::TargetedMessage(_) => {
+            panic!("Unexpected message type.")
+        }
         ProtocolMessage::PublicMessage(public_message) => public_message,
     }
 }
@@ -303,7 +309,8 @@ fn extract_staged_commit(ppm: ProcessedMessage) -> StagedCommit {
     match ppm.into_content() {
         ProcessedMessageContent::ApplicationMessage(_)
         | ProcessedMessageContent::ProposalMessage(_)
-        | ProcessedMessageContent::ExternalJoinProposalMessage(_) => {
+        | ProcessedMessageContent::ExternalJoinProposalMessage(_)
+        | ProcessedMessageContent::TargetedMessage(_) => {
             panic!("Unexpected message type.")
         }
         ProcessedMessageContent::StagedCommitMessage(staged_content) => *staged_content,
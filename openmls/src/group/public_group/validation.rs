@@ -6,7 +6,9 @@ use std::collections::{BTreeSet, HashSet};
 use openmls_traits::types::VerifiableCiphersuite;
 
 use super::PublicGroup;
-use crate::extensions::RequiredCapabilitiesExtension;
+use crate::extensions::{
+    errors::TypedExtensionError, ExtensionContext, RequiredCapabilitiesExtension,
+};
 use crate::group::proposal_store::ProposalQueue;
 use crate::group::GroupContextExtensionsProposalValidationError;
 use crate::prelude::LibraryError;
@@ -27,6 +29,7 @@ use crate::{
         Commit,
     },
     schedule::errors::PskError,
+    treesync::LeafNode,
 };
 
 impl PublicGroup {
@@ -149,8 +152,10 @@ impl PublicGroup {
         // Check that the types of all proposals are supported by all members
         for proposal in proposal_queue.queued_proposals() {
             let proposal_type = proposal.proposal().proposal_type();
-            if matches!(proposal_type, ProposalType::Custom(_))
-                && !capabilities_intersection.contains(&proposal_type)
+            if matches!(
+                proposal_type,
+                ProposalType::Custom(_) | ProposalType::SelfRemove
+            ) && !capabilities_intersection.contains(&proposal_type)
             {
                 return Err(ProposalValidationError::UnsupportedProposalType);
             }
@@ -430,6 +435,61 @@ impl PublicGroup {
             }
         }
 
+        // SelfRemove proposals remove their sender, which must be a member.
+        for queued_proposal in proposal_queue.filtered_by_type(ProposalType::SelfRemove) {
+            let Sender::Member(removed) = *queued_proposal.sender() else {
+                return Err(ProposalValidationError::SelfRemoveFromNonMember);
+            };
+            // ValSem107
+            if !removes_set.insert(removed) {
+                return Err(ProposalValidationError::DuplicateMemberRemoval);
+            }
+
+            if self.treesync().leaf(removed).is_none() {
+                return Err(ProposalValidationError::UnknownMember);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate proposals against the [`AdminsExtension`] of the group, if
+    /// there is one. Add, Remove and GroupContextExtensions proposals sent by
+    /// a member must be sent by an admin, unless the member removes itself.
+    ///
+    /// [`AdminsExtension`]: crate::extensions::AdminsExtension
+    pub(crate) fn validate_admin_proposals(
+        &self,
+        proposal_queue: &ProposalQueue,
+    ) -> Result<(), ProposalValidationError> {
+        let Some(admins) = self.group_context().extensions().admins() else {
+            return Ok(());
+        };
+
+        for queued_proposal in proposal_queue.queued_proposals() {
+            // Only members are restricted. External senders are authorized
+            // by the group context already.
+            let Sender::Member(sender) = queued_proposal.sender() else {
+                continue;
+            };
+            let requires_admin = match queued_proposal.proposal() {
+                Proposal::Add(_) | Proposal::GroupContextExtensions(_) => true,
+                Proposal::Remove(remove_proposal) => remove_proposal.removed() != *sender,
+                _ => false,
+            };
+            if !requires_admin {
+                continue;
+            }
+
+            let credential = self
+                .leaf(*sender)
+                .map(|leaf| leaf.credential())
+                .ok_or(ProposalValidationError::UnknownMember)?;
+            if !admins.is_admin(credential) {
+                return Err(ProposalValidationError::NotAnAdmin);
+            }
+        }
+
         Ok(())
     }
 
@@ -603,6 +663,122 @@ impl PublicGroup {
         Ok(())
     }
 
+    /// Validates that all members support the media types that are required
+    /// by the group's [`RequiredMediaTypesExtension`] after the commit:
+    ///  - New and updated leaf nodes, including the committer's
+    ///    `path_leaf_node`, must support the required media types.
+    ///  - If a GroupContextExtensions proposal changes the required media
+    ///    types, all members that keep their leaf node must support them.
+    ///
+    /// [`RequiredMediaTypesExtension`]: crate::extensions::RequiredMediaTypesExtension
+    pub(crate) fn validate_media_types(
+        &self,
+        proposal_queue: &ProposalQueue,
+        committer: &Sender,
+        path_leaf_node: Option<&LeafNode>,
+    ) -> Result<(), ProposalValidationError> {
+        let new_extensions = proposal_queue
+            .filtered_by_type(ProposalType::GroupContextExtensions)
+            .find_map(|queued_proposal| match queued_proposal.proposal() {
+                Proposal::GroupContextExtensions(proposal) => Some(proposal.extensions()),
+                _ => None,
+            });
+        let Some(required_media_types) = new_extensions
+            .unwrap_or(self.group_context().extensions())
+            .required_media_types()
+        else {
+            return Ok(());
+        };
+        let supports_required_media_types =
+            |leaf_node: &LeafNode| required_media_types.is_supported_by(leaf_node.extensions());
+
+        let new_leaf_nodes = proposal_queue
+            .queued_proposals()
+            .filter_map(|queued_proposal| match queued_proposal.proposal() {
+                Proposal::Add(add_proposal) => Some(add_proposal.key_package().leaf_node()),
+                Proposal::Update(update_proposal) => Some(update_proposal.leaf_node()),
+                _ => None,
+            })
+            .chain(path_leaf_node);
+        for leaf_node in new_leaf_nodes {
+            if !supports_required_media_types(leaf_node) {
+                return Err(ProposalValidationError::UnsupportedMediaTypes);
+            }
+        }
+
+        // If the requirements didn't change, the remaining members were
+        // checked when they joined or last updated their leaf node.
+        if new_extensions.is_none() {
+            return Ok(());
+        }
+        let mut replaced_leaves: HashSet<LeafNodeIndex> = proposal_queue
+            .remove_proposals()
+            .map(|remove_proposal| remove_proposal.remove_proposal().removed())
+            .chain(
+                proposal_queue
+                    .queued_proposals()
+                    .filter(|queued_proposal| {
+                        matches!(
+                            queued_proposal.proposal(),
+                            Proposal::Update(_) | Proposal::SelfRemove
+                        )
+                    })
+                    .filter_map(|queued_proposal| match queued_proposal.sender() {
+                        Sender::Member(leaf_index) => Some(*leaf_index),
+                        _ => None,
+                    }),
+            )
+            .collect();
+        if let (Sender::Member(leaf_index), Some(_)) = (committer, path_leaf_node) {
+            replaced_leaves.insert(*leaf_index);
+        }
+        for member in self.treesync().full_leave_members() {
+            if replaced_leaves.contains(&member.index) {
+                continue;
+            }
+            if !self
+                .leaf(member.index)
+                .is_some_and(supports_required_media_types)
+            {
+                return Err(ProposalValidationError::UnsupportedMediaTypes);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates the extensions of the key packages and leaf nodes in the
+    /// `commit` and the new group context extensions against the group's
+    /// [`ExtensionRegistry`](crate::extensions::ExtensionRegistry).
+    pub(crate) fn validate_registered_extensions(
+        &self,
+        proposal_queue: &ProposalQueue,
+        commit: &Commit,
+    ) -> Result<(), TypedExtensionError> {
+        let registry = &self.extension_registry;
+        if registry.is_empty() {
+            return Ok(());
+        }
+
+        for add_proposal in proposal_queue.add_proposals() {
+            registry.validate_key_package(add_proposal.add_proposal().key_package())?;
+        }
+        for update_proposal in proposal_queue.update_proposals() {
+            registry.validate_leaf_node(update_proposal.update_proposal().leaf_node())?;
+        }
+        for queued_proposal in proposal_queue.filtered_by_type(ProposalType::GroupContextExtensions)
+        {
+            if let Proposal::GroupContextExtensions(proposal) = queued_proposal.proposal() {
+                registry.validate(proposal.extensions(), ExtensionContext::GroupContext)?;
+            }
+        }
+        if let Some(path) = commit.path() {
+            registry.validate_leaf_node(path.leaf_node())?;
+        }
+
+        Ok(())
+    }
+
     /// Returns a [`LeafNodeValidationError`] if an [`ExtensionType`]
     /// in `extensions` is not supported by a leaf in this tree.
     pub(crate) fn check_extension_support(
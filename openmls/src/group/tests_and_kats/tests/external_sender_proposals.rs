@@ -0,0 +1,161 @@
+use openmls_test::openmls_test;
+
+use crate::{framing::*, group::*, messages::external_proposals::*};
+
+use openmls_traits::types::Ciphersuite;
+
+use crate::group::tests_and_kats::utils::*;
+
+// Creates a group in which the delivery service is the only external sender
+fn new_test_group(
+    ciphersuite: Ciphersuite,
+    provider: &impl crate::storage::OpenMlsProvider,
+    ds_credential_with_key: &CredentialWithKeyAndSigner,
+) -> (MlsGroup, CredentialWithKeyAndSigner) {
+    let alice_credential_with_key =
+        generate_credential_with_key("Alice".into(), ciphersuite.signature_algorithm(), provider);
+
+    let external_senders = vec![ExternalSender::new(
+        ds_credential_with_key
+            .credential_with_key
+            .signature_key
+            .clone(),
+        ds_credential_with_key
+            .credential_with_key
+            .credential
+            .clone(),
+    )];
+    let mls_group_config = MlsGroupCreateConfig::builder()
+        .wire_format_policy(PURE_PLAINTEXT_WIRE_FORMAT_POLICY)
+        .ciphersuite(ciphersuite)
+        .with_group_context_extensions(Extensions::single(Extension::ExternalSenders(
+            external_senders,
+        )))
+        .unwrap()
+        .build();
+
+    let alice_group = MlsGroup::new_with_group_id(
+        provider,
+        &alice_credential_with_key.signer,
+        &mls_group_config,
+        GroupId::from_slice(b"Test Group"),
+        alice_credential_with_key.credential_with_key.clone(),
+    )
+    .unwrap();
+
+    (alice_group, alice_credential_with_key)
+}
+
+// Processes and stores an external proposal, then commits it
+fn commit_external_proposal(
+    provider: &impl crate::storage::OpenMlsProvider,
+    group: &mut MlsGroup,
+    signer: &CredentialWithKeyAndSigner,
+    proposal: MlsMessageOut,
+) {
+    let proposal: MlsMessageIn = proposal.into();
+    let processed_message = group
+        .process_message(provider, proposal.try_into_protocol_message().unwrap())
+        .unwrap();
+    assert!(matches!(processed_message.sender(), Sender::External(_)));
+    let ProcessedMessageContent::ProposalMessage(proposal) = processed_message.into_content()
+    else {
+        panic!("Not a proposal");
+    };
+    group
+        .store_pending_proposal(provider.storage(), *proposal)
+        .unwrap();
+    group
+        .commit_to_pending_proposals(provider, &signer.signer)
+        .unwrap();
+    group.merge_pending_commit(provider).unwrap();
+}
+
+#[openmls_test]
+fn external_add_proposal_should_add_member() {
+    let ds_credential_with_key = generate_credential_with_key(
+        "delivery-service".into(),
+        ciphersuite.signature_algorithm(),
+        provider,
+    );
+    let (mut alice_group, alice_credential) =
+        new_test_group(ciphersuite, provider, &ds_credential_with_key);
+
+    let bob_credential_with_key =
+        generate_credential_with_key("Bob".into(), ciphersuite.signature_algorithm(), provider);
+    let bob_key_package = generate_key_package(
+        ciphersuite,
+        Extensions::empty(),
+        provider,
+        bob_credential_with_key,
+    );
+
+    let proposal = ExternalProposal::new_add::<Provider>(
+        bob_key_package.key_package().clone(),
+        alice_group.group_id().clone(),
+        alice_group.epoch(),
+        &ds_credential_with_key.signer,
+        SenderExtensionIndex::new(0),
+    )
+    .unwrap();
+    commit_external_proposal(provider, &mut alice_group, &alice_credential, proposal);
+
+    assert_eq!(alice_group.members().count(), 2);
+}
+
+#[openmls_test]
+fn external_group_context_extensions_proposal_should_update_context() {
+    let ds_credential_with_key = generate_credential_with_key(
+        "delivery-service".into(),
+        ciphersuite.signature_algorithm(),
+        provider,
+    );
+    let (mut alice_group, alice_credential) =
+        new_test_group(ciphersuite, provider, &ds_credential_with_key);
+
+    let mut extensions = alice_group.context().extensions().clone();
+    extensions.add_or_replace(Extension::RequiredCapabilities(
+        RequiredCapabilitiesExtension::new(&[], &[], &[]),
+    ));
+    let proposal = ExternalProposal::new_group_context_extensions::<Provider>(
+        extensions.clone(),
+        alice_group.group_id().clone(),
+        alice_group.epoch(),
+        &ds_credential_with_key.signer,
+        SenderExtensionIndex::new(0),
+    )
+    .unwrap();
+    commit_external_proposal(provider, &mut alice_group, &alice_credential, proposal);
+
+    assert_eq!(alice_group.context().extensions(), &extensions);
+}
+
+#[openmls_test]
+fn external_proposal_should_fail_when_invalid_external_senders_index() {
+    let ds_credential_with_key = generate_credential_with_key(
+        "delivery-service".into(),
+        ciphersuite.signature_algorithm(),
+        provider,
+    );
+    let (mut alice_group, _alice_credential) =
+        new_test_group(ciphersuite, provider, &ds_credential_with_key);
+
+    let proposal: MlsMessageIn = ExternalProposal::new_group_context_extensions::<Provider>(
+        Extensions::empty(),
+        alice_group.group_id().clone(),
+        alice_group.epoch(),
+        &ds_credential_with_key.signer,
+        // There is only one external sender
+        SenderExtensionIndex::new(1),
+    )
+    .unwrap()
+    .into();
+
+    let error = alice_group
+        .process_message(provider, proposal.try_into_protocol_message().unwrap())
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ProcessMessageError::ValidationError(ValidationError::UnauthorizedExternalSender)
+    );
+}
@@ -7,6 +7,7 @@ mod external_add_proposal;
 mod external_commit;
 mod external_commit_validation;
 mod external_remove_proposal;
+mod external_sender_proposals;
 mod framing;
 mod framing_validation;
 mod group;
@@ -2,9 +2,13 @@
 //!
 //! `KeyPackageError` are thrown on errors handling `KeyPackage`s.
 
+use openmls_traits::types::CryptoError;
 use thiserror::Error;
 
-use crate::{ciphersuite::signable::SignatureError, error::LibraryError};
+use crate::{
+    ciphersuite::signable::SignatureError, error::LibraryError,
+    extensions::errors::TypedExtensionError, treesync::errors::LeafNodeValidationError,
+};
 
 /// KeyPackage verify error
 #[derive(Error, Debug, PartialEq, Clone)]
@@ -36,6 +40,10 @@ pub enum KeyPackageVerifyError {
     /// The protocol version is not valid.
     #[error("The protocol version is not valid.")]
     InvalidProtocolVersion,
+    /// An extension that is registered in the
+    /// [`ExtensionRegistry`](crate::extensions::ExtensionRegistry) is invalid.
+    #[error(transparent)]
+    InvalidRegisteredExtension(#[from] TypedExtensionError),
 }
 
 /// KeyPackage extension support error
@@ -61,4 +69,25 @@ pub enum KeyPackageNewError {
     /// See [`SignatureError`] for more details.
     #[error(transparent)]
     SignatureError(#[from] SignatureError),
+    /// The leaf node capabilities don't support everything the group
+    /// requires. See [`LeafNodeValidationError`] for more details.
+    #[error(transparent)]
+    LeafNodeValidation(#[from] LeafNodeValidationError),
+    /// The key handle provider failed to generate the init key.
+    #[error("The key handle provider failed to generate the init key: {0}")]
+    KeyHandleError(CryptoError),
+}
+
+/// Key package manager error
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum KeyPackageManagerError<StorageError> {
+    /// See [`LibraryError`] for more details.
+    #[error(transparent)]
+    LibraryError(#[from] LibraryError),
+    /// See [`KeyPackageNewError`] for more details.
+    #[error(transparent)]
+    KeyPackageNewError(#[from] KeyPackageNewError),
+    /// Error accessing storage.
+    #[error("Error accessing storage")]
+    StorageError(StorageError),
 }
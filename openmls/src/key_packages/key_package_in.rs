@@ -4,8 +4,11 @@
 use crate::{
     ciphersuite::{signable::*, *},
     credentials::*,
-    extensions::Extensions,
-    treesync::node::leaf_node::{LeafNodeIn, VerifiableLeafNode},
+    extensions::{ExtensionRegistry, ExtensionType, Extensions},
+    treesync::node::leaf_node::{
+        LeafNodeIn, LeafNodeSource, LeafNodeValidationReport, ValidationCheck,
+        ValidationCheckResult, ValidationChecks, ValidationOutcome, VerifiableLeafNode,
+    },
     versions::ProtocolVersion,
 };
 use openmls_traits::{crypto::OpenMlsCrypto, types::Ciphersuite};
@@ -201,6 +204,115 @@ impl KeyPackageIn {
         Ok(key_package)
     }
 
+    /// Verify that this key package is valid as described in
+    /// [`KeyPackageIn::validate()`] and that the extensions registered in
+    /// `extension_registry` are valid.
+    pub fn validate_with_registry(
+        self,
+        crypto: &impl OpenMlsCrypto,
+        protocol_version: ProtocolVersion,
+        extension_registry: &ExtensionRegistry,
+    ) -> Result<KeyPackage, KeyPackageVerifyError> {
+        let key_package = self.validate(crypto, protocol_version)?;
+        extension_registry.validate_key_package(&key_package)?;
+
+        Ok(key_package)
+    }
+
+    /// Validates this key package without a group and reports the outcome of
+    /// every check:
+    ///
+    /// * the protocol version is the `protocol_version`
+    /// * the leaf node was created for a key package
+    /// * the signature on the key package is valid
+    /// * the init key and the encryption key are different
+    /// * all key package extensions are supported by the leaf node
+    /// * the last-resort extension, if present, is supported by the leaf node
+    ///
+    /// The report also contains the [`LeafNodeValidationReport`] of the leaf
+    /// node, see [`LeafNodeIn::validation_report()`]. The signature on the key
+    /// package is only verified if the signature on the leaf node is valid.
+    ///
+    /// Unlike [`KeyPackageIn::validate()`], this doesn't stop at the first
+    /// failed check.
+    pub fn validation_report(
+        &self,
+        crypto: &impl OpenMlsCrypto,
+        protocol_version: ProtocolVersion,
+    ) -> KeyPackageValidationReport {
+        let mut checks = ValidationChecks::default();
+        let payload = self.payload.clone();
+        let ciphersuite = payload.ciphersuite;
+
+        checks.record_passed(
+            ValidationCheck::ProtocolVersion,
+            self.version_is_supported(protocol_version),
+        );
+        checks.record_passed(
+            ValidationCheck::LeafNodeSource,
+            matches!(
+                payload.leaf_node.leaf_node_source(),
+                LeafNodeSource::KeyPackage(_)
+            ),
+        );
+
+        let leaf_node_in = payload.leaf_node.clone();
+        let (leaf_node_report, leaf_node) =
+            payload
+                .leaf_node
+                .validate_without_group(crypto, ciphersuite, None);
+        match leaf_node {
+            Some(leaf_node) => {
+                let signature_key = OpenMlsSignaturePublicKey::from_signature_key(
+                    leaf_node.signature_key().clone(),
+                    ciphersuite.signature_algorithm(),
+                );
+                let key_package_tbs = KeyPackageTbs {
+                    protocol_version: payload.protocol_version,
+                    ciphersuite,
+                    init_key: payload.init_key.clone(),
+                    leaf_node,
+                    extensions: payload.extensions.clone(),
+                };
+                let verified = VerifiableKeyPackage::new(key_package_tbs, self.signature.clone())
+                    .verify_no_out(crypto, &signature_key)
+                    .is_ok();
+                checks.record_passed(ValidationCheck::KeyPackageSignature, verified);
+            }
+            None => checks.record(
+                ValidationCheck::KeyPackageSignature,
+                ValidationOutcome::Skipped,
+            ),
+        }
+
+        checks.record_passed(
+            ValidationCheck::InitKeyDiffersFromEncryptionKey,
+            leaf_node_in.encryption_key().key() != payload.init_key.key(),
+        );
+        checks.record_passed(
+            ValidationCheck::KeyPackageExtensionSupport,
+            payload
+                .extensions
+                .iter()
+                .all(|extension| leaf_node_in.supports_extension(&extension.extension_type())),
+        );
+        let last_resort = payload.extensions.contains(ExtensionType::LastResort);
+        checks.record_passed(
+            ValidationCheck::LastResort,
+            !last_resort || leaf_node_in.supports_extension(&ExtensionType::LastResort),
+        );
+
+        KeyPackageValidationReport {
+            protocol_version: payload.protocol_version,
+            ciphersuite,
+            init_key: payload.init_key,
+            extensions: payload.extensions,
+            last_resort,
+            leaf_node: leaf_node_report,
+            checks,
+        }
+    }
+
     /// Returns true if the protocol version is supported by this key package and
     /// false otherwise.
     pub(crate) fn version_is_supported(&self, protocol_version: ProtocolVersion) -> bool {
@@ -208,6 +320,77 @@ impl KeyPackageIn {
     }
 }
 
+/// The report of the validation of a [`KeyPackageIn`] without a group. See
+/// [`KeyPackageIn::validation_report()`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyPackageValidationReport {
+    protocol_version: ProtocolVersion,
+    ciphersuite: Ciphersuite,
+    init_key: InitKey,
+    extensions: Extensions,
+    last_resort: bool,
+    leaf_node: LeafNodeValidationReport,
+    checks: ValidationChecks,
+}
+
+impl KeyPackageValidationReport {
+    /// Returns the protocol version of the key package.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Returns the ciphersuite of the key package.
+    pub fn ciphersuite(&self) -> Ciphersuite {
+        self.ciphersuite
+    }
+
+    /// Returns the init key of the key package.
+    pub fn init_key(&self) -> &InitKey {
+        &self.init_key
+    }
+
+    /// Returns the extensions of the key package.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// Returns `true` if this is a last-resort key package.
+    pub fn last_resort(&self) -> bool {
+        self.last_resort
+    }
+
+    /// Returns the report of the validation of the leaf node. It also
+    /// contains the credential, the lifetime and the capabilities.
+    pub fn leaf_node(&self) -> &LeafNodeValidationReport {
+        &self.leaf_node
+    }
+
+    /// Returns the checks of the key package that were performed or skipped,
+    /// in the order they were performed in. The checks of the leaf node are
+    /// part of the [`KeyPackageValidationReport::leaf_node()`] report.
+    pub fn checks(&self) -> &[ValidationCheckResult] {
+        self.checks.as_slice()
+    }
+
+    /// Returns the outcome of the `check` of the key package or its leaf
+    /// node, or `None` if it is not part of this report.
+    pub fn outcome(&self, check: ValidationCheck) -> Option<ValidationOutcome> {
+        self.checks
+            .outcome(check)
+            .or_else(|| self.leaf_node.outcome(check))
+    }
+
+    /// Returns the checks of the key package and its leaf node that failed.
+    pub fn failed_checks(&self) -> impl Iterator<Item = ValidationCheck> + '_ {
+        self.checks.failed().chain(self.leaf_node.failed_checks())
+    }
+
+    /// Returns `true` if no check of the key package or its leaf node failed.
+    pub fn is_valid(&self) -> bool {
+        self.failed_checks().next().is_none()
+    }
+}
+
 #[cfg(any(feature = "test-utils", test))]
 impl From<KeyPackageTbsIn> for KeyPackageTbs {
     fn from(value: KeyPackageTbsIn) -> Self {
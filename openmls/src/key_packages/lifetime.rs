@@ -87,6 +87,23 @@ impl Lifetime {
         }
     }
 
+    /// Returns the time (in seconds since the Unix epoch) before which the
+    /// key package must not be used.
+    pub fn not_before(&self) -> u64 {
+        self.not_before
+    }
+
+    /// Returns the time (in seconds since the Unix epoch) after which the key
+    /// package must not be used.
+    pub fn not_after(&self) -> u64 {
+        self.not_after
+    }
+
+    /// Returns true if this lifetime has ended.
+    pub(crate) fn is_expired(&self) -> bool {
+        unix_time_now() >= self.not_after
+    }
+
     /// ValSem(openmls/annotations#32):
     /// Applications MUST define a maximum total lifetime that is acceptable for a LeafNode,
     /// and reject any LeafNode where the total lifetime is longer than this duration.
@@ -95,6 +112,17 @@ impl Lifetime {
     }
 }
 
+/// Returns the current time in seconds since the Unix epoch.
+pub(crate) fn unix_time_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_else(|_| {
+            log::error!("SystemTime before UNIX EPOCH.");
+            0
+        })
+}
+
 impl Default for Lifetime {
     fn default() -> Self {
         Lifetime::new(DEFAULT_KEY_PACKAGE_LIFETIME_SECONDS)
@@ -0,0 +1,512 @@
+//! # Key package manager
+//!
+//! Key packages are consumed when a client is added to a group, so clients
+//! have to keep enough of them published at the delivery service. The
+//! [`KeyPackageManager`] keeps track of the key packages it created in the
+//! storage provider and
+//!
+//! - generates a number of regular key packages and one last-resort key
+//!   package per ciphersuite,
+//! - tracks which key packages were consumed by a [`Welcome`],
+//! - refills the regular key packages when fewer than a threshold are left,
+//! - rotates the last-resort key package on a schedule, and
+//! - purges key packages whose [`Lifetime`] has ended.
+//!
+//! Every operation returns a [`KeyPackageManagerUpdate`] with the key packages
+//! that have to be published to and removed from the delivery service.
+//!
+//! The manager only keeps references to the key packages. The key package
+//! bundles themselves are stored by the [`OpenMlsProvider`]. The manager is
+//! serializable, so that the application can persist it alongside.
+//!
+//! Regular key packages are deleted from the storage when joining a group
+//! from a [`Welcome`], while last-resort key packages are kept until they
+//! expire. A last-resort key package that was rotated is kept in the storage
+//! as well, because the delivery service may still hand it out until the new
+//! one is published.
+
+use openmls_traits::{signatures::Signer, storage::StorageProvider as _, types::Ciphersuite};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    errors::KeyPackageManagerError, lifetime::unix_time_now, KeyPackage, KeyPackageBuilder,
+    KeyPackageBundle, Lifetime,
+};
+use crate::{
+    ciphersuite::hash_ref::KeyPackageRef, credentials::CredentialWithKey, messages::Welcome,
+    storage::OpenMlsProvider,
+};
+
+/// The default number of regular key packages per ciphersuite.
+const DEFAULT_NUMBER_OF_KEY_PACKAGES: usize = 100;
+
+/// The default number of available regular key packages below which the key
+/// packages are refilled.
+const DEFAULT_REFILL_THRESHOLD: usize = 20;
+
+/// The default interval (in seconds) after which the last-resort key package
+/// is rotated. The value amounts to 4 weeks.
+const DEFAULT_LAST_RESORT_ROTATION_INTERVAL_SECONDS: u64 = 60 * 60 * 24 * 28;
+
+/// Configuration of a [`KeyPackageManager`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyPackageManagerConfig {
+    ciphersuites: Vec<Ciphersuite>,
+    number_of_key_packages: usize,
+    refill_threshold: usize,
+    last_resort_rotation_interval: u64,
+    key_package_lifetime: Option<u64>,
+}
+
+impl Default for KeyPackageManagerConfig {
+    fn default() -> Self {
+        Self {
+            ciphersuites: vec![Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519],
+            number_of_key_packages: DEFAULT_NUMBER_OF_KEY_PACKAGES,
+            refill_threshold: DEFAULT_REFILL_THRESHOLD,
+            last_resort_rotation_interval: DEFAULT_LAST_RESORT_ROTATION_INTERVAL_SECONDS,
+            key_package_lifetime: None,
+        }
+    }
+}
+
+impl KeyPackageManagerConfig {
+    /// Returns a builder for [`KeyPackageManagerConfig`].
+    pub fn builder() -> KeyPackageManagerConfigBuilder {
+        KeyPackageManagerConfigBuilder::new()
+    }
+
+    /// Returns the ciphersuites key packages are created for.
+    pub fn ciphersuites(&self) -> &[Ciphersuite] {
+        &self.ciphersuites
+    }
+
+    /// Returns the number of regular key packages per ciphersuite.
+    pub fn number_of_key_packages(&self) -> usize {
+        self.number_of_key_packages
+    }
+
+    /// Returns the number of available regular key packages below which the
+    /// key packages are refilled.
+    pub fn refill_threshold(&self) -> usize {
+        self.refill_threshold
+    }
+
+    /// Returns the interval (in seconds) after which the last-resort key
+    /// package is rotated.
+    pub fn last_resort_rotation_interval(&self) -> u64 {
+        self.last_resort_rotation_interval
+    }
+}
+
+/// Builder struct for a [`KeyPackageManagerConfig`].
+#[derive(Default)]
+pub struct KeyPackageManagerConfigBuilder {
+    config: KeyPackageManagerConfig,
+}
+
+impl KeyPackageManagerConfigBuilder {
+    /// Creates a new builder with default values.
+    fn new() -> Self {
+        Self {
+            config: KeyPackageManagerConfig::default(),
+        }
+    }
+
+    /// Sets the `ciphersuites` property of the [`KeyPackageManagerConfig`].
+    pub fn ciphersuites(mut self, ciphersuites: Vec<Ciphersuite>) -> Self {
+        self.config.ciphersuites = ciphersuites;
+        self
+    }
+
+    /// Sets the `number_of_key_packages` property of the
+    /// [`KeyPackageManagerConfig`].
+    pub fn number_of_key_packages(mut self, number_of_key_packages: usize) -> Self {
+        self.config.number_of_key_packages = number_of_key_packages;
+        self
+    }
+
+    /// Sets the `refill_threshold` property of the [`KeyPackageManagerConfig`].
+    pub fn refill_threshold(mut self, refill_threshold: usize) -> Self {
+        self.config.refill_threshold = refill_threshold;
+        self
+    }
+
+    /// Sets the `last_resort_rotation_interval` (in seconds) property of the
+    /// [`KeyPackageManagerConfig`].
+    pub fn last_resort_rotation_interval(mut self, last_resort_rotation_interval: u64) -> Self {
+        self.config.last_resort_rotation_interval = last_resort_rotation_interval;
+        self
+    }
+
+    /// Sets the lifetime (in seconds) of new key packages. If it is not set,
+    /// the default [`Lifetime`] is used.
+    pub fn key_package_lifetime(mut self, key_package_lifetime: u64) -> Self {
+        self.config.key_package_lifetime = Some(key_package_lifetime);
+        self
+    }
+
+    /// Finalizes the builder and returns the [`KeyPackageManagerConfig`].
+    pub fn build(self) -> KeyPackageManagerConfig {
+        self.config
+    }
+}
+
+/// The state of a key package that is tracked by a [`KeyPackageManager`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ManagedKeyPackageState {
+    /// The key package can be handed out by the delivery service.
+    Available,
+    /// The key package was used to join a group.
+    Consumed,
+    /// The last-resort key package was replaced by a new one.
+    Retired,
+}
+
+/// A key package that is tracked by a [`KeyPackageManager`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManagedKeyPackage {
+    key_package_ref: KeyPackageRef,
+    ciphersuite: Ciphersuite,
+    lifetime: Lifetime,
+    last_resort: bool,
+    created_at: u64,
+    state: ManagedKeyPackageState,
+}
+
+impl ManagedKeyPackage {
+    /// Returns the reference of the key package.
+    pub fn key_package_ref(&self) -> &KeyPackageRef {
+        &self.key_package_ref
+    }
+
+    /// Returns the ciphersuite of the key package.
+    pub fn ciphersuite(&self) -> Ciphersuite {
+        self.ciphersuite
+    }
+
+    /// Returns the lifetime of the key package.
+    pub fn lifetime(&self) -> &Lifetime {
+        &self.lifetime
+    }
+
+    /// Returns `true` if this is a last-resort key package.
+    pub fn last_resort(&self) -> bool {
+        self.last_resort
+    }
+
+    /// Returns the time (in seconds since the Unix epoch) the key package was
+    /// created at.
+    pub fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    /// Returns the state of the key package.
+    pub fn state(&self) -> ManagedKeyPackageState {
+        self.state
+    }
+
+    /// Returns `true` if this is the current last-resort key package.
+    fn is_current_last_resort(&self) -> bool {
+        self.last_resort && self.state != ManagedKeyPackageState::Retired
+    }
+}
+
+/// The changes of a [`KeyPackageManager`] operation that have to be reflected
+/// at the delivery service.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct KeyPackageManagerUpdate {
+    new_key_packages: Vec<KeyPackage>,
+    removed_key_packages: Vec<KeyPackageRef>,
+}
+
+impl KeyPackageManagerUpdate {
+    /// Returns the key packages that were created and have to be published.
+    pub fn new_key_packages(&self) -> &[KeyPackage] {
+        &self.new_key_packages
+    }
+
+    /// Returns the references of the key packages that were deleted and have
+    /// to be removed from the delivery service.
+    pub fn removed_key_packages(&self) -> &[KeyPackageRef] {
+        &self.removed_key_packages
+    }
+
+    /// Returns `true` if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.new_key_packages.is_empty() && self.removed_key_packages.is_empty()
+    }
+
+    fn extend(&mut self, other: KeyPackageManagerUpdate) {
+        self.new_key_packages.extend(other.new_key_packages);
+        self.removed_key_packages.extend(other.removed_key_packages);
+    }
+}
+
+/// Manages the key packages of a client. See the
+/// [module documentation](self) for details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyPackageManager {
+    config: KeyPackageManagerConfig,
+    key_package_builder: KeyPackageBuilder,
+    key_packages: Vec<ManagedKeyPackage>,
+}
+
+impl KeyPackageManager {
+    /// Creates a new [`KeyPackageManager`] without any key packages. Use
+    /// [`KeyPackageManager::refill()`] to create the initial key packages.
+    pub fn new(config: KeyPackageManagerConfig) -> Self {
+        Self {
+            config,
+            key_package_builder: KeyPackageBuilder::new(),
+            key_packages: vec![],
+        }
+    }
+
+    /// Sets the [`KeyPackageBuilder`] that is used as template for new key
+    /// packages, e.g. to set the leaf node capabilities. The lifetime and the
+    /// last-resort flag of the template are ignored.
+    pub fn with_key_package_builder(mut self, key_package_builder: KeyPackageBuilder) -> Self {
+        self.key_package_builder = key_package_builder;
+        self
+    }
+
+    /// Returns the configuration.
+    pub fn config(&self) -> &KeyPackageManagerConfig {
+        &self.config
+    }
+
+    /// Returns all tracked key packages.
+    pub fn key_packages(&self) -> &[ManagedKeyPackage] {
+        &self.key_packages
+    }
+
+    /// Returns the number of available regular key packages of the
+    /// `ciphersuite`.
+    pub fn available_key_packages(&self, ciphersuite: Ciphersuite) -> usize {
+        self.key_packages
+            .iter()
+            .filter(|key_package| {
+                key_package.ciphersuite == ciphersuite
+                    && !key_package.last_resort
+                    && key_package.state == ManagedKeyPackageState::Available
+            })
+            .count()
+    }
+
+    /// Returns the current last-resort key package of the `ciphersuite`.
+    pub fn last_resort_key_package(&self, ciphersuite: Ciphersuite) -> Option<&ManagedKeyPackage> {
+        self.key_packages.iter().find(|key_package| {
+            key_package.ciphersuite == ciphersuite && key_package.is_current_last_resort()
+        })
+    }
+
+    /// Marks the key package the `welcome` was encrypted to as consumed.
+    /// This should be called when joining a group from the `welcome`.
+    ///
+    /// Returns the reference of the consumed key package or `None` if the
+    /// `welcome` doesn't use any of the available key packages.
+    pub fn mark_consumed(&mut self, welcome: &Welcome) -> Option<KeyPackageRef> {
+        let key_package = self.key_packages.iter_mut().find(|key_package| {
+            key_package.state != ManagedKeyPackageState::Consumed
+                && welcome
+                    .secrets()
+                    .iter()
+                    .any(|secrets| secrets.new_member() == key_package.key_package_ref)
+        })?;
+        key_package.state = ManagedKeyPackageState::Consumed;
+        Some(key_package.key_package_ref.clone())
+    }
+
+    /// Performs all maintenance operations: Regular key packages that were
+    /// deleted from the storage are marked as consumed, expired key packages
+    /// are purged, the last-resort key packages are rotated if they are due
+    /// and the regular key packages are refilled.
+    pub fn maintain<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        credential_with_key: &CredentialWithKey,
+    ) -> Result<KeyPackageManagerUpdate, KeyPackageManagerError<Provider::StorageError>> {
+        let mut update = self.purge_expired(provider)?;
+        update.extend(self.rotate_last_resort(provider, signer, credential_with_key)?);
+        update.extend(self.refill(provider, signer, credential_with_key)?);
+        Ok(update)
+    }
+
+    /// Creates regular key packages for every ciphersuite with fewer than
+    /// the threshold of available key packages, up to the configured number,
+    /// and a last-resort key package for every ciphersuite without one.
+    ///
+    /// Regular key packages that were deleted from the storage, i.e. that
+    /// were used to join a group, are marked as consumed first.
+    pub fn refill<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        credential_with_key: &CredentialWithKey,
+    ) -> Result<KeyPackageManagerUpdate, KeyPackageManagerError<Provider::StorageError>> {
+        self.sync_consumed(provider)?;
+
+        let mut update = KeyPackageManagerUpdate::default();
+        for ciphersuite in self.config.ciphersuites.clone() {
+            let available = self.available_key_packages(ciphersuite);
+            if available < self.config.refill_threshold {
+                for _ in available..self.config.number_of_key_packages {
+                    self.create(
+                        provider,
+                        signer,
+                        credential_with_key,
+                        ciphersuite,
+                        false,
+                        &mut update,
+                    )?;
+                }
+            }
+
+            if self.last_resort_key_package(ciphersuite).is_none() {
+                self.create(
+                    provider,
+                    signer,
+                    credential_with_key,
+                    ciphersuite,
+                    true,
+                    &mut update,
+                )?;
+            }
+        }
+
+        Ok(update)
+    }
+
+    /// Replaces every last-resort key package that was consumed or is older
+    /// than the rotation interval with a new one.
+    ///
+    /// The replaced key packages are kept in the storage until they expire.
+    pub fn rotate_last_resort<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        credential_with_key: &CredentialWithKey,
+    ) -> Result<KeyPackageManagerUpdate, KeyPackageManagerError<Provider::StorageError>> {
+        let now = unix_time_now();
+        let rotation_interval = self.config.last_resort_rotation_interval;
+
+        let mut update = KeyPackageManagerUpdate::default();
+        for ciphersuite in self.config.ciphersuites.clone() {
+            let Some(last_resort) = self.key_packages.iter_mut().find(|key_package| {
+                key_package.ciphersuite == ciphersuite && key_package.is_current_last_resort()
+            }) else {
+                continue;
+            };
+            if last_resort.state == ManagedKeyPackageState::Consumed
+                || now >= last_resort.created_at.saturating_add(rotation_interval)
+            {
+                last_resort.state = ManagedKeyPackageState::Retired;
+                self.create(
+                    provider,
+                    signer,
+                    credential_with_key,
+                    ciphersuite,
+                    true,
+                    &mut update,
+                )?;
+            }
+        }
+
+        Ok(update)
+    }
+
+    /// Deletes all key packages whose lifetime has ended from the storage and
+    /// stops tracking them.
+    pub fn purge_expired<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+    ) -> Result<KeyPackageManagerUpdate, KeyPackageManagerError<Provider::StorageError>> {
+        let mut update = KeyPackageManagerUpdate::default();
+        let mut key_packages = Vec::with_capacity(self.key_packages.len());
+        for key_package in self.key_packages.drain(..) {
+            if !key_package.lifetime.is_expired() {
+                key_packages.push(key_package);
+                continue;
+            }
+
+            // Consumed regular key packages were already deleted when joining
+            // the group.
+            if key_package.last_resort || key_package.state != ManagedKeyPackageState::Consumed {
+                provider
+                    .storage()
+                    .delete_key_package(&key_package.key_package_ref)
+                    .map_err(KeyPackageManagerError::StorageError)?;
+            }
+            update
+                .removed_key_packages
+                .push(key_package.key_package_ref);
+        }
+        self.key_packages = key_packages;
+
+        Ok(update)
+    }
+
+    /// Marks the available regular key packages that are no longer in the
+    /// storage as consumed.
+    fn sync_consumed<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+    ) -> Result<(), KeyPackageManagerError<Provider::StorageError>> {
+        for key_package in self.key_packages.iter_mut().filter(|key_package| {
+            !key_package.last_resort && key_package.state == ManagedKeyPackageState::Available
+        }) {
+            let stored: Option<KeyPackageBundle> = provider
+                .storage()
+                .key_package(&key_package.key_package_ref)
+                .map_err(KeyPackageManagerError::StorageError)?;
+            if stored.is_none() {
+                key_package.state = ManagedKeyPackageState::Consumed;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a key package and starts tracking it.
+    fn create<Provider: OpenMlsProvider>(
+        &mut self,
+        provider: &Provider,
+        signer: &impl Signer,
+        credential_with_key: &CredentialWithKey,
+        ciphersuite: Ciphersuite,
+        last_resort: bool,
+        update: &mut KeyPackageManagerUpdate,
+    ) -> Result<(), KeyPackageManagerError<Provider::StorageError>> {
+        let lifetime = self
+            .config
+            .key_package_lifetime
+            .map(Lifetime::new)
+            .unwrap_or_default();
+        let mut key_package_builder = self
+            .key_package_builder
+            .clone()
+            .key_package_lifetime(lifetime);
+        key_package_builder.last_resort = last_resort;
+        let key_package_bundle = key_package_builder.build(
+            ciphersuite,
+            provider,
+            signer,
+            credential_with_key.clone(),
+        )?;
+
+        let key_package = key_package_bundle.key_package;
+        self.key_packages.push(ManagedKeyPackage {
+            key_package_ref: key_package.hash_ref(provider.crypto())?,
+            ciphersuite,
+            lifetime,
+            last_resort,
+            created_at: unix_time_now(),
+            state: ManagedKeyPackageState::Available,
+        });
+        update.new_key_packages.push(key_package);
+
+        Ok(())
+    }
+}
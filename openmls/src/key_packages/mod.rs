@@ -56,6 +56,13 @@
 //!
 //! See [`KeyPackage`] for more details and other ways to create key packages.
 //!
+//! ## Managing key packages
+//!
+//! The [`KeyPackageManager`] keeps a configured number of regular key
+//! packages and one last-resort key package per ciphersuite available. It
+//! tracks which key packages were consumed, refills and rotates them and
+//! purges expired ones.
+//!
 //! ## Loading key packages
 //!
 //! When getting key packages from another user the serialized bytes are parsed
@@ -102,16 +109,22 @@ use crate::{
     storage::OpenMlsProvider,
     treesync::{
         node::{
-            encryption_keys::{EncryptionKeyPair, EncryptionPrivateKey},
-            leaf_node::{Capabilities, LeafNodeSource, NewLeafNodeParams, TreeInfoTbs},
+            encryption_keys::{EncryptionKey, EncryptionKeyPair, EncryptionPrivateKey},
+            leaf_node::{Capabilities, LeafNodeSource, TreeInfoTbs},
         },
         LeafNode,
     },
     versions::ProtocolVersion,
 };
 use openmls_traits::{
-    crypto::OpenMlsCrypto, signatures::Signer, storage::StorageProvider, types::Ciphersuite,
+    crypto::OpenMlsCrypto,
+    key_handles::{KeyHandle, KeyHandleProvider},
+    signatures::Signer,
+    storage::StorageProvider,
+    types::{Ciphersuite, CryptoError, HpkeKeyPair},
 };
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use tls_codec::{
     Serialize as TlsSerializeTrait, TlsDeserialize, TlsDeserializeBytes, TlsSerialize, TlsSize,
@@ -125,14 +138,19 @@ pub mod errors;
 pub mod key_package_in;
 
 mod lifetime;
+mod manager;
 
 // Tests
 #[cfg(test)]
 pub(crate) mod tests;
 
 // Public types
-pub use key_package_in::KeyPackageIn;
+pub use key_package_in::{KeyPackageIn, KeyPackageValidationReport};
 pub use lifetime::Lifetime;
+pub use manager::{
+    KeyPackageManager, KeyPackageManagerConfig, KeyPackageManagerConfigBuilder,
+    KeyPackageManagerUpdate, ManagedKeyPackage, ManagedKeyPackageState,
+};
 
 /// The unsigned payload of a key package.
 /// Any modification must happen on this unsigned struct. Use `sign` to get a
@@ -280,9 +298,9 @@ impl KeyPackage {
             .map_err(|e| {
                 KeyPackageNewError::LibraryError(LibraryError::unexpected_crypto_error(e))
             })?;
-        let (key_package, encryption_keypair) = Self::new_from_keys(
+        let encryption_keypair = EncryptionKeyPair::random(provider, ciphersuite)?;
+        let key_package = Self::new_from_keys(
             ciphersuite,
-            provider,
             signer,
             credential_with_key,
             lifetime,
@@ -290,6 +308,7 @@ impl KeyPackage {
             leaf_node_capabilities,
             leaf_node_extensions,
             init_key.public.into(),
+            encryption_keypair.public_key().clone(),
         )?;
 
         Ok(KeyPackageCreationResult {
@@ -301,17 +320,14 @@ impl KeyPackage {
 
     /// Create a new key package for the given `ciphersuite` and `identity`.
     ///
-    /// The HPKE init key must have been generated before and the private part
-    /// has to be stored in the key store.
-    ///
-    /// This function returns the new [`KeyPackage`] as well as the
-    /// encryption key ([`HpkeKeyPair`]) of the leaf node.
+    /// The HPKE init key and the encryption key of the leaf node must have
+    /// been generated before and the private parts have to be stored in the
+    /// key store.
     ///
     /// The caller is responsible for storing the new values.
     #[allow(clippy::too_many_arguments)]
     fn new_from_keys(
         ciphersuite: Ciphersuite,
-        provider: &impl OpenMlsProvider,
         signer: &impl Signer,
         credential_with_key: CredentialWithKey,
         lifetime: Lifetime,
@@ -319,21 +335,17 @@ impl KeyPackage {
         capabilities: Capabilities,
         leaf_node_extensions: Extensions,
         init_key: InitKey,
-    ) -> Result<(Self, EncryptionKeyPair), KeyPackageNewError> {
-        // We don't need the private key here. It's stored in the key store for
-        // use later when creating a group with this key package.
-
-        let new_leaf_node_params = NewLeafNodeParams {
-            ciphersuite,
-            leaf_node_source: LeafNodeSource::KeyPackage(lifetime),
+        encryption_key: EncryptionKey,
+    ) -> Result<Self, KeyPackageNewError> {
+        let leaf_node = LeafNode::new_with_key(
+            encryption_key,
             credential_with_key,
+            LeafNodeSource::KeyPackage(lifetime),
             capabilities,
-            extensions: leaf_node_extensions,
-            tree_info_tbs: TreeInfoTbs::KeyPackage,
-        };
-
-        let (leaf_node, encryption_key_pair) =
-            LeafNode::new(provider, signer, new_leaf_node_params)?;
+            leaf_node_extensions,
+            TreeInfoTbs::KeyPackage,
+            signer,
+        )?;
 
         let key_package_tbs = KeyPackageTbs {
             protocol_version: ProtocolVersion::default(),
@@ -345,7 +357,7 @@ impl KeyPackage {
 
         let key_package = key_package_tbs.sign(signer)?;
 
-        Ok((key_package, encryption_key_pair))
+        Ok(key_package)
     }
 
     /// Get a reference to the extensions of this key package.
@@ -418,6 +430,7 @@ pub struct KeyPackageBuilder {
     key_package_extensions: Option<Extensions>,
     leaf_node_capabilities: Option<Capabilities>,
     leaf_node_extensions: Option<Extensions>,
+    group_context_extensions: Option<Extensions>,
     last_resort: bool,
 }
 
@@ -429,6 +442,7 @@ impl KeyPackageBuilder {
             key_package_extensions: None,
             leaf_node_capabilities: None,
             leaf_node_extensions: None,
+            group_context_extensions: None,
             last_resort: false,
         }
     }
@@ -463,6 +477,52 @@ impl KeyPackageBuilder {
         self
     }
 
+    /// Set the group context extensions of the group the key package is meant
+    /// for. When the key package is built, the leaf node capabilities are
+    /// checked to support everything the group requires.
+    pub fn group_context_extensions(mut self, extensions: Extensions) -> Self {
+        self.group_context_extensions.replace(extensions);
+        self
+    }
+
+    /// Returns the leaf node capabilities. If none were set, the default
+    /// capabilities are extended with the key package and leaf node extension
+    /// types and everything the group context extensions require.
+    fn capabilities(&self) -> Capabilities {
+        if let Some(capabilities) = &self.leaf_node_capabilities {
+            return capabilities.clone();
+        }
+
+        let key_package_extension_types: Vec<ExtensionType> = self
+            .key_package_extensions
+            .iter()
+            .flat_map(Extensions::iter)
+            .map(Extension::extension_type)
+            .collect();
+        let mut builder = Capabilities::builder().add_extensions(&key_package_extension_types);
+        if let Some(extensions) = &self.leaf_node_extensions {
+            builder = builder.for_leaf_node_extensions(extensions);
+        }
+        if let Some(extensions) = &self.group_context_extensions {
+            builder = builder.for_group_context_extensions(extensions);
+        }
+        builder.build()
+    }
+
+    /// Checks that the `capabilities` support everything the group requires,
+    /// if the group context extensions are known.
+    fn check_capabilities(&self, capabilities: &Capabilities) -> Result<(), KeyPackageNewError> {
+        if let Some(group_context_extensions) = &self.group_context_extensions {
+            capabilities.check_group_requirements(
+                group_context_extensions,
+                self.leaf_node_extensions
+                    .as_ref()
+                    .unwrap_or(&Extensions::empty()),
+            )?;
+        }
+        Ok(())
+    }
+
     /// Ensure that a last-resort extension is present in the key package if the
     /// `last_resort` flag is set.
     fn ensure_last_resort(&mut self) {
@@ -485,6 +545,8 @@ impl KeyPackageBuilder {
         credential_with_key: CredentialWithKey,
     ) -> Result<KeyPackageCreationResult, KeyPackageNewError> {
         self.ensure_last_resort();
+        let capabilities = self.capabilities();
+        self.check_capabilities(&capabilities)?;
         KeyPackage::create(
             ciphersuite,
             provider,
@@ -492,7 +554,7 @@ impl KeyPackageBuilder {
             credential_with_key,
             self.key_package_lifetime.unwrap_or_default(),
             self.key_package_extensions.unwrap_or_default(),
-            self.leaf_node_capabilities.unwrap_or_default(),
+            capabilities,
             self.leaf_node_extensions.unwrap_or_default(),
         )
     }
@@ -506,6 +568,8 @@ impl KeyPackageBuilder {
         credential_with_key: CredentialWithKey,
     ) -> Result<KeyPackageBundle, KeyPackageNewError> {
         self.ensure_last_resort();
+        let capabilities = self.capabilities();
+        self.check_capabilities(&capabilities)?;
         let KeyPackageCreationResult {
             key_package,
             encryption_keypair,
@@ -517,7 +581,7 @@ impl KeyPackageBuilder {
             credential_with_key,
             self.key_package_lifetime.unwrap_or_default(),
             self.key_package_extensions.unwrap_or_default(),
-            self.leaf_node_capabilities.unwrap_or_default(),
+            capabilities,
             self.leaf_node_extensions.unwrap_or_default(),
         )?;
 
@@ -527,6 +591,156 @@ impl KeyPackageBuilder {
             key_package,
             private_init_key: init_private_key,
             private_encryption_key: encryption_keypair.private_key().clone(),
+            init_key_handle: None,
+        };
+        provider
+            .storage()
+            .write_key_package(&full_kp.key_package.hash_ref(provider.crypto())?, &full_kp)
+            .map_err(|_| KeyPackageNewError::StorageError)?;
+
+        Ok(full_kp)
+    }
+
+    /// Finalize and build `n` key packages with the same configuration.
+    ///
+    /// The HPKE init and encryption key pairs of all key packages are derived
+    /// in parallel before the key packages are signed. The key packages are
+    /// written to the storage in one batch with
+    /// [`StorageProvider::write_key_packages()`] once all of them were
+    /// created.
+    ///
+    /// Returns the [`KeyPackageBundle`]s along with their [`KeyPackageRef`]s.
+    pub fn build_many(
+        mut self,
+        n: usize,
+        ciphersuite: Ciphersuite,
+        provider: &impl OpenMlsProvider,
+        signer: &impl Signer,
+        credential_with_key: CredentialWithKey,
+    ) -> Result<Vec<(KeyPackageRef, KeyPackageBundle)>, KeyPackageNewError> {
+        if ciphersuite.signature_algorithm() != signer.signature_scheme() {
+            return Err(KeyPackageNewError::CiphersuiteSignatureSchemeMismatch);
+        }
+        self.ensure_last_resort();
+        let capabilities = self.capabilities();
+        self.check_capabilities(&capabilities)?;
+        let lifetime = self.key_package_lifetime.unwrap_or_default();
+        let extensions = self.key_package_extensions.unwrap_or_default();
+        let leaf_node_extensions = self.leaf_node_extensions.unwrap_or_default();
+
+        // The randomness is drawn up front, because the random provider
+        // doesn't have to be thread safe.
+        let ikms = (0..n)
+            .map(|_| -> Result<_, CryptoError> {
+                Ok((
+                    Secret::random(ciphersuite, provider.rand())?,
+                    Secret::random(ciphersuite, provider.rand())?,
+                ))
+            })
+            .collect::<Result<Vec<(Secret, Secret)>, _>>()
+            .map_err(LibraryError::unexpected_crypto_error)?;
+
+        // Derive the init and encryption key pairs
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let ikms = ikms.par_iter();
+        #[cfg(target_arch = "wasm32")]
+        let ikms = ikms.iter();
+
+        let crypto = provider.crypto();
+        let key_pairs = ikms
+            .map(|(init_ikm, encryption_ikm)| -> Result<_, CryptoError> {
+                let init_key_pair =
+                    crypto.derive_hpke_keypair(ciphersuite.hpke_config(), init_ikm.as_slice())?;
+                let encryption_key_pair = crypto
+                    .derive_hpke_keypair(ciphersuite.hpke_config(), encryption_ikm.as_slice())?;
+                Ok((init_key_pair, EncryptionKeyPair::from(encryption_key_pair)))
+            })
+            .collect::<Result<Vec<(HpkeKeyPair, EncryptionKeyPair)>, _>>()
+            .map_err(LibraryError::unexpected_crypto_error)?;
+
+        // Sign the key packages. The signer doesn't have to be thread safe
+        // either.
+        let key_packages = key_pairs
+            .into_iter()
+            .map(
+                |(init_key_pair, encryption_key_pair)| -> Result<_, KeyPackageNewError> {
+                    let key_package = KeyPackage::new_from_keys(
+                        ciphersuite,
+                        signer,
+                        credential_with_key.clone(),
+                        lifetime,
+                        extensions.clone(),
+                        capabilities.clone(),
+                        leaf_node_extensions.clone(),
+                        init_key_pair.public.into(),
+                        encryption_key_pair.public_key().clone(),
+                    )?;
+                    let key_package_ref = key_package.hash_ref(crypto)?;
+                    let key_package_bundle = KeyPackageBundle {
+                        key_package,
+                        private_init_key: init_key_pair.private,
+                        private_encryption_key: encryption_key_pair.private_key().clone(),
+                        init_key_handle: None,
+                    };
+                    Ok((key_package_ref, key_package_bundle))
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Store the key packages in the key store with the hash references as
+        // ids for retrieval when parsing welcome messages.
+        provider
+            .storage()
+            .write_key_packages(&key_packages)
+            .map_err(|_| KeyPackageNewError::StorageError)?;
+
+        Ok(key_packages)
+    }
+
+    /// Finalize and build the key package with an HPKE init key that is
+    /// generated by the `key_handles` provider.
+    ///
+    /// The private init key never leaves the provider. The bundle only
+    /// contains its [`KeyHandle`], and `Welcome` messages for the key package
+    /// must be processed with
+    /// [`ProcessedWelcome::new_from_welcome_with_key_handles()`](crate::group::ProcessedWelcome::new_from_welcome_with_key_handles).
+    pub fn build_with_key_handles(
+        mut self,
+        ciphersuite: Ciphersuite,
+        provider: &impl OpenMlsProvider,
+        key_handles: &impl KeyHandleProvider,
+        signer: &impl Signer,
+        credential_with_key: CredentialWithKey,
+    ) -> Result<KeyPackageBundle, KeyPackageNewError> {
+        if ciphersuite.signature_algorithm() != signer.signature_scheme() {
+            return Err(KeyPackageNewError::CiphersuiteSignatureSchemeMismatch);
+        }
+        self.ensure_last_resort();
+        let capabilities = self.capabilities();
+        self.check_capabilities(&capabilities)?;
+
+        let (init_key_handle, init_key) = key_handles
+            .generate_hpke_key(ciphersuite.hpke_config())
+            .map_err(KeyPackageNewError::KeyHandleError)?;
+        let encryption_keypair = EncryptionKeyPair::random(provider, ciphersuite)?;
+        let key_package = KeyPackage::new_from_keys(
+            ciphersuite,
+            signer,
+            credential_with_key,
+            self.key_package_lifetime.unwrap_or_default(),
+            self.key_package_extensions.unwrap_or_default(),
+            capabilities,
+            self.leaf_node_extensions.unwrap_or_default(),
+            init_key.into(),
+            encryption_keypair.public_key().clone(),
+        )?;
+
+        let full_kp = KeyPackageBundle {
+            key_package,
+            private_init_key: HpkePrivateKey::from(Vec::new()),
+            private_encryption_key: encryption_keypair.private_key().clone(),
+            init_key_handle: Some(init_key_handle),
         };
         provider
             .storage()
@@ -541,12 +755,15 @@ impl KeyPackageBuilder {
 /// private key.
 ///
 /// This is stored to ensure the private key is handled together with the key
-/// package.
+/// package. If the init key is held by a [`KeyHandleProvider`], the bundle
+/// contains its [`KeyHandle`] instead of the private init key.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyPackageBundle {
     pub(crate) key_package: KeyPackage,
     pub(crate) private_init_key: HpkePrivateKey,
     pub(crate) private_encryption_key: EncryptionPrivateKey,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) init_key_handle: Option<KeyHandle>,
 }
 
 // Public `KeyPackageBundle` functions.
@@ -557,10 +774,19 @@ impl KeyPackageBundle {
     }
 
     /// Get a reference to the private init key.
+    ///
+    /// The key is empty if the init key is held by a [`KeyHandleProvider`].
+    /// See [`KeyPackageBundle::init_key_handle()`].
     pub fn init_private_key(&self) -> &HpkePrivateKey {
         &self.private_init_key
     }
 
+    /// Get the handle of the private init key, if it is held by a
+    /// [`KeyHandleProvider`].
+    pub fn init_key_handle(&self) -> Option<&KeyHandle> {
+        self.init_key_handle.as_ref()
+    }
+
     /// Get the encryption key pair.
     pub(crate) fn encryption_key_pair(&self) -> EncryptionKeyPair {
         EncryptionKeyPair::from((
@@ -582,6 +808,7 @@ impl KeyPackageBundle {
             key_package,
             private_init_key,
             private_encryption_key,
+            init_key_handle: None,
         }
     }
 
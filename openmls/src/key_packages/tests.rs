@@ -1,6 +1,9 @@
 use crate::test_utils::*;
 use openmls_basic_credential::SignatureKeyPair;
-use openmls_traits::prelude::*;
+use openmls_traits::{
+    prelude::*,
+    storage::{StorageProvider, CURRENT_VERSION},
+};
 
 use tls_codec::Deserialize;
 
@@ -192,3 +195,332 @@ fn last_resort_key_package() {
         .expect("An unexpected error occurred.");
     assert!(key_package.key_package().last_resort());
 }
+
+#[openmls_test::openmls_test]
+fn key_package_manager() {
+    use crate::{
+        credentials::test_utils::new_credential,
+        group::{MlsGroup, MlsGroupJoinConfig, StagedWelcome},
+    };
+
+    let (credential_with_key, signer) =
+        new_credential(provider, b"Sasha", ciphersuite.signature_algorithm());
+    let mut manager = KeyPackageManager::new(
+        KeyPackageManagerConfig::builder()
+            .ciphersuites(vec![ciphersuite])
+            .number_of_key_packages(3)
+            .refill_threshold(2)
+            .last_resort_rotation_interval(0)
+            .build(),
+    );
+
+    // The initial refill creates the regular and the last-resort key packages.
+    let update = manager
+        .refill(provider, &signer, &credential_with_key)
+        .unwrap();
+    assert_eq!(update.new_key_packages().len(), 4);
+    assert_eq!(
+        update
+            .new_key_packages()
+            .iter()
+            .filter(|key_package| key_package.last_resort())
+            .count(),
+        1
+    );
+    assert_eq!(manager.available_key_packages(ciphersuite), 3);
+    assert!(manager
+        .refill(provider, &signer, &credential_with_key)
+        .unwrap()
+        .is_empty());
+
+    // Alice adds Sasha with one of the regular key packages.
+    let (alice_credential_with_key, alice_signer) =
+        new_credential(provider, b"Alice", ciphersuite.signature_algorithm());
+    let mut alice_group = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .build(provider, &alice_signer, alice_credential_with_key)
+        .unwrap();
+    let regular_key_packages = update
+        .new_key_packages()
+        .iter()
+        .filter(|key_package| !key_package.last_resort())
+        .collect::<Vec<_>>();
+    let (_commit, welcome, _group_info) = alice_group
+        .add_members(provider, &alice_signer, &[regular_key_packages[0].clone()])
+        .unwrap();
+    alice_group.merge_pending_commit(provider).unwrap();
+    let welcome = welcome.into_welcome().unwrap();
+
+    assert_eq!(
+        manager.mark_consumed(&welcome),
+        Some(regular_key_packages[0].hash_ref(provider.crypto()).unwrap())
+    );
+    assert_eq!(manager.mark_consumed(&welcome), None);
+    StagedWelcome::new_from_welcome(
+        provider,
+        &MlsGroupJoinConfig::default(),
+        welcome,
+        Some(alice_group.export_ratchet_tree().into()),
+    )
+    .and_then(|staged_welcome| staged_welcome.into_group(provider))
+    .unwrap();
+
+    // Key packages that were deleted from the storage are consumed, too.
+    StorageProvider::<CURRENT_VERSION>::delete_key_package(
+        provider.storage(),
+        &regular_key_packages[1].hash_ref(provider.crypto()).unwrap(),
+    )
+    .unwrap();
+    let update = manager
+        .refill(provider, &signer, &credential_with_key)
+        .unwrap();
+    assert_eq!(update.new_key_packages().len(), 2);
+    assert_eq!(manager.available_key_packages(ciphersuite), 3);
+
+    // The last-resort key package is due for rotation. The old one is kept in
+    // the storage until it expires.
+    let last_resort_ref = manager
+        .last_resort_key_package(ciphersuite)
+        .unwrap()
+        .key_package_ref()
+        .clone();
+    let update = manager
+        .rotate_last_resort(provider, &signer, &credential_with_key)
+        .unwrap();
+    assert_eq!(update.new_key_packages().len(), 1);
+    assert!(update.new_key_packages()[0].last_resort());
+    assert_ne!(
+        manager
+            .last_resort_key_package(ciphersuite)
+            .unwrap()
+            .key_package_ref(),
+        &last_resort_ref
+    );
+    let stored: Option<KeyPackageBundle> =
+        provider.storage().key_package(&last_resort_ref).unwrap();
+    assert!(stored.is_some());
+    assert!(manager
+        .purge_expired(provider)
+        .unwrap()
+        .removed_key_packages()
+        .is_empty());
+
+    // Key packages without lifetime are purged right away.
+    let mut manager = KeyPackageManager::new(
+        KeyPackageManagerConfig::builder()
+            .ciphersuites(vec![ciphersuite])
+            .number_of_key_packages(1)
+            .key_package_lifetime(0)
+            .build(),
+    );
+    let update = manager
+        .refill(provider, &signer, &credential_with_key)
+        .unwrap();
+    let removed = manager.purge_expired(provider).unwrap();
+    assert_eq!(
+        removed.removed_key_packages(),
+        update
+            .new_key_packages()
+            .iter()
+            .map(|key_package| key_package.hash_ref(provider.crypto()).unwrap())
+            .collect::<Vec<_>>()
+            .as_slice()
+    );
+    assert!(manager.key_packages().is_empty());
+    let stored: Option<KeyPackageBundle> = provider
+        .storage()
+        .key_package(&removed.removed_key_packages()[0])
+        .unwrap();
+    assert!(stored.is_none());
+}
+
+#[openmls_test::openmls_test]
+fn build_many_key_packages() {
+    let credential = BasicCredential::new(b"Sasha".to_vec());
+    let signer = SignatureKeyPair::new(ciphersuite.signature_algorithm()).unwrap();
+
+    let key_packages = KeyPackage::builder()
+        .mark_as_last_resort()
+        .build_many(
+            5,
+            ciphersuite,
+            provider,
+            &signer,
+            CredentialWithKey {
+                credential: credential.into(),
+                signature_key: signer.to_public_vec().into(),
+            },
+        )
+        .expect("An unexpected error occurred.");
+    assert_eq!(key_packages.len(), 5);
+
+    for (key_package_ref, key_package_bundle) in &key_packages {
+        let key_package = key_package_bundle.key_package();
+        assert_eq!(
+            key_package_ref,
+            &key_package.hash_ref(provider.crypto()).unwrap()
+        );
+        assert!(key_package.last_resort());
+        assert!(KeyPackageIn::from(key_package.clone())
+            .validate(provider.crypto(), ProtocolVersion::Mls10)
+            .is_ok());
+
+        let stored: KeyPackageBundle = provider
+            .storage()
+            .key_package(key_package_ref)
+            .unwrap()
+            .expect("The key package was not stored.");
+        assert_eq!(stored.key_package(), key_package);
+    }
+
+    // All key packages have fresh init and encryption keys.
+    let init_keys: std::collections::HashSet<_> = key_packages
+        .iter()
+        .map(|(_, bundle)| bundle.key_package().hpke_init_key().as_slice().to_vec())
+        .collect();
+    let encryption_keys: std::collections::HashSet<_> = key_packages
+        .iter()
+        .map(|(_, bundle)| {
+            bundle
+                .key_package()
+                .leaf_node()
+                .encryption_key()
+                .as_slice()
+                .to_vec()
+        })
+        .collect();
+    assert_eq!(init_keys.len(), 5);
+    assert_eq!(encryption_keys.len(), 5);
+}
+
+#[openmls_test::openmls_test]
+fn validation_report() {
+    use crate::{
+        credentials::test_utils::new_credential,
+        group::MlsGroup,
+        treesync::{LeafNodeIn, ValidationCheck, ValidationOutcome},
+    };
+
+    let (credential_with_key, signer) =
+        new_credential(provider, b"Sasha", ciphersuite.signature_algorithm());
+    let key_package = KeyPackage::builder()
+        .mark_as_last_resort()
+        .build(ciphersuite, provider, &signer, credential_with_key.clone())
+        .expect("An unexpected error occurred.");
+    let key_package_in = KeyPackageIn::from(key_package.key_package().clone());
+
+    // A valid key package passes all checks.
+    let report = key_package_in.validation_report(provider.crypto(), ProtocolVersion::Mls10);
+    assert!(report.is_valid());
+    assert!(report
+        .checks()
+        .iter()
+        .chain(report.leaf_node().checks())
+        .all(|result| result.outcome() == ValidationOutcome::Passed));
+    assert_eq!(report.ciphersuite(), ciphersuite);
+    assert!(report.last_resort());
+    assert_eq!(
+        report.leaf_node().credential(),
+        &credential_with_key.credential
+    );
+    assert!(report.leaf_node().lifetime().is_some());
+    assert!(key_package_in
+        .clone()
+        .validate(provider.crypto(), ProtocolVersion::Mls10)
+        .is_ok());
+
+    // All checks are performed, even if one fails.
+    let report = key_package_in.validation_report(provider.crypto(), ProtocolVersion::Other(2));
+    assert!(!report.is_valid());
+    assert_eq!(
+        report.failed_checks().collect::<Vec<_>>(),
+        vec![ValidationCheck::ProtocolVersion]
+    );
+    assert_eq!(
+        report.outcome(ValidationCheck::KeyPackageSignature),
+        Some(ValidationOutcome::Passed)
+    );
+
+    // A key package that was signed with another key fails the leaf node
+    // signature check and the key package signature can't be checked.
+    let (_, other_signer) = new_credential(provider, b"Other", ciphersuite.signature_algorithm());
+    let key_package = KeyPackage::builder()
+        .build(ciphersuite, provider, &other_signer, credential_with_key)
+        .expect("An unexpected error occurred.");
+    let report = KeyPackageIn::from(key_package.key_package().clone())
+        .validation_report(provider.crypto(), ProtocolVersion::Mls10);
+    assert_eq!(
+        report.failed_checks().collect::<Vec<_>>(),
+        vec![ValidationCheck::LeafNodeSignature]
+    );
+    assert_eq!(
+        report.outcome(ValidationCheck::KeyPackageSignature),
+        Some(ValidationOutcome::Skipped)
+    );
+
+    // The signature on a leaf node from a commit can only be verified with its
+    // position in the tree.
+    let (alice_credential_with_key, alice_signer) =
+        new_credential(provider, b"Alice", ciphersuite.signature_algorithm());
+    let mut alice_group = MlsGroup::builder()
+        .ciphersuite(ciphersuite)
+        .build(provider, &alice_signer, alice_credential_with_key)
+        .expect("error creating group");
+    alice_group
+        .self_update(provider, &alice_signer, Default::default())
+        .expect("error updating");
+    alice_group.merge_pending_commit(provider).unwrap();
+    let leaf_node_in = LeafNodeIn::from(alice_group.own_leaf_node().unwrap().clone());
+
+    let report = leaf_node_in.validation_report(provider.crypto(), ciphersuite, None);
+    assert!(report.is_valid());
+    assert!(report.lifetime().is_none());
+    for check in [
+        ValidationCheck::LeafNodeSignature,
+        ValidationCheck::Lifetime,
+        ValidationCheck::LifetimeRange,
+    ] {
+        assert_eq!(report.outcome(check), Some(ValidationOutcome::Skipped));
+    }
+
+    let report = leaf_node_in.validation_report(
+        provider.crypto(),
+        ciphersuite,
+        Some((alice_group.group_id().clone(), alice_group.own_leaf_index())),
+    );
+    assert_eq!(
+        report.outcome(ValidationCheck::LeafNodeSignature),
+        Some(ValidationOutcome::Passed)
+    );
+}
+
+// The post-quantum ciphersuites use much larger keys and signatures than the
+// classical ones. Make sure they survive the serialization and validation.
+#[openmls_test::openmls_test]
+fn post_quantum_key_package() {
+    let (init_key_length, signature_key_length) = match ciphersuite {
+        Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65 => (1184, 1952),
+        Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87 => (1568, 2592),
+        _ => return,
+    };
+
+    let (key_package, _, _) = key_package(ciphersuite, provider);
+    let key_package = key_package.key_package();
+    assert_eq!(
+        key_package.hpke_init_key().as_slice().len(),
+        init_key_length
+    );
+    assert_eq!(
+        key_package.leaf_node().signature_key().as_slice().len(),
+        signature_key_length
+    );
+
+    let encoded = key_package
+        .tls_serialize_detached()
+        .expect("An unexpected error occurred.");
+    let decoded = KeyPackageIn::tls_deserialize(&mut encoded.as_slice())
+        .expect("An unexpected error occurred.")
+        .validate(provider.crypto(), ProtocolVersion::Mls10)
+        .expect("Invalid post-quantum key package.");
+    assert_eq!(key_package, &decoded);
+}
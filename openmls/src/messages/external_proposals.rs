@@ -1,24 +1,34 @@
 //! External Proposals
 //!
-//! Contains the types and methods to build external proposal to add/remove a client from a MLS group
+//! Contains the types and methods to build external proposals for a MLS group.
 //!
-//! `Add` (from external sender), `Remove` & `ReInit are not yet implemented`
+//! A [`JoinProposal`] is sent by a client that wants to join the group. An
+//! [`ExternalProposal`] is sent by a sender listed in the group's
+//! [`ExternalSendersExtension`](crate::extensions::ExternalSendersExtension)
+//! and can be any of the proposal types an external sender may send: Add,
+//! Remove, PreSharedKey, ReInit, GroupContextExtensions and custom proposals.
 
 use crate::{
     binary_tree::LeafNodeIndex,
-    extensions::SenderExtensionIndex,
+    error::LibraryError,
+    extensions::{Extensions, SenderExtensionIndex},
     framing::{mls_auth_content::AuthenticatedContent, MlsMessageOut, PublicMessage},
     group::{
-        errors::ProposeRemoveMemberError, mls_group::errors::ProposeAddMemberError, GroupEpoch,
-        GroupId,
+        errors::{ProposalError, ProposeRemoveMemberError},
+        mls_group::errors::ProposeAddMemberError,
+        GroupEpoch, GroupId,
     },
     key_packages::KeyPackage,
     messages::{AddProposal, Proposal},
+    schedule::PreSharedKeyId,
     storage::{OpenMlsProvider, StorageProvider},
 };
 use openmls_traits::signatures::Signer;
 
-use super::proposals::RemoveProposal;
+use super::proposals::{
+    CustomProposal, GroupContextExtensionProposal, PreSharedKeyProposal, ReInitProposal,
+    RemoveProposal,
+};
 
 /// External Add Proposal where sender is [NewMemberProposal](crate::prelude::Sender::NewMemberProposal). A client
 /// outside the group can request joining the group. This proposal should then be committed by a
@@ -27,8 +37,9 @@ use super::proposals::RemoveProposal;
 pub struct JoinProposal;
 
 /// External Proposal where sender is [External](crate::prelude::Sender::External). A party
-/// outside the group can request to add or remove a member to the group. This proposal should then
-/// be committed by a group member. The sender must be pre configured within the group through the [crate::extensions::ExternalSendersExtension]
+/// outside the group can request to add or remove a member, inject a PSK, reinitialize the group or
+/// change its extensions. This proposal should then be committed by a group member. The sender must
+/// be pre configured within the group through the [crate::extensions::ExternalSendersExtension]
 pub struct ExternalProposal;
 
 impl JoinProposal {
@@ -60,6 +71,33 @@ impl JoinProposal {
 }
 
 impl ExternalProposal {
+    /// Creates an external Add proposal. For delivery services requesting to add a client.
+    /// This proposal will have to be committed later by a group member.
+    ///
+    /// # Arguments
+    /// * `key_package` - of the client to add
+    /// * `group_id` - unique group identifier of the group
+    /// * `epoch` - group's epoch
+    /// * `signer` - of the sender to sign the message
+    /// * `sender` - index of the sender of the proposal (in the [crate::extensions::ExternalSendersExtension] array
+    ///   from the Group Context)
+    pub fn new_add<Provider: OpenMlsProvider>(
+        key_package: KeyPackage,
+        group_id: GroupId,
+        epoch: GroupEpoch,
+        signer: &impl Signer,
+        sender_index: SenderExtensionIndex,
+    ) -> Result<MlsMessageOut, ProposeAddMemberError<Provider::StorageError>> {
+        Self::sign_proposal(
+            Proposal::Add(AddProposal { key_package }),
+            group_id,
+            epoch,
+            signer,
+            sender_index,
+        )
+        .map_err(ProposeAddMemberError::from)
+    }
+
     /// Creates an external Remove proposal. For delivery services requesting to remove a client.
     /// This proposal will have to be committed later by a group member.
     ///
@@ -77,15 +115,138 @@ impl ExternalProposal {
         signer: &impl Signer,
         sender_index: SenderExtensionIndex,
     ) -> Result<MlsMessageOut, ProposeRemoveMemberError<Provider::StorageError>> {
-        AuthenticatedContent::new_external_proposal(
+        Self::sign_proposal(
             Proposal::Remove(RemoveProposal { removed }),
             group_id,
             epoch,
             signer,
             sender_index,
         )
-        .map(PublicMessage::from)
-        .map(MlsMessageOut::from)
         .map_err(ProposeRemoveMemberError::from)
     }
+
+    /// Creates an external PreSharedKey proposal. For delivery services requesting to inject a
+    /// PSK into the key schedule. This proposal will have to be committed later by a group member.
+    ///
+    /// # Arguments
+    /// * `psk_id` - of the PSK to inject
+    /// * `group_id` - unique group identifier of the group
+    /// * `epoch` - group's epoch
+    /// * `signer` - of the sender to sign the message
+    /// * `sender` - index of the sender of the proposal (in the [crate::extensions::ExternalSendersExtension] array
+    ///   from the Group Context)
+    pub fn new_psk<Provider: OpenMlsProvider>(
+        psk_id: PreSharedKeyId,
+        group_id: GroupId,
+        epoch: GroupEpoch,
+        signer: &impl Signer,
+        sender_index: SenderExtensionIndex,
+    ) -> Result<MlsMessageOut, ProposalError<Provider::StorageError>> {
+        Self::sign_proposal(
+            Proposal::PreSharedKey(PreSharedKeyProposal::new(psk_id)),
+            group_id,
+            epoch,
+            signer,
+            sender_index,
+        )
+        .map_err(ProposalError::from)
+    }
+
+    /// Creates an external ReInit proposal. For delivery services requesting to reinitialize
+    /// the group with new parameters. This proposal will have to be committed later by a group
+    /// member.
+    ///
+    /// Note that [`MlsGroup`](crate::group::MlsGroup) does not commit ReInit proposals yet.
+    ///
+    /// # Arguments
+    /// * `reinit` - the parameters of the new group
+    /// * `group_id` - unique group identifier of the group
+    /// * `epoch` - group's epoch
+    /// * `signer` - of the sender to sign the message
+    /// * `sender` - index of the sender of the proposal (in the [crate::extensions::ExternalSendersExtension] array
+    ///   from the Group Context)
+    pub fn new_reinit<Provider: OpenMlsProvider>(
+        reinit: ReInitProposal,
+        group_id: GroupId,
+        epoch: GroupEpoch,
+        signer: &impl Signer,
+        sender_index: SenderExtensionIndex,
+    ) -> Result<MlsMessageOut, ProposalError<Provider::StorageError>> {
+        Self::sign_proposal(
+            Proposal::ReInit(reinit),
+            group_id,
+            epoch,
+            signer,
+            sender_index,
+        )
+        .map_err(ProposalError::from)
+    }
+
+    /// Creates an external GroupContextExtensions proposal. For delivery services requesting to
+    /// replace the extensions in the group context. This proposal will have to be committed later
+    /// by a group member.
+    ///
+    /// # Arguments
+    /// * `extensions` - the new group context extensions
+    /// * `group_id` - unique group identifier of the group
+    /// * `epoch` - group's epoch
+    /// * `signer` - of the sender to sign the message
+    /// * `sender` - index of the sender of the proposal (in the [crate::extensions::ExternalSendersExtension] array
+    ///   from the Group Context)
+    pub fn new_group_context_extensions<Provider: OpenMlsProvider>(
+        extensions: Extensions,
+        group_id: GroupId,
+        epoch: GroupEpoch,
+        signer: &impl Signer,
+        sender_index: SenderExtensionIndex,
+    ) -> Result<MlsMessageOut, ProposalError<Provider::StorageError>> {
+        Self::sign_proposal(
+            Proposal::GroupContextExtensions(GroupContextExtensionProposal::new(extensions)),
+            group_id,
+            epoch,
+            signer,
+            sender_index,
+        )
+        .map_err(ProposalError::from)
+    }
+
+    /// Creates an external custom proposal. The proposal type must be supported by all members
+    /// of the group. This proposal will have to be committed later by a group member.
+    ///
+    /// # Arguments
+    /// * `custom_proposal` - the custom proposal
+    /// * `group_id` - unique group identifier of the group
+    /// * `epoch` - group's epoch
+    /// * `signer` - of the sender to sign the message
+    /// * `sender` - index of the sender of the proposal (in the [crate::extensions::ExternalSendersExtension] array
+    ///   from the Group Context)
+    pub fn new_custom<Provider: OpenMlsProvider>(
+        custom_proposal: CustomProposal,
+        group_id: GroupId,
+        epoch: GroupEpoch,
+        signer: &impl Signer,
+        sender_index: SenderExtensionIndex,
+    ) -> Result<MlsMessageOut, ProposalError<Provider::StorageError>> {
+        Self::sign_proposal(
+            Proposal::Custom(custom_proposal),
+            group_id,
+            epoch,
+            signer,
+            sender_index,
+        )
+        .map_err(ProposalError::from)
+    }
+
+    /// Signs the `proposal` as the external sender with the given `sender_index`.
+    fn sign_proposal(
+        proposal: Proposal,
+        group_id: GroupId,
+        epoch: GroupEpoch,
+        signer: &impl Signer,
+        sender_index: SenderExtensionIndex,
+    ) -> Result<MlsMessageOut, LibraryError> {
+        AuthenticatedContent::new_external_proposal(proposal, group_id, epoch, signer, sender_index)
+            .map(PublicMessage::from)
+            .map(MlsMessageOut::from)
+    }
 }
@@ -6,6 +6,7 @@
 use hash_ref::HashReference;
 use openmls_traits::{
     crypto::OpenMlsCrypto,
+    key_handles::{KeyHandle, KeyHandleProvider},
     types::{Ciphersuite, HpkeCiphertext, HpkeKeyPair},
 };
 use serde::{Deserialize, Serialize};
@@ -178,7 +179,6 @@ impl Commit {
     }
 
     /// Returns the update path of the Commit if it has one.
-    #[cfg(test)]
     pub(crate) fn path(&self) -> &Option<UpdatePath> {
         &self.path
     }
@@ -454,11 +454,35 @@ impl GroupSecrets {
             hpke::decrypt_with_label(skey, "Welcome", context, ciphertext, ciphersuite, crypto)
                 .map_err(|_| GroupSecretsError::DecryptionFailed)?;
 
-        // Note: This also checks that no extraneous data was encrypted.
-        let group_secrets = GroupSecrets::tls_deserialize_exact(group_secrets_plaintext)
-            .map_err(|_| GroupSecretsError::Malformed)?;
+        Self::from_plaintext(group_secrets_plaintext)
+    }
 
-        Ok(group_secrets)
+    /// Try to decrypt (and parse) a ciphertext into group secrets with a
+    /// private key that is held by the `key_handles` provider.
+    pub(crate) fn try_from_ciphertext_with_handle(
+        key_handles: &dyn KeyHandleProvider,
+        handle: &KeyHandle,
+        ciphertext: &HpkeCiphertext,
+        context: &[u8],
+        ciphersuite: Ciphersuite,
+    ) -> Result<Self, GroupSecretsError> {
+        let group_secrets_plaintext = hpke::decrypt_with_label_and_handle(
+            key_handles,
+            handle,
+            "Welcome",
+            context,
+            ciphertext,
+            ciphersuite,
+        )
+        .map_err(|_| GroupSecretsError::DecryptionFailed)?;
+
+        Self::from_plaintext(group_secrets_plaintext)
+    }
+
+    fn from_plaintext(group_secrets_plaintext: Vec<u8>) -> Result<Self, GroupSecretsError> {
+        // Note: This also checks that no extraneous data was encrypted.
+        GroupSecrets::tls_deserialize_exact(group_secrets_plaintext)
+            .map_err(|_| GroupSecretsError::Malformed)
     }
 
     /// Create new encoded group secrets.
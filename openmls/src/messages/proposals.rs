@@ -68,6 +68,7 @@ use crate::{
 /// | Value  | Name    | Recommended | Path Required | Reference | Notes                        |
 /// |:=======|:========|:============|:==============|:==========|:=============================|
 /// | 0x0008 | app_ack | Y           | Y             | RFC XXXX  | draft-ietf-mls-extensions-00 |
+/// | 0x000A | self_remove | Y       | Y             | RFC XXXX  | draft-ietf-mls-extensions-04 |
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Serialize, Deserialize, Hash)]
 #[allow(missing_docs)]
 pub enum ProposalType {
@@ -79,6 +80,7 @@ pub enum ProposalType {
     ExternalInit,
     GroupContextExtensions,
     AppAck,
+    SelfRemove,
     Custom(u16),
 }
 
@@ -125,7 +127,11 @@ impl ProposalType {
     pub fn is_path_required(&self) -> bool {
         matches!(
             self,
-            Self::Update | Self::Remove | Self::ExternalInit | Self::GroupContextExtensions
+            Self::Update
+                | Self::Remove
+                | Self::ExternalInit
+                | Self::GroupContextExtensions
+                | Self::SelfRemove
         )
     }
 }
@@ -141,6 +147,7 @@ impl From<u16> for ProposalType {
             6 => ProposalType::ExternalInit,
             7 => ProposalType::GroupContextExtensions,
             8 => ProposalType::AppAck,
+            10 => ProposalType::SelfRemove,
             other => ProposalType::Custom(other),
         }
     }
@@ -157,6 +164,7 @@ impl From<ProposalType> for u16 {
             ProposalType::ExternalInit => 6,
             ProposalType::GroupContextExtensions => 7,
             ProposalType::AppAck => 8,
+            ProposalType::SelfRemove => 10,
             ProposalType::Custom(id) => id,
         }
     }
@@ -197,6 +205,7 @@ pub enum Proposal {
     // TODO(#916): `AppAck` is not in draft-ietf-mls-protocol-17 but
     //             was moved to `draft-ietf-mls-extensions-00`.
     AppAck(AppAckProposal),
+    SelfRemove,
     Custom(CustomProposal),
 }
 
@@ -212,6 +221,7 @@ impl Proposal {
             Proposal::ExternalInit(_) => ProposalType::ExternalInit,
             Proposal::GroupContextExtensions(_) => ProposalType::GroupContextExtensions,
             Proposal::AppAck(_) => ProposalType::AppAck,
+            Proposal::SelfRemove => ProposalType::SelfRemove,
             Proposal::Custom(CustomProposal {
                 proposal_type,
                 payload: _,
@@ -227,6 +237,22 @@ impl Proposal {
     pub fn is_path_required(&self) -> bool {
         self.proposal_type().is_path_required()
     }
+
+    /// Returns `true` if the proposal may be sent by an
+    /// [External](crate::prelude::Sender::External) sender, i.e. if it is an
+    /// Add, Remove, PreSharedKey, ReInit, GroupContextExtensions or custom
+    /// proposal.
+    pub fn is_valid_for_external_sender(&self) -> bool {
+        matches!(
+            self,
+            Proposal::Add(_)
+                | Proposal::Remove(_)
+                | Proposal::PreSharedKey(_)
+                | Proposal::ReInit(_)
+                | Proposal::GroupContextExtensions(_)
+                | Proposal::Custom(_)
+        )
+    }
 }
 
 /// Add Proposal.
@@ -382,6 +408,23 @@ pub struct ReInitProposal {
     pub(crate) extensions: Extensions,
 }
 
+impl ReInitProposal {
+    /// Create a new [`ReInitProposal`] for a group with the given parameters.
+    pub fn new(
+        group_id: GroupId,
+        version: ProtocolVersion,
+        ciphersuite: Ciphersuite,
+        extensions: Extensions,
+    ) -> Self {
+        Self {
+            group_id,
+            version,
+            ciphersuite,
+            extensions,
+        }
+    }
+}
+
 /// ExternalInit Proposal.
 ///
 /// An ExternalInit proposal is used by new members that want to join a group by using an external
@@ -42,7 +42,10 @@ pub use crate::binary_tree::LeafNodeIndex;
 // TreeSync
 pub use crate::treesync::{
     errors::{ApplyUpdatePathError, PublicTreeError},
-    node::leaf_node::{Capabilities, CapabilitiesBuilder, LeafNode, LeafNodeParameters},
+    node::leaf_node::{
+        Capabilities, CapabilitiesBuilder, LeafNode, LeafNodeIn, LeafNodeParameters,
+        LeafNodeValidationReport, ValidationCheck, ValidationCheckResult, ValidationOutcome,
+    },
     node::parent_node::ParentNode,
     node::Node,
     RatchetTreeIn,
@@ -749,6 +749,30 @@ impl ExporterSecret {
             .as_slice()
             .to_vec())
     }
+
+    /// Derive the safe exported secret of the application component with the
+    /// given `component_id` as defined in draft-ietf-mls-extensions. This
+    /// function is made available for use from the outside through
+    /// [`MlsGroup::safe_export_secret`].
+    pub(crate) fn derive_safe_exported_secret(
+        &self,
+        ciphersuite: Ciphersuite,
+        crypto: &impl OpenMlsCrypto,
+        component_id: u32,
+    ) -> Result<Vec<u8>, CryptoError> {
+        Ok(self
+            .secret
+            .derive_secret(crypto, ciphersuite, "application_export")?
+            .kdf_expand_label(
+                crypto,
+                ciphersuite,
+                "ExportSecret",
+                &component_id.to_be_bytes(),
+                ciphersuite.hash_length(),
+            )?
+            .as_slice()
+            .to_vec())
+    }
 }
 
 /// A secret used when joining a group with an external Commit.
@@ -446,7 +446,10 @@ fn test(ciphersuite: Ciphersuite, provider: &Provider) {
         serde_json::from_reader(file).unwrap()
     };
 
-    let KatData { group_id, storages } = data.remove(&ciphersuite).unwrap();
+    let Some(KatData { group_id, storages }) = data.remove(&ciphersuite) else {
+        log::debug!("Skipping ciphersuite {ciphersuite:?} without test vectors");
+        return;
+    };
 
     // parse base64-encoded serialized storage
     let mut storages = storages
@@ -162,7 +162,8 @@ impl<Provider: OpenMlsProvider> Client<Provider> {
                 .map_err(ClientError::ProcessMessageError)?;
 
             match processed_message.into_content() {
-                ProcessedMessageContent::ApplicationMessage(_) => {}
+                ProcessedMessageContent::ApplicationMessage(_)
+                | ProcessedMessageContent::TargetedMessage(_) => {}
                 ProcessedMessageContent::ProposalMessage(staged_proposal) => {
                     group_state
                         .store_pending_proposal(self.provider.storage(), *staged_proposal)?;
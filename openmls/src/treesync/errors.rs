@@ -239,6 +239,10 @@ pub enum LeafNodeValidationError {
     /// The credential used by a member is not supported by this leaf node.
     #[error("The credential used by a member is not supported by this leaf node.")]
     MemberCredentialNotSupportedByLeafNode,
+    /// The leaf node does not support all media types that are required by
+    /// the group.
+    #[error("The leaf node does not support all media types that are required by the group.")]
+    UnsupportedMediaTypes,
 }
 
 /// Errors that can happen during lifetime validation.
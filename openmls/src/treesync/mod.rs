@@ -81,7 +81,10 @@ pub use node::encryption_keys::EncryptionKey;
 
 // Public re-exports
 pub use node::{
-    leaf_node::{LeafNode, LeafNodeParameters, LeafNodeParametersBuilder, LeafNodeUpdateError},
+    leaf_node::{
+        LeafNode, LeafNodeIn, LeafNodeParameters, LeafNodeParametersBuilder, LeafNodeUpdateError,
+        LeafNodeValidationReport, ValidationCheck, ValidationCheckResult, ValidationOutcome,
+    },
     parent_node::ParentNode,
     Node,
 };
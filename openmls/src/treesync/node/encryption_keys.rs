@@ -62,6 +62,26 @@ impl EncryptionKey {
         )
         .map_err(|_| LibraryError::custom("Encryption failed. A serialization issue really"))
     }
+
+    /// Encrypt to this HPKE public key with the given `label`.
+    pub(crate) fn encrypt_with_label(
+        &self,
+        crypto: &impl OpenMlsCrypto,
+        ciphersuite: Ciphersuite,
+        label: &str,
+        context: &[u8],
+        plaintext: &[u8],
+    ) -> Result<HpkeCiphertext, LibraryError> {
+        hpke::encrypt_with_label(
+            self.as_slice(),
+            label,
+            context,
+            plaintext,
+            ciphersuite,
+            crypto,
+        )
+        .map_err(|_| LibraryError::custom("Encryption failed. A serialization issue really"))
+    }
 }
 
 impl From<Vec<u8>> for EncryptionKey {
@@ -127,6 +147,19 @@ impl EncryptionPrivateKey {
         )
         .map(|secret_bytes| Secret::from_slice(&secret_bytes))
     }
+
+    /// Decrypt a given `HpkeCiphertext` that was encrypted with the given
+    /// `label` using this [`EncryptionPrivateKey`].
+    pub(crate) fn decrypt_with_label(
+        &self,
+        crypto: &impl OpenMlsCrypto,
+        ciphersuite: Ciphersuite,
+        label: &str,
+        context: &[u8],
+        ciphertext: &HpkeCiphertext,
+    ) -> Result<Vec<u8>, hpke::Error> {
+        hpke::decrypt_with_label(&self.key, label, context, ciphertext, ciphersuite, crypto)
+    }
 }
 
 #[cfg(any(test, feature = "test-utils"))]
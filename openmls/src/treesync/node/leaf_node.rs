@@ -27,8 +27,13 @@ use crate::treesync::errors::LeafNodeValidationError;
 
 mod capabilities;
 mod codec;
+mod validation_report;
 
 pub use capabilities::*;
+pub(crate) use validation_report::ValidationChecks;
+pub use validation_report::{
+    LeafNodeValidationReport, ValidationCheck, ValidationCheckResult, ValidationOutcome,
+};
 
 pub(crate) struct NewLeafNodeParams {
     pub(crate) ciphersuite: Ciphersuite,
@@ -230,7 +235,7 @@ impl LeafNode {
 
     /// Create a new leaf node with a given HPKE encryption key pair.
     /// The key pair must be stored in the key store by the caller.
-    fn new_with_key(
+    pub(crate) fn new_with_key(
         encryption_key: EncryptionKey,
         credential_with_key: CredentialWithKey,
         leaf_node_source: LeafNodeSource,
@@ -435,11 +440,7 @@ impl LeafNode {
 
     /// Returns `true` if the [`ExtensionType`] is supported by this leaf node.
     pub(crate) fn supports_extension(&self, extension_type: &ExtensionType) -> bool {
-        self.payload
-            .capabilities
-            .extensions
-            .contains(extension_type)
-            || default_extensions().iter().any(|et| et == extension_type)
+        self.payload.supports_extension(extension_type)
     }
     ///
     /// Check whether the this leaf node supports all the required extensions
@@ -503,6 +504,15 @@ struct LeafNodePayload {
     extensions: Extensions,
 }
 
+impl LeafNodePayload {
+    /// Returns `true` if the [`ExtensionType`] is supported by the
+    /// capabilities or is a default extension.
+    fn supports_extension(&self, extension_type: &ExtensionType) -> bool {
+        self.capabilities.extensions.contains(extension_type)
+            || default_extensions().iter().any(|et| et == extension_type)
+    }
+}
+
 #[derive(
     Debug,
     Clone,
@@ -656,6 +666,7 @@ const LEAF_NODE_SIGNATURE_LABEL: &str = "LeafNodeTBS";
     TlsDeserializeBytes,
     TlsSize,
 )]
+/// An incoming [`LeafNode`] that hasn't been validated yet.
 pub struct LeafNodeIn {
     payload: LeafNodePayload,
     signature: Signature,
@@ -699,6 +710,26 @@ impl LeafNodeIn {
     pub fn credential(&self) -> &Credential {
         &self.payload.credential
     }
+
+    /// Returns the encryption key.
+    pub fn encryption_key(&self) -> &EncryptionKey {
+        &self.payload.encryption_key
+    }
+
+    /// Returns the capabilities.
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.payload.capabilities
+    }
+
+    /// Returns the leaf node source.
+    pub fn leaf_node_source(&self) -> &LeafNodeSource {
+        &self.payload.leaf_node_source
+    }
+
+    /// Returns `true` if the [`ExtensionType`] is supported by this leaf node.
+    pub(crate) fn supports_extension(&self, extension_type: &ExtensionType) -> bool {
+        self.payload.supports_extension(extension_type)
+    }
 }
 
 impl From<LeafNode> for LeafNodeIn {
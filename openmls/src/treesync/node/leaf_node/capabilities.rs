@@ -5,7 +5,7 @@ use tls_codec::{TlsDeserialize, TlsDeserializeBytes, TlsSerialize, TlsSize};
 #[cfg(doc)]
 use super::LeafNode;
 use crate::{
-    credentials::CredentialType,
+    credentials::{registered_credential_types, CredentialType},
     extensions::{Extension, ExtensionType, Extensions, RequiredCapabilitiesExtension},
     messages::proposals::ProposalType,
     treesync::errors::LeafNodeValidationError,
@@ -47,7 +47,9 @@ impl Capabilities {
     /// Create a new [`Capabilities`] struct with the given configuration.
     /// Any argument that is `None` is filled with the default values from the
     /// global configuration.
-    // TODO(#1232)
+    ///
+    /// See [`Capabilities::builder()`] for a fluent API that can also derive
+    /// the capabilities a group requires.
     pub fn new(
         versions: Option<&[ProtocolVersion]>,
         ciphersuites: Option<&[Ciphersuite]>,
@@ -158,13 +160,47 @@ impl Capabilities {
         if required_capabilities
             .credential_types()
             .iter()
-            .any(|c| !(self.credentials().contains(c) || default_credentials().contains(c)))
+            .any(|c| !(self.credentials().contains(c) || *c == CredentialType::Basic))
         {
             return Err(LeafNodeValidationError::UnsupportedCredentials);
         }
         Ok(())
     }
 
+    /// Check if these [`Capabilities`] support everything a group with the
+    /// given `group_context_extensions` requires from its members and all of
+    /// the `leaf_node_extensions`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LeafNodeValidationError`] error if any of the required
+    /// capabilities is not supported.
+    pub(crate) fn check_group_requirements(
+        &self,
+        group_context_extensions: &Extensions,
+        leaf_node_extensions: &Extensions,
+    ) -> Result<(), LeafNodeValidationError> {
+        if let Some(required_capabilities) = group_context_extensions.required_capabilities() {
+            self.supports_required_capabilities(required_capabilities)?;
+        }
+        if !group_context_extensions
+            .iter()
+            .map(Extension::extension_type)
+            .all(|e| self.extensions().contains(&e) || default_extensions().contains(&e))
+        {
+            return Err(LeafNodeValidationError::UnsupportedExtensions);
+        }
+        if !self.contain_extensions(leaf_node_extensions) {
+            return Err(LeafNodeValidationError::ExtensionsNotInCapabilities);
+        }
+        if let Some(required_media_types) = group_context_extensions.required_media_types() {
+            if !required_media_types.is_supported_by(leaf_node_extensions) {
+                return Err(LeafNodeValidationError::UnsupportedMediaTypes);
+            }
+        }
+        Ok(())
+    }
+
     /// Check if these [`Capabilities`] contain all the extensions.
     pub(crate) fn contain_extensions(&self, extension: &Extensions) -> bool {
         extension
@@ -223,6 +259,79 @@ impl CapabilitiesBuilder {
         })
     }
 
+    /// Adds the `extensions` to the `extensions` field on the
+    /// [`Capabilities`]. Extension types that are already listed or that are
+    /// supported by default are skipped.
+    pub fn add_extensions(mut self, extensions: &[ExtensionType]) -> Self {
+        for extension in extensions {
+            if !(self.0.extensions.contains(extension) || default_extensions().contains(extension))
+            {
+                self.0.extensions.push(*extension);
+            }
+        }
+        self
+    }
+
+    /// Adds the `proposals` to the `proposals` field on the [`Capabilities`].
+    /// Proposal types that are already listed or that are supported by default
+    /// are skipped.
+    pub fn add_proposals(mut self, proposals: &[ProposalType]) -> Self {
+        for proposal in proposals {
+            if !(self.0.proposals.contains(proposal) || default_proposals().contains(proposal)) {
+                self.0.proposals.push(*proposal);
+            }
+        }
+        self
+    }
+
+    /// Adds the `credentials` to the `credentials` field on the
+    /// [`Capabilities`]. Credential types that are already listed are skipped.
+    pub fn add_credentials(mut self, credentials: &[CredentialType]) -> Self {
+        for credential in credentials {
+            if !self.0.credentials.contains(credential) {
+                self.0.credentials.push(*credential);
+            }
+        }
+        self
+    }
+
+    /// Adds everything that is required by the `required_capabilities`.
+    pub fn for_required_capabilities(
+        self,
+        required_capabilities: &RequiredCapabilitiesExtension,
+    ) -> Self {
+        self.add_extensions(required_capabilities.extension_types())
+            .add_proposals(required_capabilities.proposal_types())
+            .add_credentials(required_capabilities.credential_types())
+    }
+
+    /// Adds everything that is required to be a member of a group with the
+    /// given `group_context_extensions`: the types of the extensions
+    /// themselves and everything that is required by a
+    /// [`RequiredCapabilitiesExtension`] among them.
+    pub fn for_group_context_extensions(self, group_context_extensions: &Extensions) -> Self {
+        let extension_types: Vec<ExtensionType> = group_context_extensions
+            .iter()
+            .map(Extension::extension_type)
+            .collect();
+        let builder = self.add_extensions(&extension_types);
+        match group_context_extensions.required_capabilities() {
+            Some(required_capabilities) => builder.for_required_capabilities(required_capabilities),
+            None => builder,
+        }
+    }
+
+    /// Adds the types of the `leaf_node_extensions`. Leaf node extensions
+    /// must always be listed, even if they are supported by default.
+    pub fn for_leaf_node_extensions(mut self, leaf_node_extensions: &Extensions) -> Self {
+        for extension_type in leaf_node_extensions.iter().map(Extension::extension_type) {
+            if !self.0.extensions.contains(&extension_type) {
+                self.0.extensions.push(extension_type);
+            }
+        }
+        self
+    }
+
     /// Builds the [`Capabilities`].
     pub fn build(self) -> Capabilities {
         self.0
@@ -267,6 +376,11 @@ pub(super) fn default_ciphersuites() -> Vec<Ciphersuite> {
         Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256,
         Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519,
         Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519,
+        Ciphersuite::MLS_256_DHKEMP521_AES256GCM_SHA512_P521,
+        Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384,
+        Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65,
+        Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87,
+        Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519,
     ]
 }
 
@@ -293,9 +407,13 @@ pub(super) fn default_proposals() -> Vec<ProposalType> {
     ]
 }
 
-// TODO(#1231)
+/// The basic credential and all registered
+/// [`CredentialKind`](crate::credentials::CredentialKind)s are supported by
+/// default.
 pub(super) fn default_credentials() -> Vec<CredentialType> {
-    vec![CredentialType::Basic]
+    let mut credentials = vec![CredentialType::Basic];
+    credentials.extend(registered_credential_types());
+    credentials
 }
 
 #[cfg(test)]
@@ -0,0 +1,325 @@
+//! Validation reports for leaf nodes and key packages.
+//!
+//! A validation report lists every check that was performed on an incoming
+//! [`LeafNodeIn`] or [`KeyPackageIn`](crate::key_packages::KeyPackageIn)
+//! together with its [`ValidationOutcome`], as well as the contents that were
+//! checked. Reports don't require a group, which makes them useful for
+//! services like key directories that accept key packages and leaf nodes
+//! before they are used in a group.
+
+use openmls_traits::{crypto::OpenMlsCrypto, types::Ciphersuite};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Capabilities, EncryptionKey, LeafNode, LeafNodeIn, LeafNodeSource, TreePosition,
+    VerifiableLeafNode,
+};
+use crate::{
+    binary_tree::array_representation::LeafNodeIndex,
+    ciphersuite::{signable::Verifiable, OpenMlsSignaturePublicKey, SignaturePublicKey},
+    credentials::Credential,
+    extensions::Extensions,
+    group::GroupId,
+    key_packages::Lifetime,
+};
+
+/// A check that is performed when validating a
+/// [`KeyPackageIn`](crate::key_packages::KeyPackageIn) or a [`LeafNodeIn`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ValidationCheck {
+    /// The protocol version of the key package is the expected one.
+    ProtocolVersion,
+    /// The leaf node of the key package was created for a key package.
+    LeafNodeSource,
+    /// The signature on the key package is valid.
+    KeyPackageSignature,
+    /// The init key of the key package and the encryption key of its leaf
+    /// node are different.
+    InitKeyDiffersFromEncryptionKey,
+    /// The key package extensions are supported by the leaf node.
+    KeyPackageExtensionSupport,
+    /// The last-resort extension, if the key package has one, is supported by
+    /// the leaf node.
+    LastResort,
+    /// The signature on the leaf node is valid.
+    LeafNodeSignature,
+    /// The current time is within the lifetime of the leaf node.
+    Lifetime,
+    /// The lifetime range of the leaf node isn't longer than the acceptable
+    /// maximum.
+    LifetimeRange,
+    /// The leaf node extensions are supported by the leaf node.
+    LeafNodeExtensionSupport,
+    /// The credential type of the leaf node is supported by the leaf node.
+    CredentialSupport,
+}
+
+/// The outcome of a [`ValidationCheck`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValidationOutcome {
+    /// The check passed.
+    Passed,
+    /// The check failed.
+    Failed,
+    /// The check was not performed, because it doesn't apply or because
+    /// information that is required for it is missing.
+    Skipped,
+}
+
+impl ValidationOutcome {
+    fn from_passed(passed: bool) -> Self {
+        if passed {
+            Self::Passed
+        } else {
+            Self::Failed
+        }
+    }
+}
+
+/// A [`ValidationCheck`] and its [`ValidationOutcome`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationCheckResult {
+    check: ValidationCheck,
+    outcome: ValidationOutcome,
+}
+
+impl ValidationCheckResult {
+    /// Returns the check.
+    pub fn check(&self) -> ValidationCheck {
+        self.check
+    }
+
+    /// Returns the outcome of the check.
+    pub fn outcome(&self) -> ValidationOutcome {
+        self.outcome
+    }
+}
+
+/// A list of [`ValidationCheckResult`]s.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ValidationChecks(Vec<ValidationCheckResult>);
+
+impl ValidationChecks {
+    /// Records the `outcome` of the `check`.
+    pub(crate) fn record(&mut self, check: ValidationCheck, outcome: ValidationOutcome) {
+        self.0.push(ValidationCheckResult { check, outcome });
+    }
+
+    /// Records whether the `check` `passed`.
+    pub(crate) fn record_passed(&mut self, check: ValidationCheck, passed: bool) {
+        self.record(check, ValidationOutcome::from_passed(passed))
+    }
+
+    pub(crate) fn as_slice(&self) -> &[ValidationCheckResult] {
+        &self.0
+    }
+
+    pub(crate) fn outcome(&self, check: ValidationCheck) -> Option<ValidationOutcome> {
+        self.0
+            .iter()
+            .find(|result| result.check == check)
+            .map(ValidationCheckResult::outcome)
+    }
+
+    pub(crate) fn failed(&self) -> impl Iterator<Item = ValidationCheck> + '_ {
+        self.0
+            .iter()
+            .filter(|result| result.outcome == ValidationOutcome::Failed)
+            .map(ValidationCheckResult::check)
+    }
+}
+
+/// The report of the validation of a [`LeafNodeIn`] without a group. See
+/// [`LeafNodeIn::validation_report()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeafNodeValidationReport {
+    encryption_key: EncryptionKey,
+    signature_key: SignaturePublicKey,
+    credential: Credential,
+    capabilities: Capabilities,
+    leaf_node_source: LeafNodeSource,
+    extensions: Extensions,
+    checks: ValidationChecks,
+}
+
+impl LeafNodeValidationReport {
+    /// Returns the encryption key of the leaf node.
+    pub fn encryption_key(&self) -> &EncryptionKey {
+        &self.encryption_key
+    }
+
+    /// Returns the signature key of the leaf node.
+    pub fn signature_key(&self) -> &SignaturePublicKey {
+        &self.signature_key
+    }
+
+    /// Returns the credential of the leaf node.
+    pub fn credential(&self) -> &Credential {
+        &self.credential
+    }
+
+    /// Returns the capabilities of the leaf node.
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.capabilities
+    }
+
+    /// Returns the source of the leaf node.
+    pub fn leaf_node_source(&self) -> &LeafNodeSource {
+        &self.leaf_node_source
+    }
+
+    /// Returns the lifetime of the leaf node, if it was created for a key
+    /// package.
+    pub fn lifetime(&self) -> Option<&Lifetime> {
+        match &self.leaf_node_source {
+            LeafNodeSource::KeyPackage(lifetime) => Some(lifetime),
+            _ => None,
+        }
+    }
+
+    /// Returns the extensions of the leaf node.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// Returns all checks that were performed or skipped, in the order they
+    /// were performed in.
+    pub fn checks(&self) -> &[ValidationCheckResult] {
+        self.checks.as_slice()
+    }
+
+    /// Returns the outcome of the `check`, or `None` if it is not part of
+    /// this report.
+    pub fn outcome(&self, check: ValidationCheck) -> Option<ValidationOutcome> {
+        self.checks.outcome(check)
+    }
+
+    /// Returns the checks that failed.
+    pub fn failed_checks(&self) -> impl Iterator<Item = ValidationCheck> + '_ {
+        self.checks.failed()
+    }
+
+    /// Returns `true` if no check failed.
+    pub fn is_valid(&self) -> bool {
+        self.failed_checks().next().is_none()
+    }
+}
+
+impl LeafNodeIn {
+    /// Validates this leaf node without a group and reports the outcome of
+    /// every check:
+    ///
+    /// * the signature on the leaf node is valid
+    /// * the current time is within the lifetime
+    /// * the lifetime range isn't longer than the acceptable maximum
+    /// * the leaf node extensions are supported by the leaf node
+    /// * the credential type is supported by the leaf node
+    ///
+    /// The signature is verified with the signature scheme of the
+    /// `ciphersuite`. The signature on leaf nodes that were created in a
+    /// commit or update proposal covers their position in the tree. It can
+    /// only be verified if the `tree_position`, i.e. the group ID and the leaf
+    /// index, is given and is skipped otherwise. The lifetime checks are
+    /// skipped for leaf nodes that were not created for a key package.
+    pub fn validation_report(
+        &self,
+        crypto: &impl OpenMlsCrypto,
+        ciphersuite: Ciphersuite,
+        tree_position: Option<(GroupId, LeafNodeIndex)>,
+    ) -> LeafNodeValidationReport {
+        self.clone()
+            .validate_without_group(crypto, ciphersuite, tree_position)
+            .0
+    }
+
+    /// Validates this leaf node as described in
+    /// [`LeafNodeIn::validation_report()`] and returns the verified
+    /// [`LeafNode`] if the signature is valid.
+    pub(crate) fn validate_without_group(
+        self,
+        crypto: &impl OpenMlsCrypto,
+        ciphersuite: Ciphersuite,
+        tree_position: Option<(GroupId, LeafNodeIndex)>,
+    ) -> (LeafNodeValidationReport, Option<LeafNode>) {
+        let mut checks = ValidationChecks::default();
+        let payload = self.payload.clone();
+
+        let signature_key = OpenMlsSignaturePublicKey::from_signature_key(
+            payload.signature_key.clone(),
+            ciphersuite.signature_algorithm(),
+        );
+        let verified = match (self.into_verifiable_leaf_node(), tree_position) {
+            (VerifiableLeafNode::KeyPackage(leaf_node), _) => {
+                Some(leaf_node.verify(crypto, &signature_key))
+            }
+            (VerifiableLeafNode::Update(mut leaf_node), Some((group_id, leaf_index))) => {
+                leaf_node.add_tree_position(TreePosition::new(group_id, leaf_index));
+                Some(leaf_node.verify(crypto, &signature_key))
+            }
+            (VerifiableLeafNode::Commit(mut leaf_node), Some((group_id, leaf_index))) => {
+                leaf_node.add_tree_position(TreePosition::new(group_id, leaf_index));
+                Some(leaf_node.verify(crypto, &signature_key))
+            }
+            _ => None,
+        };
+        let leaf_node = match verified {
+            Some(Ok(leaf_node)) => {
+                checks.record(
+                    ValidationCheck::LeafNodeSignature,
+                    ValidationOutcome::Passed,
+                );
+                Some(leaf_node)
+            }
+            Some(Err(_)) => {
+                checks.record(
+                    ValidationCheck::LeafNodeSignature,
+                    ValidationOutcome::Failed,
+                );
+                None
+            }
+            None => {
+                checks.record(
+                    ValidationCheck::LeafNodeSignature,
+                    ValidationOutcome::Skipped,
+                );
+                None
+            }
+        };
+
+        if let LeafNodeSource::KeyPackage(lifetime) = &payload.leaf_node_source {
+            checks.record_passed(ValidationCheck::Lifetime, lifetime.is_valid());
+            checks.record_passed(
+                ValidationCheck::LifetimeRange,
+                lifetime.has_acceptable_range(),
+            );
+        } else {
+            checks.record(ValidationCheck::Lifetime, ValidationOutcome::Skipped);
+            checks.record(ValidationCheck::LifetimeRange, ValidationOutcome::Skipped);
+        }
+
+        checks.record_passed(
+            ValidationCheck::LeafNodeExtensionSupport,
+            payload
+                .extensions
+                .iter()
+                .all(|extension| payload.supports_extension(&extension.extension_type())),
+        );
+        checks.record_passed(
+            ValidationCheck::CredentialSupport,
+            payload
+                .capabilities
+                .contains_credential(&payload.credential.credential_type()),
+        );
+
+        let report = LeafNodeValidationReport {
+            encryption_key: payload.encryption_key,
+            signature_key: payload.signature_key,
+            credential: payload.credential,
+            capabilities: payload.capabilities,
+            leaf_node_source: payload.leaf_node_source,
+            extensions: payload.extensions,
+            checks,
+        };
+        (report, leaf_node)
+    }
+}
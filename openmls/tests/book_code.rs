@@ -121,10 +121,10 @@ fn book_operations() {
         .expect("error adding external senders extension to group context extensions")
         .ciphersuite(ciphersuite)
         .capabilities(Capabilities::new(
-            None, // Defaults to the group's protocol version
-            None, // Defaults to the group's ciphersuite
-            None, // Defaults to all basic extension types
-            None, // Defaults to all basic proposal types
+            None,                                    // Defaults to the group's protocol version
+            None,                                    // Defaults to the group's ciphersuite
+            Some(&[ExtensionType::Unknown(0xff00)]), // The example leaf extension
+            None,                                    // Defaults to all basic proposal types
             Some(&[CredentialType::Basic]),
         ))
         // Example leaf extension
@@ -0,0 +1,124 @@
+//! An in-process implementation of the [`KeyHandleProvider`] trait.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        RwLock,
+    },
+};
+
+use openmls_traits::{
+    crypto::OpenMlsCrypto,
+    key_handles::{KeyHandle, KeyHandleProvider},
+    random::OpenMlsRand,
+    types::{CryptoError, HpkeCiphertext, HpkeConfig, HpkePrivateKey, SignatureScheme},
+};
+
+use crate::RustCrypto;
+
+/// The length of the input key material for HPKE key pairs.
+const HPKE_IKM_LENGTH: usize = 32;
+
+enum PrivateKey {
+    Signature(SignatureScheme, Vec<u8>),
+    Hpke(HpkePrivateKey),
+}
+
+/// A [`KeyHandleProvider`] that keeps the private keys in memory.
+///
+/// This is a software token for testing code that uses key handles. It
+/// doesn't protect the private keys any better than a regular key pair.
+#[derive(Default)]
+pub struct SoftwareKeyHandles {
+    crypto: RustCrypto,
+    keys: RwLock<HashMap<KeyHandle, PrivateKey>>,
+    next_id: AtomicU64,
+}
+
+impl SoftwareKeyHandles {
+    /// Returns `true` if the token holds a private key for the `handle`.
+    pub fn contains(&self, handle: &KeyHandle) -> bool {
+        self.keys
+            .read()
+            .map(|keys| keys.contains_key(handle))
+            .unwrap_or(false)
+    }
+
+    fn insert(&self, private_key: PrivateKey) -> Result<KeyHandle, CryptoError> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let handle = KeyHandle::new(id.to_be_bytes().to_vec());
+        self.keys
+            .write()
+            .map_err(|_| CryptoError::CryptoLibraryError)?
+            .insert(handle.clone(), private_key);
+        Ok(handle)
+    }
+}
+
+impl KeyHandleProvider for SoftwareKeyHandles {
+    fn generate_signature_key(
+        &self,
+        alg: SignatureScheme,
+    ) -> Result<(KeyHandle, Vec<u8>), CryptoError> {
+        let (private_key, public_key) = self.crypto.signature_key_gen(alg)?;
+        let handle = self.insert(PrivateKey::Signature(alg, private_key))?;
+        Ok((handle, public_key))
+    }
+
+    fn sign(
+        &self,
+        handle: &KeyHandle,
+        alg: SignatureScheme,
+        data: &[u8],
+    ) -> Result<Vec<u8>, CryptoError> {
+        let keys = self
+            .keys
+            .read()
+            .map_err(|_| CryptoError::CryptoLibraryError)?;
+        match keys.get(handle) {
+            Some(PrivateKey::Signature(scheme, key)) if *scheme == alg => {
+                self.crypto.sign(alg, data, key)
+            }
+            Some(PrivateKey::Signature(..)) => Err(CryptoError::UnsupportedSignatureScheme),
+            _ => Err(CryptoError::InvalidKeyHandle),
+        }
+    }
+
+    fn generate_hpke_key(&self, config: HpkeConfig) -> Result<(KeyHandle, Vec<u8>), CryptoError> {
+        let ikm = self
+            .crypto
+            .random_vec(HPKE_IKM_LENGTH)
+            .map_err(|_| CryptoError::InsufficientRandomness)?;
+        let key_pair = self.crypto.derive_hpke_keypair(config, &ikm)?;
+        let handle = self.insert(PrivateKey::Hpke(key_pair.private))?;
+        Ok((handle, key_pair.public))
+    }
+
+    fn hpke_open(
+        &self,
+        handle: &KeyHandle,
+        config: HpkeConfig,
+        input: &HpkeCiphertext,
+        info: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, CryptoError> {
+        let keys = self
+            .keys
+            .read()
+            .map_err(|_| CryptoError::CryptoLibraryError)?;
+        match keys.get(handle) {
+            Some(PrivateKey::Hpke(key)) => self.crypto.hpke_open(config, input, key, info, aad),
+            _ => Err(CryptoError::InvalidKeyHandle),
+        }
+    }
+
+    fn delete_key(&self, handle: &KeyHandle) -> Result<(), CryptoError> {
+        self.keys
+            .write()
+            .map_err(|_| CryptoError::CryptoLibraryError)?
+            .remove(handle)
+            .map(|_| ())
+            .ok_or(CryptoError::InvalidKeyHandle)
+    }
+}
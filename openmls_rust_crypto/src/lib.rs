@@ -6,7 +6,9 @@
 pub use openmls_memory_storage::{MemoryStorage, MemoryStorageError};
 use openmls_traits::OpenMlsProvider;
 
+mod key_handles;
 mod provider;
+pub use key_handles::SoftwareKeyHandles;
 pub use provider::*;
 
 #[derive(Default, Debug)]
@@ -8,14 +8,16 @@ use chacha20poly1305::ChaCha20Poly1305;
 use ed25519_dalek::Signer;
 use hkdf::Hkdf;
 use hpke::Hpke;
-use hpke_rs_crypto::types as hpke_types;
+use hpke_rs_crypto::{types as hpke_types, HpkeCrypto};
 use hpke_rs_rust_crypto::HpkeRustCrypto;
+use ml_dsa::{KeyGen, MlDsa65, MlDsa87, MlDsaParams};
 use openmls_traits::{
     crypto::OpenMlsCrypto,
     random::OpenMlsRand,
     types::{
-        self, AeadType, Ciphersuite, CryptoError, ExporterSecret, HashType, HpkeAeadType,
-        HpkeCiphertext, HpkeConfig, HpkeKdfType, HpkeKemType, HpkeKeyPair, SignatureScheme,
+        self, AeadType, Ciphersuite, CompositeValue, CryptoError, ExporterSecret, HashType,
+        HpkeAeadType, HpkeCiphertext, HpkeConfig, HpkeKdfType, HpkeKemType, HpkeKeyPair,
+        SignatureScheme,
     },
 };
 use p256::{
@@ -49,15 +51,17 @@ impl Default for RustCrypto {
 }
 
 #[inline(always)]
-fn kem_mode(kem: HpkeKemType) -> hpke_types::KemAlgorithm {
+fn kem_mode(kem: HpkeKemType) -> Result<hpke_types::KemAlgorithm, CryptoError> {
     match kem {
-        HpkeKemType::DhKemP256 => hpke_types::KemAlgorithm::DhKemP256,
-        HpkeKemType::DhKemP384 => hpke_types::KemAlgorithm::DhKemP384,
-        HpkeKemType::DhKemP521 => hpke_types::KemAlgorithm::DhKemP521,
-        HpkeKemType::DhKem25519 => hpke_types::KemAlgorithm::DhKem25519,
-        HpkeKemType::DhKem448 => hpke_types::KemAlgorithm::DhKem448,
-        HpkeKemType::XWingKemDraft2 => {
-            unimplemented!("XWingKemDraft1 is not supported by the RustCrypto provider.")
+        HpkeKemType::DhKemP256 => Ok(hpke_types::KemAlgorithm::DhKemP256),
+        HpkeKemType::DhKemP384 => Ok(hpke_types::KemAlgorithm::DhKemP384),
+        HpkeKemType::DhKemP521 => Ok(hpke_types::KemAlgorithm::DhKemP521),
+        HpkeKemType::DhKem25519 => Ok(hpke_types::KemAlgorithm::DhKem25519),
+        HpkeKemType::DhKem448 => Ok(hpke_types::KemAlgorithm::DhKem448),
+        // The HPKE backend of this provider doesn't implement ML-KEM and
+        // X-Wing.
+        HpkeKemType::MlKem768 | HpkeKemType::MlKem1024 | HpkeKemType::XWingKemDraft2 => {
+            Err(CryptoError::UnsupportedCiphersuite)
         }
     }
 }
@@ -87,16 +91,28 @@ impl OpenMlsCrypto for RustCrypto {
             Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519
             | Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519
             | Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256 => Ok(()),
+            // The P-384 and P-521 KEMs are only available if the HPKE backend
+            // supports them.
+            Ciphersuite::MLS_256_DHKEMP521_AES256GCM_SHA512_P521
+            | Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384 => {
+                HpkeRustCrypto::supports_kem(kem_mode(ciphersuite.hpke_kem_algorithm())?)
+                    .map_err(|_| CryptoError::UnsupportedCiphersuite)
+            }
             _ => Err(CryptoError::UnsupportedCiphersuite),
         }
     }
 
     fn supported_ciphersuites(&self) -> Vec<Ciphersuite> {
-        vec![
+        [
             Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519,
             Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519,
             Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256,
+            Ciphersuite::MLS_256_DHKEMP521_AES256GCM_SHA512_P521,
+            Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384,
         ]
+        .into_iter()
+        .filter(|ciphersuite| self.supports(*ciphersuite).is_ok())
+        .collect()
     }
 
     fn hkdf_extract(
@@ -241,6 +257,27 @@ impl OpenMlsCrypto for RustCrypto {
                 let pk = k.verifying_key().to_encoded_point(false).as_bytes().into();
                 Ok((k.to_bytes().as_slice().into(), pk))
             }
+            SignatureScheme::ECDSA_SECP384R1_SHA384 => {
+                let mut rng = self
+                    .rng
+                    .write()
+                    .map_err(|_| CryptoError::InsufficientRandomness)?;
+                let k = p384::ecdsa::SigningKey::random(&mut *rng);
+                let pk = k.verifying_key().to_encoded_point(false).as_bytes().into();
+                Ok((k.to_bytes().as_slice().into(), pk))
+            }
+            SignatureScheme::ECDSA_SECP521R1_SHA512 => {
+                let mut rng = self
+                    .rng
+                    .write()
+                    .map_err(|_| CryptoError::InsufficientRandomness)?;
+                let k = p521::ecdsa::SigningKey::random(&mut *rng);
+                let pk = p521::ecdsa::VerifyingKey::from(&k)
+                    .to_encoded_point(false)
+                    .as_bytes()
+                    .into();
+                Ok((k.to_bytes().as_slice().into(), pk))
+            }
             SignatureScheme::ED25519 => {
                 let mut rng = self
                     .rng
@@ -250,6 +287,37 @@ impl OpenMlsCrypto for RustCrypto {
                 let pk = sk.verifying_key().to_bytes().into();
                 Ok((sk.to_bytes().into(), pk))
             }
+            SignatureScheme::ML_DSA_65 => {
+                let mut rng = self
+                    .rng
+                    .write()
+                    .map_err(|_| CryptoError::InsufficientRandomness)?;
+                Ok(ml_dsa_key_gen::<MlDsa65>(&mut rng))
+            }
+            SignatureScheme::ML_DSA_87 => {
+                let mut rng = self
+                    .rng
+                    .write()
+                    .map_err(|_| CryptoError::InsufficientRandomness)?;
+                Ok(ml_dsa_key_gen::<MlDsa87>(&mut rng))
+            }
+            SignatureScheme::ML_DSA_65_ED25519 => {
+                let (post_quantum, classical) = alg
+                    .composite_components()
+                    .ok_or(CryptoError::UnsupportedSignatureScheme)?;
+                let (post_quantum_private, post_quantum_public) =
+                    self.signature_key_gen(post_quantum)?;
+                let (classical_private, classical_public) = self.signature_key_gen(classical)?;
+                let private = CompositeValue {
+                    post_quantum: &post_quantum_private,
+                    classical: &classical_private,
+                };
+                let public = CompositeValue {
+                    post_quantum: &post_quantum_public,
+                    classical: &classical_public,
+                };
+                Ok((private.to_vec(), public.to_vec()))
+            }
             _ => Err(CryptoError::UnsupportedSignatureScheme),
         }
     }
@@ -273,6 +341,26 @@ impl OpenMlsCrypto for RustCrypto {
                 )
                 .map_err(|_| CryptoError::InvalidSignature)
             }
+            SignatureScheme::ECDSA_SECP384R1_SHA384 => {
+                let k = p384::ecdsa::VerifyingKey::from_sec1_bytes(pk)
+                    .map_err(|_| CryptoError::CryptoLibraryError)?;
+                k.verify(
+                    data,
+                    &p384::ecdsa::Signature::from_der(signature)
+                        .map_err(|_| CryptoError::InvalidSignature)?,
+                )
+                .map_err(|_| CryptoError::InvalidSignature)
+            }
+            SignatureScheme::ECDSA_SECP521R1_SHA512 => {
+                let k = p521::ecdsa::VerifyingKey::from_sec1_bytes(pk)
+                    .map_err(|_| CryptoError::CryptoLibraryError)?;
+                k.verify(
+                    data,
+                    &p521::ecdsa::Signature::from_der(signature)
+                        .map_err(|_| CryptoError::InvalidSignature)?,
+                )
+                .map_err(|_| CryptoError::InvalidSignature)
+            }
             SignatureScheme::ED25519 => {
                 let k = ed25519_dalek::VerifyingKey::try_from(pk)
                     .map_err(|_| CryptoError::CryptoLibraryError)?;
@@ -284,6 +372,17 @@ impl OpenMlsCrypto for RustCrypto {
                 k.verify_strict(data, &ed25519_dalek::Signature::from(sig))
                     .map_err(|_| CryptoError::InvalidSignature)
             }
+            SignatureScheme::ML_DSA_65 => ml_dsa_verify::<MlDsa65>(data, pk, signature),
+            SignatureScheme::ML_DSA_87 => ml_dsa_verify::<MlDsa87>(data, pk, signature),
+            SignatureScheme::ML_DSA_65_ED25519 => {
+                let (post_quantum, classical) = alg
+                    .composite_components()
+                    .ok_or(CryptoError::UnsupportedSignatureScheme)?;
+                let pk = CompositeValue::public_key(alg, pk)?;
+                let signature = CompositeValue::signature(alg, signature)?;
+                self.verify_signature(post_quantum, data, pk.post_quantum, signature.post_quantum)?;
+                self.verify_signature(classical, data, pk.classical, signature.classical)
+            }
             _ => Err(CryptoError::UnsupportedSignatureScheme),
         }
     }
@@ -301,12 +400,37 @@ impl OpenMlsCrypto for RustCrypto {
                 let signature: Signature = k.sign(data);
                 Ok(signature.to_der().to_bytes().into())
             }
+            SignatureScheme::ECDSA_SECP384R1_SHA384 => {
+                let k = p384::ecdsa::SigningKey::from_slice(key)
+                    .map_err(|_| CryptoError::CryptoLibraryError)?;
+                let signature: p384::ecdsa::Signature = k.sign(data);
+                Ok(signature.to_der().to_bytes().into())
+            }
+            SignatureScheme::ECDSA_SECP521R1_SHA512 => {
+                let k = p521::ecdsa::SigningKey::from_slice(key)
+                    .map_err(|_| CryptoError::CryptoLibraryError)?;
+                let signature: p521::ecdsa::Signature = k.sign(data);
+                Ok(signature.to_der().to_bytes().into())
+            }
             SignatureScheme::ED25519 => {
                 let k = ed25519_dalek::SigningKey::try_from(key)
                     .map_err(|_| CryptoError::CryptoLibraryError)?;
                 let signature = k.sign(data);
                 Ok(signature.to_bytes().into())
             }
+            SignatureScheme::ML_DSA_65 => ml_dsa_sign::<MlDsa65>(data, key),
+            SignatureScheme::ML_DSA_87 => ml_dsa_sign::<MlDsa87>(data, key),
+            SignatureScheme::ML_DSA_65_ED25519 => {
+                let (post_quantum, classical) = alg
+                    .composite_components()
+                    .ok_or(CryptoError::UnsupportedSignatureScheme)?;
+                let key = CompositeValue::private_key(alg, key)?;
+                let signature = CompositeValue {
+                    post_quantum: &self.sign(post_quantum, data, key.post_quantum)?,
+                    classical: &self.sign(classical, data, key.classical)?,
+                };
+                Ok(signature.to_vec())
+            }
             _ => Err(CryptoError::UnsupportedSignatureScheme),
         }
     }
@@ -319,7 +443,7 @@ impl OpenMlsCrypto for RustCrypto {
         aad: &[u8],
         ptxt: &[u8],
     ) -> Result<types::HpkeCiphertext, CryptoError> {
-        let (kem_output, ciphertext) = hpke_from_config(config)
+        let (kem_output, ciphertext) = hpke_from_config(config)?
             .seal(&pk_r.into(), info, aad, ptxt, None, None, None)
             .map_err(|e| match e {
                 hpke::HpkeError::InvalidInput => CryptoError::InvalidLength,
@@ -339,7 +463,7 @@ impl OpenMlsCrypto for RustCrypto {
         info: &[u8],
         aad: &[u8],
     ) -> Result<Vec<u8>, CryptoError> {
-        hpke_from_config(config)
+        hpke_from_config(config)?
             .open(
                 input.kem_output.as_slice(),
                 &sk_r.into(),
@@ -361,7 +485,7 @@ impl OpenMlsCrypto for RustCrypto {
         exporter_context: &[u8],
         exporter_length: usize,
     ) -> Result<(Vec<u8>, ExporterSecret), CryptoError> {
-        let (kem_output, context) = hpke_from_config(config)
+        let (kem_output, context) = hpke_from_config(config)?
             .setup_sender(&pk_r.into(), info, None, None, None)
             .map_err(|_| CryptoError::SenderSetupError)?;
         let exported_secret = context
@@ -379,7 +503,7 @@ impl OpenMlsCrypto for RustCrypto {
         exporter_context: &[u8],
         exporter_length: usize,
     ) -> Result<ExporterSecret, CryptoError> {
-        let context = hpke_from_config(config)
+        let context = hpke_from_config(config)?
             .setup_receiver(enc, &sk_r.into(), info, None, None, None)
             .map_err(|_| CryptoError::ReceiverSetupError)?;
         let exported_secret = context
@@ -393,7 +517,7 @@ impl OpenMlsCrypto for RustCrypto {
         config: HpkeConfig,
         ikm: &[u8],
     ) -> Result<types::HpkeKeyPair, CryptoError> {
-        let kp = hpke_from_config(config)
+        let kp = hpke_from_config(config)?
             .derive_key_pair(ikm)
             .map_err(|e| match e {
                 hpke::HpkeError::InvalidInput => CryptoError::InvalidLength,
@@ -407,13 +531,44 @@ impl OpenMlsCrypto for RustCrypto {
     }
 }
 
-fn hpke_from_config(config: HpkeConfig) -> Hpke<HpkeRustCrypto> {
-    Hpke::<HpkeRustCrypto>::new(
+fn ml_dsa_key_gen<P: MlDsaParams>(rng: &mut rand_chacha::ChaCha20Rng) -> (Vec<u8>, Vec<u8>) {
+    let key_pair = P::key_gen(rng);
+    (
+        key_pair.signing_key().encode().to_vec(),
+        key_pair.verifying_key().encode().to_vec(),
+    )
+}
+
+fn ml_dsa_sign<P: MlDsaParams>(data: &[u8], key: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    let key = ml_dsa::EncodedSigningKey::<P>::try_from(key)
+        .map_err(|_| CryptoError::CryptoLibraryError)?;
+    let signature = ml_dsa::SigningKey::<P>::decode(&key).sign(data);
+    Ok(signature.encode().to_vec())
+}
+
+fn ml_dsa_verify<P: MlDsaParams>(
+    data: &[u8],
+    pk: &[u8],
+    signature: &[u8],
+) -> Result<(), CryptoError> {
+    let pk = ml_dsa::EncodedVerifyingKey::<P>::try_from(pk)
+        .map_err(|_| CryptoError::CryptoLibraryError)?;
+    let signature = ml_dsa::EncodedSignature::<P>::try_from(signature)
+        .ok()
+        .and_then(|signature| ml_dsa::Signature::<P>::decode(&signature))
+        .ok_or(CryptoError::InvalidSignature)?;
+    ml_dsa::VerifyingKey::<P>::decode(&pk)
+        .verify(data, &signature)
+        .map_err(|_| CryptoError::InvalidSignature)
+}
+
+fn hpke_from_config(config: HpkeConfig) -> Result<Hpke<HpkeRustCrypto>, CryptoError> {
+    Ok(Hpke::<HpkeRustCrypto>::new(
         hpke::Mode::Base,
-        kem_mode(config.0),
+        kem_mode(config.0)?,
         kdf_mode(config.1),
         aead_mode(config.2),
-    )
+    ))
 }
 
 impl OpenMlsRand for RustCrypto {
@@ -0,0 +1,144 @@
+//! # Key handles
+//!
+//! This module defines an interface for private keys that are kept in a token,
+//! such as a hardware security module or a remote key service, in the style
+//! of PKCS#11. Keys are generated inside the token and referenced by opaque
+//! [`KeyHandle`]s. The private key bytes never leave the token. Instead, the
+//! token performs the operations that require them.
+//!
+//! Signing with a key handle is done with a [`HandleSigner`], which implements
+//! the [`Signer`] trait and can be used anywhere a signer is expected. HPKE
+//! init keys of key packages can be generated in a token as well, so that
+//! `Welcome` messages are decrypted by the token. Leaf encryption keys are
+//! derived from path secrets by the protocol itself and are therefore not
+//! held by the token.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    signatures::{Signer, SignerError},
+    types::{CryptoError, HpkeCiphertext, HpkeConfig, SignatureScheme},
+};
+
+/// An opaque reference to a private key held by a [`KeyHandleProvider`].
+///
+/// The content of a handle is defined by the provider, e.g. the `CKA_ID` of a
+/// PKCS#11 object. Handles don't contain key material and can be stored
+/// freely.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyHandle {
+    id: Vec<u8>,
+}
+
+impl KeyHandle {
+    /// Create a new key handle from the provider-defined `id`.
+    pub fn new(id: Vec<u8>) -> Self {
+        Self { id }
+    }
+
+    /// Returns the provider-defined id of this handle.
+    pub fn id(&self) -> &[u8] {
+        &self.id
+    }
+}
+
+/// A token that holds private keys and performs the operations that need
+/// them.
+///
+/// All functions return a [`CryptoError`] if the algorithm isn't supported
+/// by the token or the operation fails. Using an unknown handle returns
+/// [`CryptoError::InvalidKeyHandle`].
+pub trait KeyHandleProvider: Send + Sync {
+    /// Generate a new signature key pair for the `alg` inside the token.
+    ///
+    /// Returns the handle of the private key and the encoded public key.
+    fn generate_signature_key(
+        &self,
+        alg: SignatureScheme,
+    ) -> Result<(KeyHandle, Vec<u8>), CryptoError>;
+
+    /// Sign the `data` with the private key referenced by the `handle`.
+    fn sign(
+        &self,
+        handle: &KeyHandle,
+        alg: SignatureScheme,
+        data: &[u8],
+    ) -> Result<Vec<u8>, CryptoError>;
+
+    /// Generate a new HPKE key pair for the `config` inside the token.
+    ///
+    /// Returns the handle of the private key and the encoded public key.
+    fn generate_hpke_key(&self, config: HpkeConfig) -> Result<(KeyHandle, Vec<u8>), CryptoError>;
+
+    /// HPKE single-shot decryption of `input` with the private key referenced
+    /// by the `handle`, using `info` and `aad`.
+    fn hpke_open(
+        &self,
+        handle: &KeyHandle,
+        config: HpkeConfig,
+        input: &HpkeCiphertext,
+        info: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, CryptoError>;
+
+    /// Destroy the private key referenced by the `handle`.
+    fn delete_key(&self, handle: &KeyHandle) -> Result<(), CryptoError>;
+}
+
+/// A [`Signer`] for a signature key held by a [`KeyHandleProvider`].
+#[derive(Debug)]
+pub struct HandleSigner<'a, Provider: KeyHandleProvider + ?Sized> {
+    provider: &'a Provider,
+    handle: KeyHandle,
+    signature_scheme: SignatureScheme,
+    public_key: Vec<u8>,
+}
+
+impl<'a, Provider: KeyHandleProvider + ?Sized> HandleSigner<'a, Provider> {
+    /// Generate a new signature key for the `signature_scheme` in the
+    /// `provider` and return a signer for it.
+    pub fn generate(
+        provider: &'a Provider,
+        signature_scheme: SignatureScheme,
+    ) -> Result<Self, CryptoError> {
+        let (handle, public_key) = provider.generate_signature_key(signature_scheme)?;
+        Ok(Self::new(provider, handle, signature_scheme, public_key))
+    }
+
+    /// Create a signer for an existing signature key in the `provider`.
+    pub fn new(
+        provider: &'a Provider,
+        handle: KeyHandle,
+        signature_scheme: SignatureScheme,
+        public_key: Vec<u8>,
+    ) -> Self {
+        Self {
+            provider,
+            handle,
+            signature_scheme,
+            public_key,
+        }
+    }
+
+    /// Returns the handle of the private key.
+    pub fn handle(&self) -> &KeyHandle {
+        &self.handle
+    }
+
+    /// Returns the encoded public key.
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+}
+
+impl<'a, Provider: KeyHandleProvider + ?Sized> Signer for HandleSigner<'a, Provider> {
+    fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, SignerError> {
+        self.provider
+            .sign(&self.handle, self.signature_scheme, payload)
+            .map_err(SignerError::CryptoError)
+    }
+
+    fn signature_scheme(&self) -> SignatureScheme {
+        self.signature_scheme
+    }
+}
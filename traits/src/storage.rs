@@ -226,6 +226,24 @@ pub trait StorageProvider<const VERSION: u16> {
     ) -> Result<(), Self::Error>;
     // ANCHOR_END: write_key_package
 
+    /// Store multiple key packages at once.
+    ///
+    /// The default implementation calls `write_key_package` for each key
+    /// package. Storage providers may override it to write all key packages
+    /// in a single batch or transaction.
+    fn write_key_packages<
+        HashReference: traits::HashReference<VERSION>,
+        KeyPackage: traits::KeyPackage<VERSION>,
+    >(
+        &self,
+        key_packages: &[(HashReference, KeyPackage)],
+    ) -> Result<(), Self::Error> {
+        for (hash_ref, key_package) in key_packages {
+            self.write_key_package(hash_ref, key_package)?;
+        }
+        Ok(())
+    }
+
     /// Store a PSK.
     ///
     /// This stores PSKs based on the PSK id.
@@ -101,6 +101,14 @@ pub enum SignatureScheme {
     ED25519 = 0x0807,
     /// ED448
     ED448 = 0x0808,
+    /// ML-DSA-65
+    ML_DSA_65 = 0x0905,
+    /// ML-DSA-87
+    ML_DSA_87 = 0x0906,
+    /// ML-DSA-65 and Ed25519 composite
+    ///
+    /// See [`CompositeValue`] for the encoding of keys and signatures.
+    ML_DSA_65_ED25519 = 0x090B,
 }
 
 impl TryFrom<u16> for SignatureScheme {
@@ -113,11 +121,102 @@ impl TryFrom<u16> for SignatureScheme {
             0x0603 => Ok(SignatureScheme::ECDSA_SECP521R1_SHA512),
             0x0807 => Ok(SignatureScheme::ED25519),
             0x0808 => Ok(SignatureScheme::ED448),
+            0x0905 => Ok(SignatureScheme::ML_DSA_65),
+            0x0906 => Ok(SignatureScheme::ML_DSA_87),
+            0x090B => Ok(SignatureScheme::ML_DSA_65_ED25519),
             _ => Err(format!("Unsupported SignatureScheme: {value}")),
         }
     }
 }
 
+impl SignatureScheme {
+    /// Returns the post-quantum and the classical component of a composite
+    /// signature scheme, or `None` if this is not a composite signature
+    /// scheme.
+    pub const fn composite_components(&self) -> Option<(SignatureScheme, SignatureScheme)> {
+        match self {
+            SignatureScheme::ML_DSA_65_ED25519 => {
+                Some((SignatureScheme::ML_DSA_65, SignatureScheme::ED25519))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A key or signature of a composite [`SignatureScheme`].
+///
+/// Composite keys and signatures are the concatenation of the post-quantum
+/// and the classical key or signature. The classical component has a fixed
+/// length and is used to split the encoding. A composite signature is valid if
+/// both component signatures on the same message are valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompositeValue<'a> {
+    /// The post-quantum component.
+    pub post_quantum: &'a [u8],
+    /// The classical component.
+    pub classical: &'a [u8],
+}
+
+impl<'a> CompositeValue<'a> {
+    /// Splits a composite public key of the `signature_scheme`.
+    pub fn public_key(
+        signature_scheme: SignatureScheme,
+        value: &'a [u8],
+    ) -> Result<Self, CryptoError> {
+        Self::split(signature_scheme, value, |classical| match classical {
+            SignatureScheme::ED25519 => Some(32),
+            _ => None,
+        })
+        .ok_or(CryptoError::InvalidPublicKey)
+    }
+
+    /// Splits a composite private key of the `signature_scheme`.
+    pub fn private_key(
+        signature_scheme: SignatureScheme,
+        value: &'a [u8],
+    ) -> Result<Self, CryptoError> {
+        Self::split(signature_scheme, value, |classical| match classical {
+            SignatureScheme::ED25519 => Some(32),
+            _ => None,
+        })
+        .ok_or(CryptoError::InvalidLength)
+    }
+
+    /// Splits a composite signature of the `signature_scheme`.
+    pub fn signature(
+        signature_scheme: SignatureScheme,
+        value: &'a [u8],
+    ) -> Result<Self, CryptoError> {
+        Self::split(signature_scheme, value, |classical| match classical {
+            SignatureScheme::ED25519 => Some(64),
+            _ => None,
+        })
+        .ok_or(CryptoError::InvalidSignature)
+    }
+
+    /// Returns the encoding of this composite value.
+    pub fn to_vec(&self) -> Vec<u8> {
+        [self.post_quantum, self.classical].concat()
+    }
+
+    fn split(
+        signature_scheme: SignatureScheme,
+        value: &'a [u8],
+        classical_length: impl Fn(SignatureScheme) -> Option<usize>,
+    ) -> Option<Self> {
+        let (_, classical) = signature_scheme.composite_components()?;
+        let boundary = value.len().checked_sub(classical_length(classical)?)?;
+        if boundary == 0 {
+            return None;
+        }
+        let (post_quantum, classical) = value.split_at(boundary);
+        Some(Self {
+            post_quantum,
+            classical,
+        })
+    }
+}
+
 /// Crypto errors.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CryptoError {
@@ -145,6 +244,7 @@ pub enum CryptoError {
     TooMuchData,
     SigningError,
     InvalidPublicKey,
+    InvalidKeyHandle,
 }
 
 impl std::fmt::Display for CryptoError {
@@ -180,6 +280,12 @@ pub enum HpkeKemType {
     /// DH KEM on x448
     DhKem448 = 0x0021,
 
+    /// ML-KEM-768
+    MlKem768 = 0x0041,
+
+    /// ML-KEM-1024
+    MlKem1024 = 0x0042,
+
     /// XWing combiner for ML-KEM and X25519
     XWingKemDraft2 = 0x004D,
 }
@@ -374,6 +480,24 @@ pub enum Ciphersuite {
 
     /// X-WING KEM draft-01 | Chacha20Poly1305 | SHA2-256 | Ed25519
     MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519 = 0x004D,
+
+    /// ML-KEM-768 | AES-GCM 256 | SHA2-384 | ML-DSA-65
+    ///
+    /// This ciphersuite uses a code point from the private use range until
+    /// one is assigned by IANA.
+    MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65 = 0xF041,
+
+    /// ML-KEM-1024 | AES-GCM 256 | SHA2-512 | ML-DSA-87
+    ///
+    /// This ciphersuite uses a code point from the private use range until
+    /// one is assigned by IANA.
+    MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87 = 0xF042,
+
+    /// X-WING KEM draft-01 | Chacha20Poly1305 | SHA2-256 | ML-DSA-65 and Ed25519 composite
+    ///
+    /// This ciphersuite uses a code point from the private use range until
+    /// one is assigned by IANA.
+    MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519 = 0xF043,
 }
 
 impl core::fmt::Display for Ciphersuite {
@@ -410,6 +534,9 @@ impl TryFrom<u16> for Ciphersuite {
             0x0006 => Ok(Ciphersuite::MLS_256_DHKEMX448_CHACHA20POLY1305_SHA512_Ed448),
             0x0007 => Ok(Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384),
             0x004D => Ok(Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519),
+            0xF041 => Ok(Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65),
+            0xF042 => Ok(Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87),
+            0xF043 => Ok(Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519),
             _ => Err(Self::Error::DecodingError(format!(
                 "{v} is not a valid ciphersuite value"
             ))),
@@ -467,11 +594,16 @@ impl Ciphersuite {
             Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519
             | Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256
             | Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519
-            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519 => HashType::Sha2_256,
-            Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384 => HashType::Sha2_384,
+            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519
+            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519 => {
+                HashType::Sha2_256
+            }
+            Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384
+            | Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65 => HashType::Sha2_384,
             Ciphersuite::MLS_256_DHKEMX448_AES256GCM_SHA512_Ed448
             | Ciphersuite::MLS_256_DHKEMP521_AES256GCM_SHA512_P521
-            | Ciphersuite::MLS_256_DHKEMX448_CHACHA20POLY1305_SHA512_Ed448 => HashType::Sha2_512,
+            | Ciphersuite::MLS_256_DHKEMX448_CHACHA20POLY1305_SHA512_Ed448
+            | Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87 => HashType::Sha2_512,
         }
     }
 
@@ -497,6 +629,11 @@ impl Ciphersuite {
             Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384 => {
                 SignatureScheme::ECDSA_SECP384R1_SHA384
             }
+            Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65 => SignatureScheme::ML_DSA_65,
+            Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87 => SignatureScheme::ML_DSA_87,
+            Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519 => {
+                SignatureScheme::ML_DSA_65_ED25519
+            }
         }
     }
 
@@ -508,12 +645,15 @@ impl Ciphersuite {
             | Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256 => AeadType::Aes128Gcm,
             Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519
             | Ciphersuite::MLS_256_DHKEMX448_CHACHA20POLY1305_SHA512_Ed448
-            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519 => {
+            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519
+            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519 => {
                 AeadType::ChaCha20Poly1305
             }
             Ciphersuite::MLS_256_DHKEMX448_AES256GCM_SHA512_Ed448
             | Ciphersuite::MLS_256_DHKEMP521_AES256GCM_SHA512_P521
-            | Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384 => AeadType::Aes256Gcm,
+            | Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384
+            | Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65
+            | Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87 => AeadType::Aes256Gcm,
         }
     }
 
@@ -524,13 +664,16 @@ impl Ciphersuite {
             Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519
             | Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256
             | Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519
-            | Self::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519 => HpkeKdfType::HkdfSha256,
-            Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384 => HpkeKdfType::HkdfSha384,
+            | Self::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519
+            | Self::MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519 => {
+                HpkeKdfType::HkdfSha256
+            }
+            Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384
+            | Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65 => HpkeKdfType::HkdfSha384,
             Ciphersuite::MLS_256_DHKEMX448_AES256GCM_SHA512_Ed448
             | Ciphersuite::MLS_256_DHKEMP521_AES256GCM_SHA512_P521
-            | Ciphersuite::MLS_256_DHKEMX448_CHACHA20POLY1305_SHA512_Ed448 => {
-                HpkeKdfType::HkdfSha512
-            }
+            | Ciphersuite::MLS_256_DHKEMX448_CHACHA20POLY1305_SHA512_Ed448
+            | Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87 => HpkeKdfType::HkdfSha512,
         }
     }
 
@@ -547,9 +690,12 @@ impl Ciphersuite {
             | Ciphersuite::MLS_256_DHKEMX448_CHACHA20POLY1305_SHA512_Ed448 => HpkeKemType::DhKem448,
             Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384 => HpkeKemType::DhKemP384,
             Ciphersuite::MLS_256_DHKEMP521_AES256GCM_SHA512_P521 => HpkeKemType::DhKemP521,
-            Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519 => {
+            Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519
+            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519 => {
                 HpkeKemType::XWingKemDraft2
             }
+            Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65 => HpkeKemType::MlKem768,
+            Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87 => HpkeKemType::MlKem1024,
         }
     }
 
@@ -560,12 +706,15 @@ impl Ciphersuite {
             Ciphersuite::MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519
             | Ciphersuite::MLS_128_DHKEMP256_AES128GCM_SHA256_P256 => HpkeAeadType::AesGcm128,
             Ciphersuite::MLS_128_DHKEMX25519_CHACHA20POLY1305_SHA256_Ed25519
-            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519 => {
+            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_Ed25519
+            | Ciphersuite::MLS_256_XWING_CHACHA20POLY1305_SHA256_MLDSA65_Ed25519 => {
                 HpkeAeadType::ChaCha20Poly1305
             }
             Ciphersuite::MLS_256_DHKEMX448_AES256GCM_SHA512_Ed448
             | Ciphersuite::MLS_256_DHKEMP384_AES256GCM_SHA384_P384
-            | Ciphersuite::MLS_256_DHKEMP521_AES256GCM_SHA512_P521 => HpkeAeadType::AesGcm256,
+            | Ciphersuite::MLS_256_DHKEMP521_AES256GCM_SHA512_P521
+            | Ciphersuite::MLS_192_MLKEM768_AES256GCM_SHA384_MLDSA65
+            | Ciphersuite::MLS_256_MLKEM1024_AES256GCM_SHA512_MLDSA87 => HpkeAeadType::AesGcm256,
             Ciphersuite::MLS_256_DHKEMX448_CHACHA20POLY1305_SHA512_Ed448 => {
                 HpkeAeadType::ChaCha20Poly1305
             }